@@ -21,6 +21,31 @@ pub fn criterion_benchmark(c: &mut Criterion) {
     criterion_benchmark_buffer(c, SIZES, "swap_with_clone", |buffer| {
         buffer.swap_with_clone();
     });
+
+    criterion_benchmark_buffer(c, SIZES, "swap_with_copy", |buffer| {
+        buffer.swap_with_copy();
+    });
+
+    criterion_benchmark_access(c);
+}
+
+fn criterion_benchmark_access(c: &mut Criterion) {
+    let mut group = c.benchmark_group("access");
+    let mut buffer: DoubleBuffer<u64> = DoubleBuffer::new(1, 2);
+    buffer.swap();
+
+    group.bench_function("current", |b| b.iter(|| *black_box(&buffer).as_ref()));
+    group.bench_function("next_mut", |b| {
+        b.iter(|| *black_box(&mut buffer).as_mut() += 1);
+    });
+    group.bench_function("split_mut", |b| {
+        b.iter(|| {
+            let (current, next) = black_box(&mut buffer).split_mut();
+            *next = *current;
+        });
+    });
+
+    group.finish();
 }
 
 fn criterion_benchmark_buffer(c: &mut Criterion, sizes: &[usize], group_name: &str, iter_fn: fn(&mut DoubleBuffer<Vec<u8>>)) {
@@ -0,0 +1,246 @@
+//! Derive macros of the [`double-buffer`](https://docs.rs/double-buffer) crate,
+//! re-exported by it with the `derive` feature.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields, FieldsNamed, Index};
+
+/// Generates a `<Name>Buffers` type double buffering the struct.
+///
+/// By default every field is in its own `DoubleBuffer`, and
+/// `#[double_buffered(single)]` puts the whole struct in one `DoubleBuffer`.
+/// The type has `new(current, next)`, `read()`, `stage()` and `swap_all()`.
+///
+/// With a buffer per field, `read()` and `stage()` return `<Name>Read` and
+/// `<Name>Stage`, holding references to the current and next values of the fields.
+#[proc_macro_derive(DoubleBuffered, attributes(double_buffered))]
+pub fn derive_double_buffered(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match double_buffered(&input) {
+        Ok(output) => output.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Implements `Swap` for a struct swapping all its fields, which must be `Swap` too.
+#[proc_macro_derive(Swap)]
+pub fn derive_swap(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match swap(input) {
+        Ok(output) => output.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn swap(mut input: DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            Span::call_site(),
+            "Swap can only be derived for structs",
+        ));
+    };
+    let fields: Vec<_> = match &data.fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|field| {
+                let name = &field.ident;
+                quote!(#name)
+            })
+            .collect(),
+        Fields::Unnamed(fields) => (0..fields.unnamed.len())
+            .map(|index| {
+                let index = Index::from(index);
+                quote!(#index)
+            })
+            .collect(),
+        Fields::Unit => Vec::new(),
+    };
+    let types: Vec<_> = data.fields.iter().map(|field| field.ty.clone()).collect();
+    let where_clause = input.generics.make_where_clause();
+    for ty in types {
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: ::double_buffer::Swap));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::double_buffer::Swap for #name #ty_generics #where_clause {
+            #[inline]
+            fn swap_buffers(&mut self) {
+                #(::double_buffer::Swap::swap_buffers(&mut self.#fields);)*
+            }
+        }
+    })
+}
+
+fn double_buffered(input: &DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let mut single = false;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("double_buffered"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("single") {
+                single = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `single`"))
+            }
+        })?;
+    }
+    if single {
+        Ok(single_buffer(input))
+    } else {
+        let fields = named_fields(input, "DoubleBuffered")?;
+        Ok(buffer_per_field(input, fields))
+    }
+}
+
+fn named_fields<'a>(input: &'a DeriveInput, derive: &str) -> Result<&'a FieldsNamed, Error> {
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => Ok(fields),
+            _ => Err(Error::new(
+                Span::call_site(),
+                format!("{derive} requires named fields"),
+            )),
+        },
+        _ => Err(Error::new(
+            Span::call_site(),
+            format!("{derive} can only be derived for structs"),
+        )),
+    }
+}
+
+fn buffers_ident(name: &Ident, suffix: &str) -> Ident {
+    format_ident!("{name}{suffix}")
+}
+
+fn single_buffer(input: &DeriveInput) -> proc_macro2::TokenStream {
+    let vis = &input.vis;
+    let name = &input.ident;
+    let buffers = buffers_ident(name, "Buffers");
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let doc = format!("Double buffer of [`{name}`].");
+
+    quote! {
+        #[doc = #doc]
+        #vis struct #buffers #impl_generics (pub ::double_buffer::DoubleBuffer<#name #ty_generics>) #where_clause;
+
+        impl #impl_generics #buffers #ty_generics #where_clause {
+            #[inline]
+            #vis const fn new(current: #name #ty_generics, next: #name #ty_generics) -> Self {
+                Self(::double_buffer::DoubleBuffer::new(current, next))
+            }
+
+            /// Gives access to the current value.
+            #[inline]
+            #vis fn read(&self) -> &#name #ty_generics {
+                &self.0
+            }
+
+            /// Gives mutable access to the next value.
+            #[inline]
+            #vis fn stage(&mut self) -> &mut #name #ty_generics {
+                &mut self.0
+            }
+
+            /// Swaps the buffer.
+            #[inline]
+            #vis fn swap_all(&mut self) {
+                self.0.swap();
+            }
+        }
+
+        impl #impl_generics ::double_buffer::Swap for #buffers #ty_generics #where_clause {
+            #[inline]
+            fn swap_buffers(&mut self) {
+                self.swap_all();
+            }
+        }
+    }
+}
+
+fn buffer_per_field(input: &DeriveInput, fields: &FieldsNamed) -> proc_macro2::TokenStream {
+    let vis = &input.vis;
+    let name = &input.ident;
+    let buffers = buffers_ident(name, "Buffers");
+    let read = buffers_ident(name, "Read");
+    let stage = buffers_ident(name, "Stage");
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut ref_generics = input.generics.clone();
+    ref_generics.params.insert(0, parse_quote!('double_buffer));
+    let (ref_impl_generics, _, _) = ref_generics.split_for_impl();
+    let mut elided_generics = input.generics.clone();
+    elided_generics.params.insert(0, parse_quote!('_));
+    let (_, elided_ty_generics, _) = elided_generics.split_for_impl();
+
+    let field_vis: Vec<_> = fields.named.iter().map(|field| &field.vis).collect();
+    let field_names: Vec<_> = fields.named.iter().map(|field| &field.ident).collect();
+    let field_types: Vec<_> = fields.named.iter().map(|field| &field.ty).collect();
+
+    let buffers_doc = format!("Fields of [`{name}`], each one in its own double buffer.");
+    let read_doc = format!("Current values of the fields of [`{buffers}`].");
+    let stage_doc = format!("Next values of the fields of [`{buffers}`].");
+
+    quote! {
+        #[doc = #buffers_doc]
+        #vis struct #buffers #impl_generics #where_clause {
+            #(#field_vis #field_names: ::double_buffer::DoubleBuffer<#field_types>,)*
+        }
+
+        #[doc = #read_doc]
+        #vis struct #read #ref_impl_generics #where_clause {
+            #(#field_vis #field_names: &'double_buffer #field_types,)*
+        }
+
+        #[doc = #stage_doc]
+        #vis struct #stage #ref_impl_generics #where_clause {
+            #(#field_vis #field_names: &'double_buffer mut #field_types,)*
+        }
+
+        impl #impl_generics #buffers #ty_generics #where_clause {
+            #[inline]
+            #vis fn new(current: #name #ty_generics, next: #name #ty_generics) -> Self {
+                Self {
+                    #(#field_names: ::double_buffer::DoubleBuffer::new(current.#field_names, next.#field_names),)*
+                }
+            }
+
+            /// Gives access to the current values of the fields.
+            #[inline]
+            #vis fn read(&self) -> #read #elided_ty_generics {
+                #read {
+                    #(#field_names: &self.#field_names,)*
+                }
+            }
+
+            /// Gives mutable access to the next values of the fields.
+            #[inline]
+            #vis fn stage(&mut self) -> #stage #elided_ty_generics {
+                #stage {
+                    #(#field_names: &mut self.#field_names,)*
+                }
+            }
+
+            /// Swaps the buffers of all the fields.
+            #[inline]
+            #vis fn swap_all(&mut self) {
+                #(self.#field_names.swap();)*
+            }
+        }
+
+        impl #impl_generics ::double_buffer::Swap for #buffers #ty_generics #where_clause {
+            #[inline]
+            fn swap_buffers(&mut self) {
+                self.swap_all();
+            }
+        }
+    }
+}
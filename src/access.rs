@@ -0,0 +1,74 @@
+use crate::{DoubleBuffer, Instrument};
+
+impl<T, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Gives access to an element of the current value, or `None` if the index is
+    /// out of bounds.
+    ///
+    /// Unlike indexing the buffer, where `buffer[i]` reads the current value but
+    /// `buffer[i] = x` writes the next value, the name says which value is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let mut buffer: DoubleBuffer<[u8; 4]> = DoubleBuffer::new([1, 2, 3, 4], [0; 4]);
+    /// assert_eq!(buffer.get_current(1), Some(&2));
+    /// assert_eq!(buffer.get_current(4), None);
+    ///
+    /// *buffer.get_next_mut(1).unwrap() = 5;
+    /// assert_eq!(buffer.get_current(1), Some(&2));
+    /// buffer.swap();
+    /// assert_eq!(buffer.get_current(1), Some(&5));
+    /// ```
+    #[inline]
+    pub fn get_current<E>(&self, index: usize) -> Option<&E>
+    where
+        T: AsRef<[E]>,
+    {
+        self.current().as_ref().get(index)
+    }
+
+    /// Gives mutable access to an element of the next value, or `None` if the index
+    /// is out of bounds.
+    ///
+    /// It counts as a mutable access for [`DoubleBuffer::has_pending_changes()`].
+    #[inline]
+    pub fn get_next_mut<E>(&mut self, index: usize) -> Option<&mut E>
+    where
+        T: AsMut<[E]>,
+    {
+        self.as_mut().as_mut().get_mut(index)
+    }
+
+    /// Overwrites the elements of the next value with the items of the iterator,
+    /// from the start, until either one ends, and returns the number of elements written.
+    ///
+    /// The elements after the written ones keep their values. It counts as a
+    /// mutable access for [`DoubleBuffer::has_pending_changes()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let mut frame: DoubleBuffer<[u8; 4]> = DoubleBuffer::new([0; 4], [9; 4]);
+    /// let decoded = [1, 2, 3].into_iter();
+    ///
+    /// assert_eq!(frame.fill_next_from_iter(decoded), 3);
+    /// frame.swap();
+    /// assert_eq!(frame, [1, 2, 3, 9]);
+    ///
+    /// assert_eq!(frame.fill_next_from_iter(0..), 4);
+    /// ```
+    #[inline]
+    pub fn fill_next_from_iter<E>(&mut self, iter: impl IntoIterator<Item = E>) -> usize
+    where
+        T: AsMut<[E]>,
+    {
+        self.as_mut()
+            .as_mut()
+            .iter_mut()
+            .zip(iter)
+            .map(|(element, item)| *element = item)
+            .count()
+    }
+}
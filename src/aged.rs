@@ -0,0 +1,148 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::{Clear, DoubleBuffer, MoveSwap, SwapStrategy};
+
+/// Wraps a [`DoubleBuffer`] counting the frames since the last swap, for
+/// consumers extrapolating from a stale value or warning about it.
+///
+/// [`Aged::tick()`] is called once per loop iteration and every swap resets the
+/// count, so [`Aged::frames_since_swap()`] is how many frames old the current
+/// value is, without a [`Clock`](crate::Clock).
+///
+/// Like [`Timestamped`](crate::Timestamped), it dereferences to the current value
+/// and mutably dereferences to the next value, and all swaps must go through the
+/// wrapper, so the inner buffer is only accessible immutably with [`Aged::buffer()`].
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{Aged, DoubleBuffer};
+/// let mut position: Aged<f32> = Aged::new(DoubleBuffer::new(0.0, 0.0));
+/// let velocity = 2.0;
+///
+/// *position = 10.0;
+/// position.swap();
+///
+/// // The producer missed two frames, extrapolate from the last value.
+/// position.tick();
+/// position.tick();
+/// let estimate = *position + velocity * position.frames_since_swap() as f32;
+/// assert_eq!(estimate, 14.0);
+/// ```
+pub struct Aged<T, S = MoveSwap> {
+    buffer: DoubleBuffer<T, S>,
+    frames: u64,
+}
+
+impl<T, S> Aged<T, S> {
+    #[inline]
+    pub const fn new(buffer: DoubleBuffer<T, S>) -> Self {
+        Self { buffer, frames: 0 }
+    }
+
+    #[inline]
+    pub const fn buffer(&self) -> &DoubleBuffer<T, S> {
+        &self.buffer
+    }
+
+    /// Counts a frame, call it once per loop iteration.
+    #[inline]
+    pub const fn tick(&mut self) {
+        self.frames = self.frames.saturating_add(1);
+    }
+
+    /// Number of ticks since the last swap, or since it was created if it was never swapped.
+    #[inline]
+    pub const fn frames_since_swap(&self) -> u64 {
+        self.frames
+    }
+
+    /// Swaps like [`DoubleBuffer::try_swap_with()`] and resets the age if it was swapped.
+    #[inline]
+    pub fn try_swap_with<E>(&mut self, reset: impl FnOnce() -> Result<T, E>) -> Result<(), E> {
+        self.buffer.try_swap_with(reset)?;
+        self.frames = 0;
+        Ok(())
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> DoubleBuffer<T, S> {
+        self.buffer
+    }
+}
+
+impl<T, S: SwapStrategy<T>> Aged<T, S> {
+    /// Swaps like [`DoubleBuffer::swap()`] and resets the age.
+    #[inline]
+    pub fn swap(&mut self) {
+        self.buffer.swap();
+        self.frames = 0;
+    }
+}
+
+impl<T: Clone, S> Aged<T, S> {
+    /// Swaps like [`DoubleBuffer::swap_with_clone()`] and resets the age.
+    #[inline]
+    pub fn swap_with_clone(&mut self) {
+        self.buffer.swap_with_clone();
+        self.frames = 0;
+    }
+}
+
+impl<T: Default, S> Aged<T, S> {
+    /// Swaps like [`DoubleBuffer::swap_with_default()`] and resets the age.
+    #[inline]
+    pub fn swap_with_default(&mut self) {
+        self.buffer.swap_with_default();
+        self.frames = 0;
+    }
+}
+
+impl<T: Clear, S> Aged<T, S> {
+    /// Swaps like [`DoubleBuffer::swap_with_clear()`] and resets the age.
+    #[inline]
+    pub fn swap_with_clear(&mut self) {
+        self.buffer.swap_with_clear();
+        self.frames = 0;
+    }
+}
+
+impl<T: Default, S> Default for Aged<T, S> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(DoubleBuffer::default())
+    }
+}
+
+impl<T, S> Deref for Aged<T, S> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+impl<T, S> DerefMut for Aged<T, S> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failed_swap_keeps_age() {
+        let mut buffer: Aged<[u8; 2]> = Aged::default();
+        buffer.tick();
+        buffer.try_swap_with(|| Err(())).unwrap_err();
+        assert_eq!(buffer.frames_since_swap(), 1);
+
+        buffer.swap_with_clear();
+        assert_eq!(buffer.frames_since_swap(), 0);
+        assert_eq!(buffer.buffer().current_slot().index(), 1);
+    }
+}
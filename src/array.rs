@@ -0,0 +1,141 @@
+use core::fmt::{Debug, Formatter};
+
+use crate::{SlotId, Swap};
+
+/// `N` double buffered values swapped at once, sharing a single flag.
+///
+/// Each [`DoubleBuffer`](crate::DoubleBuffer) keeps its own current slot, which
+/// for small values is padded to their alignment. Here every element takes
+/// `2 * size_of::<T>()` and the whole array one byte more, for keeping thousands
+/// of small components.
+///
+/// Swapping is O(1), as it only flips the flag.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::DoubleBufferArray;
+/// let mut healths: DoubleBufferArray<[u8; 3], 1000> = DoubleBufferArray::default();
+/// assert_eq!(size_of_val(&healths), 2 * 3 * 1000 + 1);
+///
+/// healths.next_mut(7).unwrap()[0] = 100;
+/// assert_eq!(healths.current(7), Some(&[0; 3]));
+///
+/// healths.swap();
+/// assert_eq!(healths.current(7), Some(&[100, 0, 0]));
+/// ```
+pub struct DoubleBufferArray<T, const N: usize> {
+    current: SlotId,
+    pairs: [[T; 2]; N],
+}
+
+impl<T, const N: usize> DoubleBufferArray<T, N> {
+    /// Creates the array from pairs of current and next values.
+    #[inline]
+    pub const fn new(pairs: [[T; 2]; N]) -> Self {
+        Self {
+            current: SlotId::FIRST,
+            pairs,
+        }
+    }
+
+    /// Creates the pairs of current and next values calling `f` with their indexes.
+    #[inline]
+    pub fn from_fn(mut f: impl FnMut(usize) -> [T; 2]) -> Self {
+        Self::new(core::array::from_fn(&mut f))
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Publishes the next values of every element.
+    #[inline]
+    pub const fn swap(&mut self) {
+        self.current = self.current.other();
+    }
+
+    /// Gives access to the current value of an element.
+    #[inline]
+    pub fn current(&self, index: usize) -> Option<&T> {
+        Some(&self.pairs.get(index)?[self.current.index()])
+    }
+
+    /// Gives mutable access to the next value of an element.
+    #[inline]
+    pub fn next_mut(&mut self, index: usize) -> Option<&mut T> {
+        let next = self.current.other().index();
+        Some(&mut self.pairs.get_mut(index)?[next])
+    }
+
+    /// Gives access to the current value and mutable access to the next value of an element.
+    #[inline]
+    pub fn split_mut(&mut self, index: usize) -> Option<(&T, &mut T)> {
+        let [first, second] = self.pairs.get_mut(index)?;
+        Some(if self.current == SlotId::FIRST {
+            (first, second)
+        } else {
+            (second, first)
+        })
+    }
+
+    /// Iterates the current values.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &T> {
+        let current = self.current.index();
+        self.pairs.iter().map(move |pair| &pair[current])
+    }
+
+    /// Iterates the next values, mutably.
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = &mut T> {
+        let next = self.current.other().index();
+        self.pairs.iter_mut().map(move |pair| &mut pair[next])
+    }
+}
+
+impl<T, const N: usize> Swap for DoubleBufferArray<T, N> {
+    #[inline]
+    fn swap_buffers(&mut self) {
+        self.swap();
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for DoubleBufferArray<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Default, const N: usize> Default for DoubleBufferArray<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::from_fn(|_| Default::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_mut_follows_swaps() {
+        let mut values = DoubleBufferArray::new([[1, 2], [3, 4]]);
+        values.swap();
+
+        let (current, next) = values.split_mut(1).unwrap();
+        assert_eq!((*current, *next), (4, 3));
+        *next = 5;
+
+        values.swap();
+        assert!(values.iter().copied().eq([1, 5]));
+        assert_eq!(values.split_mut(2), None);
+    }
+}
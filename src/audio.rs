@@ -0,0 +1,154 @@
+use crate::{DoubleBuffer, Instrument, MoveSwap, SwapStrategy};
+
+/// Wraps a [`DoubleBuffer`] of audio samples, like `[f32; 512]` or a `Vec<f32>`,
+/// to fill them and play them block by block.
+///
+/// The renderer fills the next value with [`AudioBlocks::fill_next()`], in one or
+/// many calls, and the audio callback takes blocks of the current value with
+/// [`AudioBlocks::take_current_block()`]. When the current value is fully taken
+/// and the next value is full, it's swapped.
+///
+/// Both take `&mut self`, so it isn't a handoff between threads: the renderer and
+/// the callback have to share the wrapper, e.g. by rendering in the callback or
+/// behind a lock. Taking a block doesn't loop or allocate.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{AudioBlocks, DoubleBuffer};
+/// let mut audio: AudioBlocks<[f32; 4]> = AudioBlocks::new(DoubleBuffer::new([0.0; 4], [0.0; 4]));
+///
+/// audio.fill_next(|block: &mut [f32]| {
+///     block.fill(0.5);
+///     block.len()
+/// });
+///
+/// // The first value is silence, then the filled one is swapped in.
+/// assert_eq!(audio.take_current_block(4), &[0.0; 4]);
+/// assert_eq!(audio.take_current_block(3), &[0.5; 3]);
+/// assert_eq!(audio.take_current_block(3), &[0.5]);
+/// ```
+pub struct AudioBlocks<T, S = MoveSwap, I = ()> {
+    buffer: DoubleBuffer<T, S, I>,
+    filled: usize,
+    taken: usize,
+    underruns: u64,
+}
+
+impl<T, S, I: Instrument<T>> AudioBlocks<T, S, I> {
+    /// Wraps the buffer, its current value is taken first and its next value is empty.
+    #[inline]
+    pub const fn new(buffer: DoubleBuffer<T, S, I>) -> Self {
+        Self {
+            buffer,
+            filled: 0,
+            taken: 0,
+            underruns: 0,
+        }
+    }
+
+    #[inline]
+    pub const fn buffer(&self) -> &DoubleBuffer<T, S, I> {
+        &self.buffer
+    }
+
+    /// Number of samples filled in the next value.
+    #[inline]
+    pub const fn filled(&self) -> usize {
+        self.filled
+    }
+
+    /// Number of samples taken from the current value.
+    #[inline]
+    pub const fn taken(&self) -> usize {
+        self.taken
+    }
+
+    /// Number of times a block was taken when the current value was fully taken
+    /// and the next value wasn't full yet.
+    #[inline]
+    pub const fn underruns(&self) -> u64 {
+        self.underruns
+    }
+
+    /// Calls `fill` with the samples of the next value not filled yet, it returns
+    /// how many of them it filled, from the start.
+    ///
+    /// Returns the number of samples filled by this call.
+    #[inline]
+    pub fn fill_next<A>(&mut self, fill: impl FnOnce(&mut [A]) -> usize) -> usize
+    where
+        T: AsMut<[A]>,
+    {
+        let samples = self.buffer.as_mut().as_mut();
+        let Some(block) = samples.get_mut(self.filled..) else {
+            return 0;
+        };
+        let count = fill(block).min(block.len());
+        self.filled += count;
+        count
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> DoubleBuffer<T, S, I> {
+        self.buffer
+    }
+}
+
+impl<T, S: SwapStrategy<T>, I: Instrument<T>> AudioBlocks<T, S, I> {
+    /// Takes up to `len` samples of the current value. If it's fully taken, the
+    /// buffer is swapped first when the next value is full.
+    ///
+    /// Returns an empty block on underrun, when the current value was fully taken
+    /// and the next value isn't full yet.
+    #[inline]
+    pub fn take_current_block<A>(&mut self, len: usize) -> &[A]
+    where
+        T: AsRef<[A]> + AsMut<[A]>,
+    {
+        let current_len = self.buffer.as_ref().as_ref().len();
+        if self.taken >= current_len {
+            if self.filled < self.buffer.next().as_ref().len() {
+                self.underruns = self.underruns.saturating_add(1);
+                return &[];
+            }
+            self.buffer.swap();
+            self.filled = 0;
+            self.taken = 0;
+        }
+        let samples = self.buffer.as_ref().as_ref();
+        let start = self.taken.min(samples.len());
+        let end = start.saturating_add(len).min(samples.len());
+        self.taken = end;
+        &samples[start..end]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_underrun() {
+        let mut audio: AudioBlocks<[i16; 2]> = AudioBlocks::new(DoubleBuffer::new([1; 2], [0; 2]));
+        assert_eq!(audio.take_current_block(2), &[1, 1]);
+
+        let filled = audio.fill_next(|block: &mut [i16]| {
+            block[0] = 2;
+            1
+        });
+        assert_eq!(filled, 1);
+        assert_eq!(audio.take_current_block::<i16>(2), &[] as &[i16]);
+        assert_eq!(audio.underruns(), 1);
+
+        // It can't fill more than the samples left.
+        let filled = audio.fill_next(|block: &mut [i16]| {
+            block[0] = 3;
+            5
+        });
+        assert_eq!(filled, 1);
+        assert_eq!(audio.fill_next(|_: &mut [i16]| 1), 0);
+        assert_eq!(audio.take_current_block(2), &[2, 3]);
+        assert_eq!(audio.filled(), 0);
+    }
+}
@@ -0,0 +1,149 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::{DoubleBuffer, Instrument, MoveSwap, SwapStrategy};
+
+/// Wraps a [`DoubleBuffer`] to swap it automatically after a number of commits.
+///
+/// Every call to [`AutoSwap::commit_one()`] counts one write into the next value,
+/// and when the configured count is reached the buffer is swapped. It's useful to
+/// batch samples into fixed-size frames.
+///
+/// Reads and writes go to the inner buffer through [`Deref`] and [`DerefMut`],
+/// so they don't count as commits.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{AutoSwap, DoubleBuffer};
+/// let mut samples: AutoSwap<[u16; 4]> = AutoSwap::new(DoubleBuffer::default(), 4);
+///
+/// for sample in 1..=4 {
+///     let index = samples.pending_commits();
+///     samples[index] = sample;
+///     samples.commit_one();
+/// }
+///
+/// assert_eq!(**samples, [1, 2, 3, 4]);
+/// assert_eq!(samples.pending_commits(), 0);
+/// ```
+pub struct AutoSwap<T, S = MoveSwap, I = ()> {
+    buffer: DoubleBuffer<T, S, I>,
+    commits: usize,
+    commits_per_swap: usize,
+}
+
+impl<T, S, I: Instrument<T>> AutoSwap<T, S, I> {
+    /// Wraps the buffer to swap it every `commits_per_swap` commits.
+    ///
+    /// If `commits_per_swap` is zero, it never swaps automatically.
+    #[inline]
+    pub const fn new(buffer: DoubleBuffer<T, S, I>, commits_per_swap: usize) -> Self {
+        Self {
+            buffer,
+            commits: 0,
+            commits_per_swap,
+        }
+    }
+
+    /// Number of commits since the last swap.
+    #[inline]
+    pub const fn pending_commits(&self) -> usize {
+        self.commits
+    }
+
+    /// Number of commits that trigger a swap.
+    #[inline]
+    pub const fn commits_per_swap(&self) -> usize {
+        self.commits_per_swap
+    }
+
+    /// Changes the number of commits that trigger a swap.
+    ///
+    /// It doesn't swap even if there are already more pending commits,
+    /// the next commit will do it.
+    #[inline]
+    pub fn set_commits_per_swap(&mut self, commits_per_swap: usize) {
+        self.commits_per_swap = commits_per_swap;
+    }
+
+    /// Unwraps the inner buffer, discarding the count of pending commits.
+    #[inline]
+    pub fn into_inner(self) -> DoubleBuffer<T, S, I> {
+        self.buffer
+    }
+}
+
+impl<T, S: SwapStrategy<T>, I: Instrument<T>> AutoSwap<T, S, I> {
+    /// Counts one commit, and swaps the buffer if the configured count is reached.
+    ///
+    /// Returns `true` if the buffer was swapped.
+    #[inline]
+    pub fn commit_one(&mut self) -> bool {
+        self.commits = self.commits.saturating_add(1);
+        if self.commits_per_swap != 0 && self.commits >= self.commits_per_swap {
+            self.swap();
+            return true;
+        }
+        false
+    }
+
+    /// Swaps the buffer now and resets the count of pending commits.
+    #[inline]
+    pub fn swap(&mut self) {
+        self.buffer.swap();
+        self.commits = 0;
+    }
+}
+
+impl<T, S, I: Instrument<T>> Deref for AutoSwap<T, S, I> {
+    type Target = DoubleBuffer<T, S, I>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+impl<T, S, I: Instrument<T>> DerefMut for AutoSwap<T, S, I> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap_after_commits() {
+        let mut buffer: AutoSwap<u32> = AutoSwap::new(DoubleBuffer::new(0, 0), 2);
+
+        **buffer += 1;
+        assert!(!buffer.commit_one());
+        assert_eq!(*buffer, 0);
+
+        **buffer += 1;
+        assert!(buffer.commit_one());
+        assert_eq!(*buffer, 2);
+        assert_eq!(buffer.pending_commits(), 0);
+    }
+
+    #[test]
+    fn test_never_swap_with_zero_commits() {
+        let mut buffer: AutoSwap<u32> = AutoSwap::new(DoubleBuffer::new(0, 1), 0);
+        for _ in 0..8 {
+            assert!(!buffer.commit_one());
+        }
+        assert_eq!(*buffer, 0);
+        assert_eq!(buffer.pending_commits(), 8);
+    }
+
+    #[test]
+    fn test_wraps_tracked_buffer() {
+        let mut buffer = AutoSwap::new(DoubleBuffer::tracked(0u32, 0), 1);
+        **buffer = 1;
+        assert!(buffer.commit_one());
+        assert_eq!(buffer.generation(), 1);
+    }
+}
@@ -0,0 +1,118 @@
+use core::fmt::{Debug, Formatter};
+
+use crate::{DoubleBuffer, Instrument, SwapStrategy};
+
+impl<T, S: SwapStrategy<T>, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Starts a batch of updates of the next value, published once as a single
+    /// revision when the [`Batch`] is committed or dropped.
+    ///
+    /// The buffer is borrowed by the batch, so none of the intermediate values can
+    /// be published, and the hooks are called once for the whole batch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::TrackedBuffer;
+    /// let mut inventory: TrackedBuffer<Vec<&str>> = TrackedBuffer::default();
+    ///
+    /// let mut batch = inventory.begin_batch();
+    /// batch.update(|items| items.push("sword"));
+    /// batch.update(|items| items.push("shield"));
+    /// assert_eq!(batch.updates(), 2);
+    /// batch.commit();
+    ///
+    /// assert_eq!(*inventory, ["sword", "shield"]);
+    /// assert_eq!(inventory.generation(), 1);
+    /// ```
+    #[inline]
+    pub fn begin_batch(&mut self) -> Batch<'_, T, S, I> {
+        Batch {
+            buffer: self,
+            updates: 0,
+        }
+    }
+}
+
+/// Updates of the next value of a [`DoubleBuffer`] published as one revision,
+/// started by [`DoubleBuffer::begin_batch()`].
+///
+/// The updates are published when it's committed or dropped, if there was any.
+/// If an update panics, a [`TrackedBuffer`](crate::TrackedBuffer) is poisoned like with
+/// [`DoubleBuffer::build_next()`] and the batch isn't published.
+#[must_use = "the updates are published when the batch is dropped"]
+pub struct Batch<'a, T, S: SwapStrategy<T>, I: Instrument<T> = ()> {
+    buffer: &'a mut DoubleBuffer<T, S, I>,
+    updates: usize,
+}
+
+impl<T, S: SwapStrategy<T>, I: Instrument<T>> Batch<'_, T, S, I> {
+    /// Updates the next value, counted as part of the batch.
+    #[inline]
+    pub fn update<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> R {
+        self.buffer.set_poisoned(true);
+        let result = f(self.buffer.as_mut());
+        self.buffer.set_poisoned(false);
+        self.updates += 1;
+        result
+    }
+
+    /// Number of updates in the batch.
+    #[inline]
+    pub const fn updates(&self) -> usize {
+        self.updates
+    }
+
+    #[inline]
+    pub const fn current(&self) -> &T {
+        self.buffer.current()
+    }
+
+    #[inline]
+    pub const fn next(&self) -> &T {
+        self.buffer.next()
+    }
+
+    /// Ends the batch, publishing the updates if there was any.
+    #[inline]
+    pub fn commit(self) {}
+}
+
+impl<T, S: SwapStrategy<T>, I: Instrument<T>> Drop for Batch<'_, T, S, I> {
+    #[inline]
+    fn drop(&mut self) {
+        if self.updates > 0 && !self.buffer.poisoned() {
+            self.buffer.swap();
+        }
+    }
+}
+
+impl<T: Debug, S: SwapStrategy<T>, I: Instrument<T>> Debug for Batch<'_, T, S, I> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Batch")
+            .field("next", self.buffer.next())
+            .field("updates", &self.updates)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TrackedBuffer;
+
+    #[test]
+    fn test_empty_batch_not_published() {
+        let mut buffer: TrackedBuffer<u32> = DoubleBuffer::tracked(1, 2);
+        buffer.begin_batch().commit();
+        assert_eq!(buffer.generation(), 0);
+
+        {
+            let mut batch = buffer.begin_batch();
+            batch.update(|next| *next += 1);
+            assert_eq!((*batch.current(), *batch.next()), (1, 3));
+        }
+        assert_eq!(*buffer, 3);
+        assert_eq!(buffer.generation(), 1);
+    }
+}
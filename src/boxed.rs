@@ -0,0 +1,41 @@
+use alloc::boxed::Box;
+
+use crate::DoubleBuffer;
+
+impl<T: ?Sized> DoubleBuffer<Box<T>> {
+    /// Creates a buffer of boxed values of an unsized type, usually trait objects,
+    /// so each value can be of a different type implementing the trait.
+    ///
+    /// The boxes given are coerced to the type of the buffer, which must be known,
+    /// so name it like `DoubleBuffer::<Box<dyn Trait>>::new_dyn()` or annotate the
+    /// variable. Assigning the next value coerces the box too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// trait Renderer {
+    ///     fn name(&self) -> &str;
+    /// }
+    ///
+    /// struct Software;
+    /// struct Gpu;
+    ///
+    /// impl Renderer for Software {
+    ///     fn name(&self) -> &str { "software" }
+    /// }
+    ///
+    /// impl Renderer for Gpu {
+    ///     fn name(&self) -> &str { "gpu" }
+    /// }
+    ///
+    /// let mut renderer = DoubleBuffer::<Box<dyn Renderer>>::new_dyn(Box::new(Software), Box::new(Software));
+    /// *renderer = Box::new(Gpu);
+    /// renderer.swap();
+    /// assert_eq!(renderer.name(), "gpu");
+    /// ```
+    #[inline]
+    pub const fn new_dyn(current: Box<T>, next: Box<T>) -> Self {
+        Self::new(current, next)
+    }
+}
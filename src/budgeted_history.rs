@@ -0,0 +1,420 @@
+use alloc::borrow::Cow;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+
+use crate::ByteSize;
+
+/// Generalization of [`HistoryBuffer`](crate::HistoryBuffer) retaining the
+/// published values within a byte budget instead of a fixed depth.
+///
+/// Like [`HistoryBuffer`](crate::HistoryBuffer), it dereferences to the current
+/// value, mutably dereferences to the next value, [`BudgetedHistory::swap()`]
+/// publishes the next value, and older values are accessible with
+/// [`BudgetedHistory::get()`] and restored with [`BudgetedHistory::rollback()`].
+///
+/// Every swap retains a copy of the value that stops being current, measured with
+/// [`ByteSize`], and the oldest values are evicted while [`BudgetedHistory::used_bytes()`]
+/// is over [`BudgetedHistory::budget()`], so a long replay window doesn't require
+/// guessing a depth up front. The current and next values aren't counted.
+///
+/// The eviction policy `P` chooses how the values are retained, [`DropOldest`]
+/// retains them whole, and [`KeyframeDelta`] retains only their changes from the
+/// last keyframe, so more of them fit in the budget.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{BudgetedHistory, KeyframeDelta};
+/// let mut world: BudgetedHistory<Vec<u32>, KeyframeDelta<30>> =
+///     BudgetedHistory::new(vec![0; 256], vec![0; 256], 64 * 1024);
+///
+/// for frame in 1..=100 {
+///     world[0] = frame;
+///     world.swap();
+/// }
+/// assert_eq!(world.len(), 101);
+/// assert!(world.used_bytes() < 16 * 1024);
+///
+/// assert!(world.rollback(40));
+/// assert_eq!(world[0], 40);
+/// ```
+pub struct BudgetedHistory<T, P: EvictionPolicy<T> = DropOldest> {
+    generation: u64,
+    current: T,
+    next: T,
+    // Values before the current one, oldest first.
+    retained: VecDeque<Retained<T, P::Changes>>,
+    budget: usize,
+    used: usize,
+    policy: PhantomData<fn() -> P>,
+}
+
+/// How [`BudgetedHistory`] retains the values that stop being current.
+pub trait EvictionPolicy<T> {
+    /// Changes of a value from a keyframe.
+    type Changes: ByteSize;
+
+    /// Returns the changes from `keyframe` to `value`, or `None` to retain `value`
+    /// whole as a new keyframe, with `since_keyframe` values retained after `keyframe`.
+    fn changes(since_keyframe: usize, keyframe: &T, value: &T) -> Option<Self::Changes>;
+
+    /// Rebuilds the value from `keyframe` and its `changes`.
+    fn apply(keyframe: &T, changes: &Self::Changes) -> T;
+}
+
+/// Policy of [`BudgetedHistory`] retaining every value whole and evicting the oldest one.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DropOldest;
+
+/// Policy of [`BudgetedHistory`] retaining one value whole every `INTERVAL`
+/// values, as a keyframe, and only the changes from it for the others.
+///
+/// A keyframe is evicted with the values retained as changes from it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KeyframeDelta<const INTERVAL: usize>;
+
+/// Values that can be retained as their changes from another value, for [`KeyframeDelta`].
+pub trait Changes: Sized {
+    /// Changes from another value.
+    type Changes: ByteSize;
+
+    /// Returns the changes from `base` to this value.
+    fn changes_from(&self, base: &Self) -> Self::Changes;
+
+    /// Rebuilds the value from `base` and its changes.
+    fn apply_changes(base: &Self, changes: &Self::Changes) -> Self;
+}
+
+enum Retained<T, C> {
+    Keyframe(T),
+    Changes(C),
+}
+
+impl<T: ByteSize, C: ByteSize> Retained<T, C> {
+    #[inline]
+    fn byte_size(&self) -> usize {
+        match self {
+            Self::Keyframe(value) => value.byte_size(),
+            Self::Changes(changes) => changes.byte_size(),
+        }
+    }
+}
+
+impl<T, P: EvictionPolicy<T>> BudgetedHistory<T, P> {
+    /// Creates a buffer with the given current and next values, no older values,
+    /// and `budget` bytes for the older values.
+    #[inline]
+    pub const fn new(current: T, next: T, budget: usize) -> Self {
+        Self {
+            generation: 0,
+            current,
+            next,
+            retained: VecDeque::new(),
+            budget,
+            used: 0,
+            policy: PhantomData,
+        }
+    }
+
+    /// Number of swaps since the buffer was created.
+    #[inline]
+    pub const fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Number of published values retained, including the current one.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.retained.len() + 1
+    }
+
+    /// Always `false`, there is always a current value.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Maximum number of bytes of the values retained before the current one.
+    #[inline]
+    pub const fn budget(&self) -> usize {
+        self.budget
+    }
+
+    /// Number of bytes of the values retained before the current one.
+    #[inline]
+    pub const fn used_bytes(&self) -> usize {
+        self.used
+    }
+
+    /// Gives access to the current value and mutable access to the next value at once.
+    #[inline]
+    pub fn split_mut(&mut self) -> (&T, &mut T) {
+        (&self.current, &mut self.next)
+    }
+}
+
+impl<T: ByteSize + Clone, P: EvictionPolicy<T>> BudgetedHistory<T, P> {
+    /// Publishes the next value and retains the previous current value, evicting
+    /// the oldest values over the budget, then writes will be over the previous
+    /// current value.
+    pub fn swap(&mut self) {
+        core::mem::swap(&mut self.current, &mut self.next);
+        self.generation = self.generation.wrapping_add(1);
+        let keyframe =
+            self.retained
+                .iter()
+                .rev()
+                .enumerate()
+                .find_map(|(since_keyframe, retained)| match retained {
+                    Retained::Keyframe(keyframe) => Some((since_keyframe, keyframe)),
+                    Retained::Changes(_) => None,
+                });
+        let retained = keyframe
+            .and_then(|(since_keyframe, keyframe)| P::changes(since_keyframe, keyframe, &self.next))
+            .map_or_else(|| Retained::Keyframe(self.next.clone()), Retained::Changes);
+        self.used += retained.byte_size();
+        self.retained.push_back(retained);
+        self.evict_over_budget();
+    }
+
+    /// Sets the budget of the values retained before the current one, evicting
+    /// the oldest values over it.
+    #[inline]
+    pub fn set_budget(&mut self, bytes: usize) {
+        self.budget = bytes;
+        self.evict_over_budget();
+    }
+
+    /// Gives the value published `nth_back` swaps ago, `0` being the current
+    /// value, rebuilding it if it was retained as changes.
+    ///
+    /// Returns `None` if it was evicted or is older than the buffer.
+    pub fn get(&self, nth_back: usize) -> Option<Cow<'_, T>> {
+        if nth_back == 0 {
+            return Some(Cow::Borrowed(&self.current));
+        }
+        let index = self.retained.len().checked_sub(nth_back)?;
+        match &self.retained[index] {
+            Retained::Keyframe(value) => Some(Cow::Borrowed(value)),
+            Retained::Changes(changes) => {
+                let keyframe =
+                    self.retained
+                        .range(..index)
+                        .rev()
+                        .find_map(|retained| match retained {
+                            Retained::Keyframe(keyframe) => Some(keyframe),
+                            Retained::Changes(_) => None,
+                        })?;
+                Some(Cow::Owned(P::apply(keyframe, changes)))
+            }
+        }
+    }
+
+    /// Restores the value published at `generation` as the current value and the
+    /// next value, discarding the newer values.
+    ///
+    /// Returns `false`, without changing anything, if that generation is not retained.
+    pub fn rollback(&mut self, generation: u64) -> bool {
+        let Some(nth_back) = self.generation.checked_sub(generation) else {
+            return false;
+        };
+        let Ok(nth_back) = usize::try_from(nth_back) else {
+            return false;
+        };
+        let Some(value) = self.get(nth_back).map(Cow::into_owned) else {
+            return false;
+        };
+        for _ in 0..nth_back {
+            if let Some(retained) = self.retained.pop_back() {
+                self.used -= retained.byte_size();
+            }
+        }
+        self.next.clone_from(&value);
+        self.current = value;
+        self.generation = generation;
+        true
+    }
+
+    fn evict_over_budget(&mut self) {
+        while self.used > self.budget {
+            let Some(oldest) = self.retained.pop_front() else {
+                break;
+            };
+            self.used -= oldest.byte_size();
+            // The changes from an evicted keyframe can't be rebuilt anymore.
+            while let Some(Retained::Changes(_)) = self.retained.front() {
+                if let Some(changes) = self.retained.pop_front() {
+                    self.used -= changes.byte_size();
+                }
+            }
+        }
+    }
+}
+
+impl<T> EvictionPolicy<T> for DropOldest {
+    type Changes = Infallible;
+
+    #[inline]
+    fn changes(_: usize, _: &T, _: &T) -> Option<Infallible> {
+        None
+    }
+
+    #[inline]
+    fn apply(_: &T, changes: &Infallible) -> T {
+        match *changes {}
+    }
+}
+
+impl<T: Changes, const INTERVAL: usize> EvictionPolicy<T> for KeyframeDelta<INTERVAL> {
+    type Changes = T::Changes;
+
+    #[inline]
+    fn changes(since_keyframe: usize, keyframe: &T, value: &T) -> Option<T::Changes> {
+        (since_keyframe + 1 < INTERVAL).then(|| value.changes_from(keyframe))
+    }
+
+    #[inline]
+    fn apply(keyframe: &T, changes: &T::Changes) -> T {
+        T::apply_changes(keyframe, changes)
+    }
+}
+
+/// The elements that differ from the base, with their index.
+impl<E: ByteSize + Clone + PartialEq, const N: usize> Changes for [E; N] {
+    type Changes = Vec<(usize, E)>;
+
+    fn changes_from(&self, base: &Self) -> Self::Changes {
+        let changed = self
+            .iter()
+            .zip(base)
+            .enumerate()
+            .filter(|(_, (value, base))| value != base);
+        changed
+            .map(|(index, (value, _))| (index, value.clone()))
+            .collect()
+    }
+
+    fn apply_changes(base: &Self, changes: &Self::Changes) -> Self {
+        let mut value = base.clone();
+        for (index, element) in changes {
+            value[*index] = element.clone();
+        }
+        value
+    }
+}
+
+/// The length, and the elements that differ from the base or are past its end,
+/// with their index.
+impl<E: ByteSize + Clone + PartialEq> Changes for Vec<E> {
+    type Changes = (usize, Vec<(usize, E)>);
+
+    fn changes_from(&self, base: &Self) -> Self::Changes {
+        let changed = self
+            .iter()
+            .enumerate()
+            .filter(|(index, value)| base.get(*index) != Some(value));
+        (
+            self.len(),
+            changed
+                .map(|(index, value)| (index, value.clone()))
+                .collect(),
+        )
+    }
+
+    fn apply_changes(base: &Self, (len, changes): &Self::Changes) -> Self {
+        let mut value = base.clone();
+        value.truncate(*len);
+        for (index, element) in changes {
+            match value.get_mut(*index) {
+                Some(value) => *value = element.clone(),
+                None => value.push(element.clone()),
+            }
+        }
+        value
+    }
+}
+
+impl<T: Debug, P: EvictionPolicy<T>> Debug for BudgetedHistory<T, P> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BudgetedHistory")
+            .field("current", &self.current)
+            .field("next", &self.next)
+            .field("generation", &self.generation)
+            .field("len", &self.len())
+            .field("used_bytes", &self.used)
+            .field("budget", &self.budget)
+            .finish()
+    }
+}
+
+impl<T, P: EvictionPolicy<T>> Deref for BudgetedHistory<T, P> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.current
+    }
+}
+
+impl<T, P: EvictionPolicy<T>> DerefMut for BudgetedHistory<T, P> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drop_oldest_within_budget() {
+        let mut history: BudgetedHistory<u64> = BudgetedHistory::new(0, 0, 24);
+        for value in 1..=5 {
+            *history = value;
+            history.swap();
+        }
+        assert_eq!(history.len(), 4);
+        assert_eq!(history.used_bytes(), 24);
+        assert_eq!(history.get(3).as_deref(), Some(&2));
+        assert_eq!(history.get(4), None);
+
+        history.set_budget(8);
+        assert_eq!(history.len(), 2);
+        assert!(history.rollback(4));
+        assert_eq!((*history, history.used_bytes()), (4, 0));
+    }
+
+    #[test]
+    fn test_keyframe_evicted_with_its_changes() {
+        let mut history: BudgetedHistory<[u8; 4], KeyframeDelta<2>> =
+            BudgetedHistory::new([0; 4], [0; 4], usize::MAX);
+        for value in 1..=4 {
+            history[0] = value;
+            history.swap();
+        }
+        // Keyframes of generations 0 and 2, changes of 1 and 3.
+        let changes = [1_u8, 0, 0, 0].changes_from(&[0; 4]).byte_size();
+        assert_eq!(history.used_bytes(), 4 + changes + 4 + changes);
+        assert_eq!(history.get(3).as_deref(), Some(&[1, 0, 0, 0]));
+
+        history.set_budget(history.used_bytes() - 1);
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.get(1).as_deref(), Some(&[3, 0, 0, 0]));
+        assert_eq!(history.get(3), None);
+    }
+
+    #[test]
+    fn test_vec_changes_with_other_length() {
+        let base = Vec::from([1, 2, 3]);
+        for value in [Vec::from([1, 5]), Vec::from([1, 2, 3, 4, 5])] {
+            let changes = value.changes_from(&base);
+            assert_eq!(Vec::apply_changes(&base, &changes), value);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use core::convert::Infallible;
+
+/// Number of bytes used by a value, including its heap allocations.
+///
+/// It's used by [`BudgetedHistory`](crate::BudgetedHistory) to keep the retained
+/// values within a byte budget.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::ByteSize;
+/// struct Frame {
+///     pixels: [u8; 64],
+///     frame_number: u64,
+/// }
+///
+/// impl ByteSize for Frame {
+///     fn byte_size(&self) -> usize {
+///         self.pixels.byte_size() + self.frame_number.byte_size()
+///     }
+/// }
+/// ```
+pub trait ByteSize {
+    /// Returns the bytes used by the value, including its heap allocations.
+    fn byte_size(&self) -> usize;
+}
+
+macro_rules! impl_byte_size_for_primitives {
+    ($($primitive:ty),*) => {
+        $(
+            impl ByteSize for $primitive {
+                #[inline]
+                fn byte_size(&self) -> usize {
+                    size_of::<Self>()
+                }
+            }
+        )*
+    };
+}
+
+impl_byte_size_for_primitives!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    bool,
+    char,
+    ()
+);
+
+impl ByteSize for Infallible {
+    #[inline]
+    fn byte_size(&self) -> usize {
+        match *self {}
+    }
+}
+
+impl<T: ByteSize, const N: usize> ByteSize for [T; N] {
+    #[inline]
+    fn byte_size(&self) -> usize {
+        self.iter().map(ByteSize::byte_size).sum()
+    }
+}
+
+impl<A: ByteSize, B: ByteSize> ByteSize for (A, B) {
+    #[inline]
+    fn byte_size(&self) -> usize {
+        self.0.byte_size() + self.1.byte_size()
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod alloc_impls {
+    use super::ByteSize;
+    use alloc::boxed::Box;
+    use alloc::collections::VecDeque;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    /// The vector, its items and its spare capacity.
+    impl<T: ByteSize> ByteSize for Vec<T> {
+        #[inline]
+        fn byte_size(&self) -> usize {
+            let spare = (self.capacity() - self.len()) * size_of::<T>();
+            size_of::<Self>() + spare + self.iter().map(ByteSize::byte_size).sum::<usize>()
+        }
+    }
+
+    /// The deque, its items and its spare capacity.
+    impl<T: ByteSize> ByteSize for VecDeque<T> {
+        #[inline]
+        fn byte_size(&self) -> usize {
+            let spare = (self.capacity() - self.len()) * size_of::<T>();
+            size_of::<Self>() + spare + self.iter().map(ByteSize::byte_size).sum::<usize>()
+        }
+    }
+
+    /// The string and its capacity.
+    impl ByteSize for String {
+        #[inline]
+        fn byte_size(&self) -> usize {
+            size_of::<Self>() + self.capacity()
+        }
+    }
+
+    impl<T: ByteSize + ?Sized> ByteSize for Box<T> {
+        #[inline]
+        fn byte_size(&self) -> usize {
+            size_of::<Self>() + T::byte_size(self.as_ref())
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_vec_counts_spare_capacity() {
+        let mut values: Vec<u32> = Vec::with_capacity(4);
+        values.push(1);
+        assert_eq!(values.byte_size(), size_of::<Vec<u32>>() + 16);
+    }
+}
@@ -0,0 +1,205 @@
+use core::cell::Cell;
+use core::fmt::{Debug, Formatter};
+
+use crate::{SlotId, Swap};
+
+/// Double buffer of a [`Copy`] value with interior mutability, so closures and
+/// callbacks on the same thread can read the current value, write the next value
+/// and swap through shared references, without the runtime checks of a `RefCell`.
+///
+/// Like [`Cell`], values are copied in and out, it never gives references to them.
+/// For arrays, [`DoubleBufferCell::current_cells()`] and
+/// [`DoubleBufferCell::next_cells()`] give access to single elements, like the bytes
+/// of a frame, without copying the whole array.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::DoubleBufferCell;
+/// let score = DoubleBufferCell::new(0u32, 0);
+///
+/// let on_hit = || score.update_next(|score| score + 10);
+/// let on_frame = || score.swap();
+///
+/// on_hit();
+/// on_hit();
+/// assert_eq!(score.get(), 0);
+///
+/// on_frame();
+/// assert_eq!(score.get(), 20);
+/// ```
+pub struct DoubleBufferCell<T> {
+    current: Cell<SlotId>,
+    generation: Cell<u64>,
+    buffers: [Cell<T>; 2],
+}
+
+impl<T> DoubleBufferCell<T> {
+    #[inline]
+    pub const fn new(current: T, next: T) -> Self {
+        Self {
+            current: Cell::new(SlotId::FIRST),
+            generation: Cell::new(0),
+            buffers: [Cell::new(current), Cell::new(next)],
+        }
+    }
+
+    /// Swaps the current and next values, then writes will be over the previous current value.
+    #[inline]
+    pub fn swap(&self) {
+        self.current.set(self.current.get().other());
+        self.generation.set(self.generation.get().wrapping_add(1));
+    }
+
+    /// Swaps like [`DoubleBufferCell::swap()`] only if the generation is still
+    /// `expected`, the one observed when the next value started being written,
+    /// see [`SharedDoubleBuffer::compare_and_publish()`](crate::SharedDoubleBuffer::compare_and_publish()).
+    ///
+    /// On conflict, it returns a copy of the current value.
+    #[inline]
+    pub fn compare_and_swap(&self, expected: u64) -> Result<(), T>
+    where
+        T: Copy,
+    {
+        if self.generation.get() != expected {
+            return Err(self.get());
+        }
+        self.swap();
+        Ok(())
+    }
+
+    /// Number of swaps since it was created, see [`DoubleBuffer::generation()`](crate::DoubleBuffer::generation()).
+    #[inline]
+    pub fn generation(&self) -> u64 {
+        self.generation.get()
+    }
+
+    /// Sets the next value.
+    #[inline]
+    pub fn set_next(&self, value: T) {
+        self.next_cell().set(value);
+    }
+
+    /// Replaces the next value, returning the previous one.
+    #[inline]
+    pub fn replace_next(&self, value: T) -> T {
+        self.next_cell().replace(value)
+    }
+
+    /// Gives access to the current value and mutable access to the next value at once,
+    /// without copying them, as it's borrowed exclusively.
+    #[inline]
+    pub fn split_mut(&mut self) -> (&T, &mut T) {
+        let current = self.current.get();
+        let [first, second] = &mut self.buffers;
+        let (current, next) = if current == SlotId::FIRST {
+            (first, second)
+        } else {
+            (second, first)
+        };
+        (current.get_mut(), next.get_mut())
+    }
+
+    #[inline]
+    fn current_cell(&self) -> &Cell<T> {
+        &self.buffers[self.current.get().index()]
+    }
+
+    #[inline]
+    fn next_cell(&self) -> &Cell<T> {
+        &self.buffers[self.current.get().other().index()]
+    }
+}
+
+impl<T: Copy> DoubleBufferCell<T> {
+    /// Returns a copy of the current value.
+    #[inline]
+    pub fn get(&self) -> T {
+        self.current_cell().get()
+    }
+
+    /// Returns a copy of the next value.
+    #[inline]
+    pub fn next(&self) -> T {
+        self.next_cell().get()
+    }
+
+    /// Sets the next value to the result of `f` with a copy of the next value.
+    #[inline]
+    pub fn update_next(&self, f: impl FnOnce(T) -> T) {
+        self.set_next(f(self.next()));
+    }
+}
+
+impl<T, const N: usize> DoubleBufferCell<[T; N]> {
+    /// Gives access to the elements of the current value as cells.
+    ///
+    /// The cells of the current value can be written too, but then the value is
+    /// published partially written, so prefer to only read them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBufferCell;
+    /// let frame = DoubleBufferCell::new([0u8; 4], [0; 4]);
+    /// frame.next_cells()[1].set(7);
+    /// frame.swap();
+    /// assert_eq!(frame.current_cells()[1].get(), 7);
+    /// ```
+    #[inline]
+    pub fn current_cells(&self) -> &[Cell<T>] {
+        let cell: &Cell<[T]> = self.current_cell();
+        cell.as_slice_of_cells()
+    }
+
+    /// Gives access to the elements of the next value as cells.
+    #[inline]
+    pub fn next_cells(&self) -> &[Cell<T>] {
+        let cell: &Cell<[T]> = self.next_cell();
+        cell.as_slice_of_cells()
+    }
+}
+
+impl<T> Swap for DoubleBufferCell<T> {
+    #[inline]
+    fn swap_buffers(&mut self) {
+        self.swap();
+    }
+}
+
+impl<T: Copy + Debug> Debug for DoubleBufferCell<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DoubleBufferCell")
+            .field("current", &self.get())
+            .field("next", &self.next())
+            .finish()
+    }
+}
+
+impl<T: Default> Default for DoubleBufferCell<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default(), T::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shared_swaps() {
+        let buffer = DoubleBufferCell::new(1, 2);
+        assert_eq!(buffer.replace_next(3), 2);
+        buffer.swap();
+        assert_eq!((buffer.get(), buffer.next()), (3, 1));
+        assert_eq!(buffer.generation(), 1);
+        assert_eq!(buffer.compare_and_swap(0), Err(3));
+
+        let mut buffer = buffer;
+        let (current, next) = buffer.split_mut();
+        *next = *current + 1;
+        assert_eq!((buffer.get(), buffer.next()), (3, 4));
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{DoubleBuffer, IncompleteFrame, MoveSwap, SwapStrategy};
+
+/// Wraps a [`DoubleBuffer`] of bytes that receives frames in chunks, like
+/// packets from a socket or a UART, and only publishes complete frames.
+///
+/// [`ChunkedBuffer::start_frame()`] sets the expected length of the frame,
+/// [`ChunkedBuffer::write()`] copies chunks to the next value as they arrive,
+/// and [`ChunkedBuffer::publish()`] fails until all the bytes arrived, so a
+/// partially received frame never becomes current.
+///
+/// The expected length is kept for the next frame, for fixed length frames.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{ChunkedBuffer, DoubleBuffer};
+/// let mut packets = ChunkedBuffer::new(DoubleBuffer::new([0u8; 8], [0; 8]));
+/// packets.start_frame(5);
+///
+/// assert_eq!(packets.write(&[1, 2, 3]), 3);
+/// assert!(packets.publish().is_err());
+///
+/// // Bytes over the expected length are left for the next frame.
+/// assert_eq!(packets.write(&[4, 5, 6]), 2);
+/// assert!(packets.publish().is_ok());
+/// assert_eq!(packets.published(), [1, 2, 3, 4, 5]);
+/// ```
+#[derive(Debug)]
+pub struct ChunkedBuffer<T, S = MoveSwap> {
+    buffer: DoubleBuffer<T, S>,
+    expected: usize,
+    received: usize,
+    published: usize,
+}
+
+impl<T: AsRef<[u8]> + AsMut<[u8]>, S> ChunkedBuffer<T, S> {
+    /// Wraps `buffer` expecting empty frames until [`ChunkedBuffer::start_frame()`].
+    #[inline]
+    pub const fn new(buffer: DoubleBuffer<T, S>) -> Self {
+        Self {
+            buffer,
+            expected: 0,
+            received: 0,
+            published: 0,
+        }
+    }
+
+    #[inline]
+    pub const fn buffer(&self) -> &DoubleBuffer<T, S> {
+        &self.buffer
+    }
+
+    /// Discards the bytes received and starts receiving a frame of `len` bytes.
+    ///
+    /// # Panics
+    ///
+    /// If `len` is longer than the next value.
+    #[inline]
+    pub fn start_frame(&mut self, len: usize) {
+        assert!(
+            len <= self.buffer.next().as_ref().len(),
+            "frame of {len} bytes is longer than the buffer"
+        );
+        self.expected = len;
+        self.received = 0;
+    }
+
+    /// Copies the bytes of `chunk` that belong to the frame to the next value,
+    /// returning how many were copied.
+    #[inline]
+    pub fn write(&mut self, chunk: &[u8]) -> usize {
+        let len = chunk.len().min(self.remaining());
+        let start = self.received;
+        self.buffer.as_mut().as_mut()[start..start + len].copy_from_slice(&chunk[..len]);
+        self.received += len;
+        len
+    }
+
+    /// Expected length of the frame being received.
+    #[inline]
+    pub const fn expected(&self) -> usize {
+        self.expected
+    }
+
+    /// Number of bytes of the frame received.
+    #[inline]
+    pub const fn received(&self) -> usize {
+        self.received
+    }
+
+    /// Number of bytes missing to complete the frame.
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        self.expected - self.received
+    }
+
+    #[inline]
+    pub const fn is_complete(&self) -> bool {
+        self.received == self.expected
+    }
+
+    /// Gives access to the bytes of the frame received so far.
+    #[inline]
+    pub fn staged(&self) -> &[u8] {
+        &self.buffer.next().as_ref()[..self.received]
+    }
+
+    /// Gives access to the bytes of the last published frame.
+    #[inline]
+    pub fn published(&self) -> &[u8] {
+        &self.buffer.current().as_ref()[..self.published]
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> DoubleBuffer<T, S> {
+        self.buffer
+    }
+}
+
+impl<T: AsRef<[u8]> + AsMut<[u8]>, S: SwapStrategy<T>> ChunkedBuffer<T, S> {
+    /// Swaps like [`DoubleBuffer::swap()`] if the frame is complete, then the next
+    /// frame with the same length is received.
+    #[inline]
+    pub fn publish(&mut self) -> Result<(), IncompleteFrame> {
+        if !self.is_complete() {
+            return Err(IncompleteFrame {
+                received: self.received,
+                expected: self.expected,
+            });
+        }
+        self.buffer.swap();
+        self.published = self.received;
+        self.received = 0;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restart_discards_partial_frame() {
+        let mut packets = ChunkedBuffer::new(DoubleBuffer::new([0u8; 4], [0; 4]));
+        packets.start_frame(2);
+        packets.write(&[1]);
+        packets.start_frame(3);
+        assert!(packets.staged().is_empty());
+        assert_eq!(packets.write(&[7, 8, 9, 10]), 3);
+        packets.publish().unwrap();
+        assert_eq!(packets.published(), [7, 8, 9]);
+        assert_eq!(packets.remaining(), 3);
+    }
+}
@@ -0,0 +1,226 @@
+/// Resets a value in place, keeping any allocated capacity.
+///
+/// It's used by [`DoubleBuffer::swap_with_clear()`](crate::DoubleBuffer::swap_with_clear)
+/// to reset the next value without dropping it and allocating a new one, like
+/// [`DoubleBuffer::swap_with_default()`](crate::DoubleBuffer::swap_with_default) does.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::Clear;
+/// struct Frame {
+///     pixels: [u8; 4],
+///     frame_number: u64,
+/// }
+///
+/// impl Clear for Frame {
+///     fn clear(&mut self) {
+///         self.pixels.clear();
+///         self.frame_number = 0;
+///     }
+/// }
+/// ```
+pub trait Clear {
+    /// Resets the value in place.
+    fn clear(&mut self);
+}
+
+impl<T: Default, const N: usize> Clear for [T; N] {
+    #[inline]
+    fn clear(&mut self) {
+        for item in self.iter_mut() {
+            *item = T::default();
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod alloc_impls {
+    use super::Clear;
+    use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    impl<T> Clear for Vec<T> {
+        #[inline]
+        fn clear(&mut self) {
+            Vec::clear(self);
+        }
+    }
+
+    impl Clear for String {
+        #[inline]
+        fn clear(&mut self) {
+            String::clear(self);
+        }
+    }
+
+    impl<T> Clear for VecDeque<T> {
+        #[inline]
+        fn clear(&mut self) {
+            VecDeque::clear(self);
+        }
+    }
+
+    impl<T> Clear for BinaryHeap<T> {
+        #[inline]
+        fn clear(&mut self) {
+            BinaryHeap::clear(self);
+        }
+    }
+
+    impl<T> Clear for LinkedList<T> {
+        #[inline]
+        fn clear(&mut self) {
+            LinkedList::clear(self);
+        }
+    }
+
+    impl<K, V> Clear for BTreeMap<K, V> {
+        #[inline]
+        fn clear(&mut self) {
+            BTreeMap::clear(self);
+        }
+    }
+
+    impl<T> Clear for BTreeSet<T> {
+        #[inline]
+        fn clear(&mut self) {
+            BTreeSet::clear(self);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod std_impls {
+    use super::Clear;
+    use std::collections::{HashMap, HashSet};
+
+    impl<K, V, S> Clear for HashMap<K, V, S> {
+        #[inline]
+        fn clear(&mut self) {
+            HashMap::clear(self);
+        }
+    }
+
+    impl<T, S> Clear for HashSet<T, S> {
+        #[inline]
+        fn clear(&mut self) {
+            HashSet::clear(self);
+        }
+    }
+}
+
+#[cfg(feature = "heapless")]
+mod heapless_impls {
+    use super::Clear;
+
+    impl<T, const N: usize> Clear for heapless::Vec<T, N> {
+        #[inline]
+        fn clear(&mut self) {
+            heapless::Vec::clear(self);
+        }
+    }
+
+    impl<const N: usize> Clear for heapless::String<N> {
+        #[inline]
+        fn clear(&mut self) {
+            heapless::String::clear(self);
+        }
+    }
+
+    impl<T, const N: usize> Clear for heapless::Deque<T, N> {
+        #[inline]
+        fn clear(&mut self) {
+            heapless::Deque::clear(self);
+        }
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+mod arrayvec_impls {
+    use super::Clear;
+
+    impl<T, const CAP: usize> Clear for arrayvec::ArrayVec<T, CAP> {
+        #[inline]
+        fn clear(&mut self) {
+            arrayvec::ArrayVec::clear(self);
+        }
+    }
+
+    impl<const CAP: usize> Clear for arrayvec::ArrayString<CAP> {
+        #[inline]
+        fn clear(&mut self) {
+            arrayvec::ArrayString::clear(self);
+        }
+    }
+}
+
+#[cfg(feature = "smallvec")]
+mod smallvec_impls {
+    use super::Clear;
+
+    impl<A: smallvec::Array> Clear for smallvec::SmallVec<A> {
+        #[inline]
+        fn clear(&mut self) {
+            smallvec::SmallVec::clear(self);
+        }
+    }
+}
+
+#[cfg(feature = "tinyvec")]
+mod tinyvec_impls {
+    use super::Clear;
+
+    impl<A: tinyvec::Array> Clear for tinyvec::ArrayVec<A> {
+        #[inline]
+        fn clear(&mut self) {
+            tinyvec::ArrayVec::clear(self);
+        }
+    }
+
+    impl<T: Default> Clear for tinyvec::SliceVec<'_, T> {
+        #[inline]
+        fn clear(&mut self) {
+            tinyvec::SliceVec::clear(self);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<A: tinyvec::Array> Clear for tinyvec::TinyVec<A> {
+        #[inline]
+        fn clear(&mut self) {
+            tinyvec::TinyVec::clear(self);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clear_array() {
+        let mut array = [1u8, 2, 3];
+        array.clear();
+        assert_eq!(array, [0, 0, 0]);
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn test_clear_heapless_vec() {
+        let mut vec: heapless::Vec<u8, 4> = heapless::Vec::from_slice(&[1, 2]).unwrap();
+        Clear::clear(&mut vec);
+        assert!(vec.is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_clear_vec_keeps_capacity() {
+        let mut vec = alloc::vec![1u8; 64];
+        let capacity = vec.capacity();
+        Clear::clear(&mut vec);
+        assert!(vec.is_empty());
+        assert_eq!(vec.capacity(), capacity);
+    }
+}
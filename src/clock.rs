@@ -0,0 +1,74 @@
+/// Source of time for the types recording when a buffer was published.
+///
+/// With the `std` feature, [`StdClock`] uses [`std::time::Instant`]. In `no_std`
+/// it can be implemented over a hardware timer or a tick counter.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::Clock;
+/// use core::sync::atomic::{AtomicU32, Ordering};
+///
+/// static TICKS: AtomicU32 = AtomicU32::new(0);
+///
+/// struct TickClock;
+///
+/// impl Clock for TickClock {
+///     type Instant = u32;
+///     type Duration = u32;
+///
+///     fn now(&self) -> u32 {
+///         TICKS.load(Ordering::Relaxed)
+///     }
+///
+///     fn elapsed(&self, since: u32) -> u32 {
+///         self.now().wrapping_sub(since)
+///     }
+/// }
+/// ```
+pub trait Clock {
+    type Instant: Copy;
+    type Duration;
+
+    /// Current time.
+    fn now(&self) -> Self::Instant;
+
+    /// Time elapsed from `since` until now.
+    fn elapsed(&self, since: Self::Instant) -> Self::Duration;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    type Instant = C::Instant;
+    type Duration = C::Duration;
+
+    #[inline]
+    fn now(&self) -> Self::Instant {
+        (**self).now()
+    }
+
+    #[inline]
+    fn elapsed(&self, since: Self::Instant) -> Self::Duration {
+        (**self).elapsed(since)
+    }
+}
+
+/// [`Clock`] using [`std::time::Instant`].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct StdClock;
+
+#[cfg(feature = "std")]
+impl Clock for StdClock {
+    type Instant = std::time::Instant;
+    type Duration = std::time::Duration;
+
+    #[inline]
+    fn now(&self) -> Self::Instant {
+        std::time::Instant::now()
+    }
+
+    #[inline]
+    fn elapsed(&self, since: Self::Instant) -> Self::Duration {
+        since.elapsed()
+    }
+}
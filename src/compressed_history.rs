@@ -0,0 +1,199 @@
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::ops::{Deref, DerefMut};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::{MoveSwap, PersistError, SwapStrategy, TrackedBuffer};
+
+/// Wraps a [`TrackedBuffer`] retaining the last published values compressed, so
+/// deep rollback windows fit in memory when the values are large.
+///
+/// Unlike [`HistoryBuffer`](crate::HistoryBuffer), which keeps `DEPTH` values
+/// and reuses them as the next value, every swap serializes the current value
+/// with `postcard` and compresses it with LZ4 before publishing the next one, and
+/// [`CompressedHistory::rollback()`] decompresses the retained value back. Values
+/// of consecutive frames usually differ little, so they compress well.
+///
+/// The history is bounded by its depth, evicting the oldest values first. To
+/// bound it by bytes instead, see [`BudgetedHistory`](crate::BudgetedHistory).
+///
+/// It dereferences to the current value and mutably dereferences to the next
+/// value, and all swaps must go through the wrapper, so the inner buffer is only
+/// accessible immutably with [`CompressedHistory::buffer()`].
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{CompressedHistory, DoubleBuffer};
+/// let mut world = CompressedHistory::new(DoubleBuffer::tracked(vec![0u32; 1024], vec![0; 1024]), 60);
+///
+/// for frame in 1..=10 {
+///     world[0] = frame;
+///     world.swap().unwrap();
+/// }
+/// assert!(world.compressed_len() < 10 * 4096);
+///
+/// assert_eq!(world.rollback(4), Ok(true));
+/// assert_eq!(world[0], 4);
+/// assert_eq!(world.len(), 4);
+/// ```
+#[derive(Debug)]
+pub struct CompressedHistory<T, S = MoveSwap> {
+    buffer: TrackedBuffer<T, S>,
+    depth: usize,
+    // Oldest first, with the generation of each value.
+    snapshots: VecDeque<(u64, Vec<u8>)>,
+    compressed_len: usize,
+}
+
+impl<T, S> CompressedHistory<T, S> {
+    /// Wraps `buffer` retaining up to `depth` values before the current one.
+    #[inline]
+    pub const fn new(buffer: TrackedBuffer<T, S>, depth: usize) -> Self {
+        Self {
+            buffer,
+            depth,
+            snapshots: VecDeque::new(),
+            compressed_len: 0,
+        }
+    }
+
+    #[inline]
+    pub const fn buffer(&self) -> &TrackedBuffer<T, S> {
+        &self.buffer
+    }
+
+    /// Number of values retained before the current one.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    /// Number of bytes of the retained values, compressed.
+    #[inline]
+    pub const fn compressed_len(&self) -> usize {
+        self.compressed_len
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> TrackedBuffer<T, S> {
+        self.buffer
+    }
+}
+
+impl<T: Serialize, S: SwapStrategy<T>> CompressedHistory<T, S> {
+    /// Compresses the current value into the history, evicting the oldest value
+    /// past the depth, then swaps like [`DoubleBuffer::swap()`].
+    ///
+    /// Nothing is changed if the current value can't be serialized.
+    pub fn swap(&mut self) -> Result<(), PersistError> {
+        if self.depth > 0 {
+            let bytes =
+                postcard::to_allocvec(self.buffer.current()).map_err(PersistError::Serialize)?;
+            let bytes = lz4_flex::compress_prepend_size(&bytes);
+            if self.snapshots.len() == self.depth {
+                if let Some((_, oldest)) = self.snapshots.pop_front() {
+                    self.compressed_len -= oldest.len();
+                }
+            }
+            self.compressed_len += bytes.len();
+            self.snapshots.push_back((self.buffer.generation(), bytes));
+        }
+        self.buffer.swap();
+        Ok(())
+    }
+}
+
+impl<T: DeserializeOwned + Clone, S> CompressedHistory<T, S> {
+    /// Restores the value published at `generation` as the current value and the
+    /// next value, discarding the newer values.
+    ///
+    /// Returns `Ok(false)`, without changing anything, if that generation is not
+    /// retained, and an error, without changing anything, if the value can't be
+    /// decompressed or deserialized, or violates the invariant of the buffer.
+    pub fn rollback(&mut self, generation: u64) -> Result<bool, PersistError> {
+        if generation == self.buffer.generation() {
+            return Ok(true);
+        }
+        let Some(position) = self
+            .snapshots
+            .iter()
+            .position(|(retained, _)| *retained == generation)
+        else {
+            return Ok(false);
+        };
+        let bytes = lz4_flex::decompress_size_prepended(&self.snapshots[position].1)
+            .map_err(|_| PersistError::Decompress)?;
+        let value: T = postcard::from_bytes(&bytes).map_err(PersistError::Deserialize)?;
+        if !self.buffer.invariant_holds(&value) {
+            return Err(PersistError::Invalid);
+        }
+        let (current, next) = self.buffer.both_mut();
+        *current = value;
+        next.clone_from(current);
+        self.buffer.instrument.generation = generation;
+        self.buffer.instrument.dirty = false;
+        self.buffer.instrument.poisoned = false;
+        while self.snapshots.len() > position {
+            if let Some((_, bytes)) = self.snapshots.pop_back() {
+                self.compressed_len -= bytes.len();
+            }
+        }
+        Ok(true)
+    }
+}
+
+impl<T, S> Deref for CompressedHistory<T, S> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+impl<T, S> DerefMut for CompressedHistory<T, S> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DoubleBuffer;
+
+    #[test]
+    fn test_depth_evicts_oldest() {
+        let mut history = CompressedHistory::new(DoubleBuffer::tracked(0u32, 0), 2);
+        for value in 1..=4 {
+            *history = value;
+            history.swap().unwrap();
+        }
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.rollback(1), Ok(false));
+        assert_eq!(history.rollback(2), Ok(true));
+        assert_eq!((*history, history.buffer().generation()), (2, 2));
+        assert!(history.is_empty());
+        assert_eq!(history.compressed_len(), 0);
+    }
+
+    #[test]
+    fn test_corrupted_snapshot_is_an_error() {
+        let mut history = CompressedHistory::new(DoubleBuffer::tracked(0u32, 0), 2);
+        *history = 1;
+        history.swap().unwrap();
+        history.snapshots[0].1.truncate(2);
+
+        assert_eq!(history.rollback(0), Err(PersistError::Decompress));
+        assert_eq!((*history, history.len()), (1, 1));
+    }
+}
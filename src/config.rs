@@ -0,0 +1,148 @@
+use core::fmt::{Debug, Formatter};
+use core::ops::Deref;
+
+use serde::de::{DeserializeOwned, Deserializer};
+
+use crate::{ConfigError, DoubleBuffer, TrackedBuffer};
+
+/// Configuration reloaded from serialized bytes, published only if it's
+/// deserialized and validated, so readers never see a broken configuration.
+///
+/// [`ConfigBuffer::reload()`] deserializes in place into the staged value, reusing
+/// its allocations, checks it with the validation closure and publishes it.
+/// If any step fails, the published configuration is kept.
+///
+/// It dereferences to the published configuration.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{ConfigBuffer, ConfigError};
+/// #[derive(serde::Deserialize, Default)]
+/// struct Limits {
+///     max_connections: u32,
+/// }
+///
+/// let mut limits = ConfigBuffer::new(Limits { max_connections: 8 }, |limits: &Limits| {
+///     if limits.max_connections == 0 {
+///         return Err("max_connections must be positive");
+///     }
+///     Ok(())
+/// });
+///
+/// let bytes = postcard::to_allocvec(&0u32).unwrap();
+/// let result = limits.reload(&mut postcard::Deserializer::from_bytes(&bytes));
+/// assert!(matches!(result, Err(ConfigError::Invalid("max_connections must be positive"))));
+/// assert_eq!(limits.max_connections, 8);
+///
+/// let bytes = postcard::to_allocvec(&64u32).unwrap();
+/// limits.reload(&mut postcard::Deserializer::from_bytes(&bytes)).unwrap();
+/// assert_eq!(limits.max_connections, 64);
+/// ```
+pub struct ConfigBuffer<T, V> {
+    buffer: TrackedBuffer<T>,
+    validate: V,
+}
+
+impl<T, V> ConfigBuffer<T, V> {
+    /// Creates it with `config` published, validating the reloaded configurations
+    /// with `validate`.
+    #[inline]
+    pub fn new<E>(config: T, validate: V) -> Self
+    where
+        T: Default,
+        V: FnMut(&T) -> Result<(), E>,
+    {
+        Self {
+            buffer: DoubleBuffer::tracked(config, T::default()),
+            validate,
+        }
+    }
+
+    /// Number of configurations published since it was created.
+    #[inline]
+    pub const fn generation(&self) -> u64 {
+        self.buffer.generation()
+    }
+
+    #[inline]
+    pub const fn buffer(&self) -> &TrackedBuffer<T> {
+        &self.buffer
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> TrackedBuffer<T> {
+        self.buffer
+    }
+}
+
+impl<T: DeserializeOwned, V> ConfigBuffer<T, V> {
+    /// Deserializes a configuration into the staged value, validates it and
+    /// publishes it, or returns an error keeping the published configuration.
+    pub fn reload<'de, D: Deserializer<'de>, E>(
+        &mut self,
+        deserializer: D,
+    ) -> Result<(), ConfigError<D::Error, E>>
+    where
+        V: FnMut(&T) -> Result<(), E>,
+    {
+        T::deserialize_in_place(deserializer, self.buffer.as_mut())
+            .map_err(ConfigError::Deserialize)?;
+        (self.validate)(self.buffer.next()).map_err(ConfigError::Invalid)?;
+        self.buffer.swap();
+        Ok(())
+    }
+
+    /// Reloads like [`ConfigBuffer::reload()`] a configuration serialized with `postcard`.
+    #[cfg(feature = "postcard")]
+    #[inline]
+    pub fn reload_from_slice<E>(
+        &mut self,
+        bytes: &[u8],
+    ) -> Result<(), ConfigError<postcard::Error, E>>
+    where
+        V: FnMut(&T) -> Result<(), E>,
+    {
+        self.reload(&mut postcard::Deserializer::from_bytes(bytes))
+    }
+}
+
+impl<T: Debug, V> Debug for ConfigBuffer<T, V> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ConfigBuffer")
+            .field("published", self.buffer.current())
+            .field("generation", &self.buffer.generation())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, V> Deref for ConfigBuffer<T, V> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.buffer.current()
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_reload_reuses_staged() {
+        let mut ports = ConfigBuffer::new(Vec::from([80]), |_: &Vec<u16>| Ok::<_, ()>(()));
+        let bytes = postcard::to_allocvec(&Vec::from([443u16, 8080])).unwrap();
+        ports
+            .reload(&mut postcard::Deserializer::from_bytes(&bytes))
+            .unwrap();
+        assert_eq!(*ports, [443, 8080]);
+
+        let result = ports.reload(&mut postcard::Deserializer::from_bytes(&[2]));
+        assert!(matches!(result, Err(ConfigError::Deserialize(_))));
+        assert_eq!(*ports, [443, 8080]);
+        assert_eq!(ports.generation(), 1);
+    }
+}
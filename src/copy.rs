@@ -0,0 +1,62 @@
+use crate::{DoubleBuffer, Instrument, SwapKind};
+
+impl<E: Copy, const N: usize, S, I: Instrument<[E; N]>> DoubleBuffer<[E; N], S, I> {
+    /// Swaps like [`DoubleBuffer::swap_with_clone()`], but it's guaranteed to copy
+    /// the elements with `copy_from_slice`, a `memcpy`, so large framebuffers are
+    /// published at memory bandwidth.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let mut frame: DoubleBuffer<[u8; 4096]> = DoubleBuffer::new([0; 4096], [0; 4096]);
+    /// let address = format!("{:p}", frame);
+    ///
+    /// frame[0] = 255;
+    /// frame.swap_with_copy();
+    ///
+    /// assert_eq!(frame[0], 255);
+    /// assert_eq!(format!("{:p}", frame), address);
+    /// ```
+    #[inline]
+    pub fn swap_with_copy(&mut self) {
+        self.check_invariant();
+        let (current, next) = self.current_mut_and_next();
+        current.copy_from_slice(next);
+        self.published(SwapKind::Clone);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: Copy, S, I: Instrument<alloc::vec::Vec<E>>> DoubleBuffer<alloc::vec::Vec<E>, S, I> {
+    /// Swaps like [`DoubleBuffer::swap_with_clone()`], but it's guaranteed to copy
+    /// the elements with `copy_from_slice`, a `memcpy`, so large framebuffers are
+    /// published at memory bandwidth.
+    ///
+    /// The capacity of the current value is reused, it only allocates if the
+    /// next value is longer than it.
+    #[inline]
+    pub fn swap_with_copy(&mut self) {
+        self.check_invariant();
+        let (current, next) = self.current_mut_and_next();
+        current.clear();
+        current.extend_from_slice(next);
+        self.published(SwapKind::Clone);
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::TrackedBuffer;
+    use alloc::vec;
+
+    #[test]
+    fn test_swap_with_copy_vec_of_different_length() {
+        let mut buffer: TrackedBuffer<alloc::vec::Vec<u8>> =
+            DoubleBuffer::tracked(vec![1; 8], vec![2; 4]);
+        buffer.swap_with_copy();
+        assert_eq!(buffer, vec![2; 4]);
+        assert_eq!(buffer.swap_count(), 1);
+    }
+}
@@ -0,0 +1,138 @@
+use alloc::sync::Arc;
+use core::fmt::{Debug, Formatter};
+use core::ops::Deref;
+
+use crate::{DoubleBuffer, TrackedBuffer};
+
+/// Double buffer whose sides are shared with [`Arc`] and cloned on write.
+///
+/// Publishing shares the staged value with the published side, a pointer copy
+/// instead of the clone of [`DoubleBuffer::swap_with_clone()`]. The value is
+/// only cloned when it's staged again while it's still shared, by the published
+/// side or by a snapshot, so for large states that are rarely modified it
+/// avoids a clone per frame.
+///
+/// It dereferences to the published value.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::CowBuffer;
+/// let mut config: CowBuffer<Vec<u32>> = CowBuffer::new(vec![1, 2, 3]);
+///
+/// // Not modified, publishing doesn't clone.
+/// config.publish();
+/// assert!(config.is_shared());
+///
+/// // The first write clones the shared value.
+/// config.staged_mut().push(4);
+/// assert!(!config.is_shared());
+/// assert_eq!(*config, [1, 2, 3]);
+///
+/// config.publish();
+/// assert_eq!(*config, [1, 2, 3, 4]);
+/// ```
+pub struct CowBuffer<T> {
+    buffer: TrackedBuffer<Arc<T>>,
+}
+
+impl<T> CowBuffer<T> {
+    /// Creates it with the value published and staged, shared by both sides.
+    #[inline]
+    pub fn new(value: T) -> Self {
+        let value = Arc::new(value);
+        Self {
+            buffer: DoubleBuffer::tracked(Arc::clone(&value), value),
+        }
+    }
+
+    /// Gives access to the staged value.
+    #[inline]
+    pub fn staged(&self) -> &T {
+        self.buffer.next()
+    }
+
+    /// Gives mutable access to the staged value, cloning it first if it's shared.
+    #[inline]
+    pub fn staged_mut(&mut self) -> &mut T
+    where
+        T: Clone,
+    {
+        Arc::make_mut(self.buffer.as_mut())
+    }
+
+    /// Returns `true` if the staged value is shared, so writing it will clone it.
+    #[inline]
+    pub fn is_shared(&self) -> bool {
+        Arc::strong_count(self.buffer.next()) > 1
+    }
+
+    /// Publishes the staged value sharing it, then the following write will clone it.
+    #[inline]
+    pub fn publish(&mut self) {
+        self.buffer.swap_with_clone();
+    }
+
+    /// Shares the published value, to read it somewhere else after following publishes.
+    #[inline]
+    pub fn snapshot(&self) -> Arc<T> {
+        Arc::clone(self.buffer.current())
+    }
+
+    /// Number of swaps since it was created, see [`DoubleBuffer::generation()`].
+    #[inline]
+    pub const fn generation(&self) -> u64 {
+        self.buffer.generation()
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> TrackedBuffer<Arc<T>> {
+        self.buffer
+    }
+}
+
+impl<T: Debug> Debug for CowBuffer<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CowBuffer")
+            .field("published", self.buffer.current())
+            .field("staged", self.buffer.next())
+            .finish()
+    }
+}
+
+impl<T: Default> Default for CowBuffer<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> Deref for CowBuffer<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.buffer.current()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_keeps_value() {
+        let mut counter = CowBuffer::new(1);
+        let snapshot = counter.snapshot();
+
+        *counter.staged_mut() = 2;
+        counter.publish();
+        assert!(counter.is_shared());
+
+        *counter.staged_mut() = 3;
+        assert_eq!(*snapshot, 1);
+        assert_eq!(*counter, 2);
+        assert_eq!(*counter.staged(), 3);
+    }
+}
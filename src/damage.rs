@@ -0,0 +1,466 @@
+use core::ops::{Deref, Range};
+
+use crate::{Clear, DoubleBuffer, Instrument, MoveSwap, SwapKind, SwapStrategy};
+
+/// Rectangle of a 2D framebuffer, in pixels.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Rect {
+    #[inline]
+    pub const fn new(x: usize, y: usize, width: usize, height: usize) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Horizontal coordinate just after the rectangle.
+    #[inline]
+    pub const fn right(&self) -> usize {
+        self.x.saturating_add(self.width)
+    }
+
+    /// Vertical coordinate just after the rectangle.
+    #[inline]
+    pub const fn bottom(&self) -> usize {
+        self.y.saturating_add(self.height)
+    }
+
+    #[inline]
+    pub const fn area(&self) -> usize {
+        self.width.saturating_mul(self.height)
+    }
+
+    /// Smallest rectangle containing both rectangles.
+    #[inline]
+    pub fn union(&self, other: &Self) -> Self {
+        if self.is_empty() {
+            return *other;
+        }
+        if other.is_empty() {
+            return *self;
+        }
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        Self::new(
+            x,
+            y,
+            self.right().max(other.right()) - x,
+            self.bottom().max(other.bottom()) - y,
+        )
+    }
+
+    /// Part of the rectangle inside the other one, it may be empty.
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+        Self::new(x, y, right.saturating_sub(x), bottom.saturating_sub(y))
+    }
+
+    /// Returns `true` if the rectangles overlap or share an edge.
+    #[inline]
+    fn touches(&self, other: &Self) -> bool {
+        self.x <= other.right()
+            && other.x <= self.right()
+            && self.y <= other.bottom()
+            && other.y <= self.bottom()
+    }
+}
+
+/// Up to `N` rectangles, merged when they touch or when there is no room for more.
+#[derive(Debug, Clone, Copy)]
+struct Damage<const N: usize> {
+    rects: [Rect; N],
+    len: usize,
+}
+
+impl<const N: usize> Damage<N> {
+    const fn new() -> Self {
+        Self {
+            rects: [Rect::new(0, 0, 0, 0); N],
+            len: 0,
+        }
+    }
+
+    fn as_slice(&self) -> &[Rect] {
+        &self.rects[..self.len]
+    }
+
+    fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    fn add(&mut self, mut rect: Rect) {
+        if rect.is_empty() {
+            return;
+        }
+        // Absorb every rectangle touching the new one, it may grow and touch more.
+        let mut index = 0;
+        while index < self.len {
+            if self.rects[index].touches(&rect) {
+                rect = rect.union(&self.rects[index]);
+                self.len -= 1;
+                self.rects[index] = self.rects[self.len];
+                index = 0;
+            } else {
+                index += 1;
+            }
+        }
+        if self.len < N {
+            self.rects[self.len] = rect;
+            self.len += 1;
+            return;
+        }
+        let Some(closest) = self
+            .as_slice()
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, other)| other.union(&rect).area() - other.area())
+            .map(|(index, _)| index)
+        else {
+            return;
+        };
+        let merged = self.rects[closest].union(&rect);
+        self.len -= 1;
+        self.rects[closest] = self.rects[self.len];
+        self.add(merged);
+    }
+}
+
+/// Wraps a [`DoubleBuffer`] of a 2D framebuffer recording which rectangles of
+/// the next value were written, so partial-refresh displays and compositors can
+/// update only those rectangles after each swap.
+///
+/// The framebuffer is a slice of `width` × `height` pixels in row-major order.
+/// Damage is kept in up to `N` rectangles, merging them when there are more,
+/// so `N` must be at least `1`.
+///
+/// Writes must go through the wrapper to be recorded, the inner buffer is only
+/// accessible immutably through [`Deref`].
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{DamageTracked, DoubleBuffer, Rect};
+/// let mut frame: DamageTracked<[u8; 64]> = DamageTracked::new(DoubleBuffer::new([0; 64], [0; 64]), 8, 8);
+///
+/// frame.set(1, 1, 255);
+/// frame.fill_rect(Rect::new(4, 4, 2, 2), 128);
+/// frame.swap();
+///
+/// assert!(frame.damage().eq(&[Rect::new(1, 1, 1, 1), Rect::new(4, 4, 2, 2)]));
+/// ```
+///
+/// Without room for a rectangle, the damage would be lost, so it doesn't compile:
+///
+/// ```compile_fail
+/// # use double_buffer::{DamageTracked, DoubleBuffer, MoveSwap};
+/// let frame: DamageTracked<[u8; 4], MoveSwap, 0> = DamageTracked::new(DoubleBuffer::default(), 2, 2);
+/// ```
+pub struct DamageTracked<T, S = MoveSwap, const N: usize = 8, I = ()> {
+    buffer: DoubleBuffer<T, S, I>,
+    width: usize,
+    height: usize,
+    staged: Damage<N>,
+    published: Damage<N>,
+    synced: bool,
+}
+
+impl<T, S, const N: usize, I: Instrument<T>> DamageTracked<T, S, N, I> {
+    #[inline]
+    pub const fn new(buffer: DoubleBuffer<T, S, I>, width: usize, height: usize) -> Self {
+        const { assert!(N > 0, "DamageTracked needs room for at least one rectangle") };
+        Self {
+            buffer,
+            width,
+            height,
+            staged: Damage::new(),
+            published: Damage::new(),
+            synced: false,
+        }
+    }
+
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Rectangle covering the whole framebuffer.
+    #[inline]
+    pub const fn bounds(&self) -> Rect {
+        Rect::new(0, 0, self.width, self.height)
+    }
+
+    /// Rectangles written in the next value before the last swap.
+    #[inline]
+    pub fn damage(&self) -> core::slice::Iter<'_, Rect> {
+        self.published.as_slice().iter()
+    }
+
+    /// Rectangles written in the next value since the last swap.
+    #[inline]
+    pub fn pending_damage(&self) -> core::slice::Iter<'_, Rect> {
+        self.staged.as_slice().iter()
+    }
+
+    /// Records a rectangle of the next value as written, it's clipped to the bounds.
+    #[inline]
+    pub fn mark_damaged(&mut self, rect: Rect) {
+        let rect = rect.intersection(&self.bounds());
+        self.staged.add(rect);
+    }
+
+    /// Gives mutable access to the whole next value, marking all of it as written.
+    #[inline]
+    pub fn next_mut(&mut self) -> &mut T {
+        self.mark_damaged(self.bounds());
+        self.buffer.as_mut()
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> DoubleBuffer<T, S, I> {
+        self.buffer
+    }
+
+    #[inline]
+    fn published(&mut self, synced: bool) {
+        self.published = self.staged;
+        self.staged.clear();
+        self.synced = synced;
+    }
+
+    /// Writes a pixel of the next value.
+    ///
+    /// Returns `false`, without writing, if it's out of the bounds.
+    #[inline]
+    pub fn set<P>(&mut self, x: usize, y: usize, pixel: P) -> bool
+    where
+        T: AsMut<[P]>,
+    {
+        if x >= self.width || y >= self.height {
+            return false;
+        }
+        let Some(target) = self.buffer.as_mut().as_mut().get_mut(y * self.width + x) else {
+            return false;
+        };
+        *target = pixel;
+        self.staged.add(Rect::new(x, y, 1, 1));
+        true
+    }
+
+    /// Fills a rectangle of the next value, it's clipped to the bounds.
+    pub fn fill_rect<P: Clone>(&mut self, rect: Rect, pixel: P)
+    where
+        T: AsMut<[P]>,
+    {
+        let rect = rect.intersection(&self.bounds());
+        if rect.is_empty() {
+            return;
+        }
+        let pixels = self.buffer.as_mut().as_mut();
+        for range in ranges(rect, self.width) {
+            if let Some(pixels) = pixels.get_mut(range) {
+                pixels.fill(pixel.clone());
+            }
+        }
+        self.staged.add(rect);
+    }
+}
+
+/// Index ranges of the pixels in the rectangle, joining whole rows.
+#[inline]
+fn ranges(rect: Rect, width: usize) -> impl Iterator<Item = Range<usize>> {
+    let (rows, row_len) = if rect.x == 0 && rect.width == width {
+        (rect.y..rect.y + 1, rect.area())
+    } else {
+        (rect.y..rect.bottom(), rect.width)
+    };
+    rows.map(move |y| {
+        let start = y * width + rect.x;
+        start..start + row_len
+    })
+}
+
+impl<T, S: SwapStrategy<T>, const N: usize, I: Instrument<T>> DamageTracked<T, S, N, I> {
+    /// Swaps like [`DoubleBuffer::swap()`], the written rectangles become the damage.
+    #[inline]
+    pub fn swap(&mut self) {
+        self.buffer.swap();
+        self.published(false);
+    }
+}
+
+impl<T: Clone, S, const N: usize, I: Instrument<T>> DamageTracked<T, S, N, I> {
+    /// Swaps like [`DoubleBuffer::swap_with_clone()`], the written rectangles become the damage.
+    ///
+    /// Only the written rectangles are cloned to the current value, as the rest
+    /// is already equal since the last call. The first call, and the first one
+    /// after another kind of swap, clones the whole value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::{DamageTracked, DoubleBuffer, Rect};
+    /// let mut frame: DamageTracked<[u8; 16]> = DamageTracked::new(DoubleBuffer::new([0; 16], [0; 16]), 4, 4);
+    /// frame.fill_rect(Rect::new(0, 0, 4, 1), 1);
+    /// frame.swap_with_clone();
+    ///
+    /// // Only the second row is cloned.
+    /// frame.fill_rect(Rect::new(0, 1, 4, 1), 2);
+    /// frame.swap_with_clone();
+    /// assert_eq!(frame[..8], [1, 1, 1, 1, 2, 2, 2, 2]);
+    /// ```
+    #[inline]
+    pub fn swap_with_clone<P: Clone>(&mut self)
+    where
+        T: AsRef<[P]> + AsMut<[P]>,
+    {
+        if self.synced {
+            let width = self.width;
+            let ranges = self
+                .staged
+                .as_slice()
+                .iter()
+                .flat_map(|rect| ranges(*rect, width));
+            self.buffer.swap_with_clone_ranges(ranges);
+        } else {
+            self.buffer.swap_with_clone();
+        }
+        self.published(true);
+    }
+}
+
+impl<T, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Swaps like [`DoubleBuffer::swap_with_clone()`], but only clones the given
+    /// index ranges of the next value to the current value.
+    ///
+    /// It's only equivalent when the rest of the values is equal, like after
+    /// another clone swap when the writes are in the ranges, otherwise the stale
+    /// regions are published. Out of bounds ranges are ignored.
+    pub(crate) fn swap_with_clone_ranges<E: Clone>(
+        &mut self,
+        ranges: impl IntoIterator<Item = Range<usize>>,
+    ) where
+        T: AsRef<[E]> + AsMut<[E]>,
+    {
+        self.check_invariant();
+        let (current, next) = self.current_mut_and_next();
+        let (current, next) = (current.as_mut(), next.as_ref());
+        for range in ranges {
+            if let (Some(from), Some(to)) = (next.get(range.clone()), current.get_mut(range)) {
+                to.clone_from_slice(from);
+            }
+        }
+        self.published(SwapKind::Clone);
+    }
+}
+
+impl<T: Default, S, const N: usize, I: Instrument<T>> DamageTracked<T, S, N, I> {
+    /// Swaps like [`DoubleBuffer::swap_with_default()`], the written rectangles become the damage.
+    #[inline]
+    pub fn swap_with_default(&mut self) {
+        self.buffer.swap_with_default();
+        self.published(false);
+    }
+}
+
+impl<T: Clear, S, const N: usize, I: Instrument<T>> DamageTracked<T, S, N, I> {
+    /// Swaps like [`DoubleBuffer::swap_with_clear()`], the written rectangles become the damage.
+    #[inline]
+    pub fn swap_with_clear(&mut self) {
+        self.buffer.swap_with_clear();
+        self.published(false);
+    }
+}
+
+impl<T, S, const N: usize, I: Instrument<T>> Deref for DamageTracked<T, S, N, I> {
+    type Target = DoubleBuffer<T, S, I>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_touching_rects_are_merged() {
+        let mut damage: Damage<4> = Damage::new();
+        damage.add(Rect::new(0, 0, 2, 2));
+        damage.add(Rect::new(2, 0, 2, 2));
+        assert_eq!(damage.as_slice(), &[Rect::new(0, 0, 4, 2)]);
+    }
+
+    #[test]
+    fn test_merge_closest_when_full() {
+        let mut damage: Damage<2> = Damage::new();
+        damage.add(Rect::new(0, 0, 1, 1));
+        damage.add(Rect::new(10, 10, 1, 1));
+        damage.add(Rect::new(12, 12, 1, 1));
+        assert_eq!(
+            damage.as_slice(),
+            &[Rect::new(0, 0, 1, 1), Rect::new(10, 10, 3, 3)]
+        );
+    }
+
+    #[test]
+    fn test_writes_are_clipped() {
+        let mut frame: DamageTracked<[u8; 16]> = DamageTracked::new(DoubleBuffer::default(), 4, 4);
+        assert!(!frame.set(4, 0, 1));
+        frame.fill_rect(Rect::new(2, 2, 10, 10), 1);
+        assert!(frame.pending_damage().eq(&[Rect::new(2, 2, 2, 2)]));
+        assert_eq!(frame.damage().count(), 0);
+
+        frame.swap();
+        assert_eq!(**frame, [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1]);
+        assert_eq!(frame.pending_damage().count(), 0);
+    }
+
+    #[test]
+    fn test_swap_with_clone_ranges() {
+        let mut buffer: DoubleBuffer<[u8; 4]> = DoubleBuffer::new([0; 4], [0; 4]);
+        buffer[1] = 1;
+        buffer[2] = 2;
+        buffer.swap_with_clone_ranges([1..3, 3..9]);
+        assert_eq!(buffer, [0, 1, 2, 0]);
+    }
+
+    #[test]
+    fn test_swap_with_clone_copies_only_damage() {
+        let mut frame: DamageTracked<[u8; 16]> =
+            DamageTracked::new(DoubleBuffer::new([0; 16], [9; 16]), 4, 4);
+        frame.swap_with_clone();
+        assert_eq!(**frame, [9; 16]);
+
+        frame.set(1, 1, 1);
+        frame.fill_rect(Rect::new(0, 3, 4, 1), 2);
+        // Not damaged, so it's not cloned.
+        frame.buffer.as_mut()[0] = 3;
+        frame.swap_with_clone();
+        assert_eq!(**frame, [9, 9, 9, 9, 9, 1, 9, 9, 9, 9, 9, 9, 2, 2, 2, 2]);
+    }
+}
@@ -0,0 +1,291 @@
+use serde::ser::{Serialize, SerializeSeq, SerializeStruct, Serializer};
+
+use crate::{Diff, DoubleBuffer, Tracked};
+
+/// Serializable changes from the current value to the next value of a buffer,
+/// created by [`DoubleBuffer::delta()`] or [`DoubleBuffer::full_delta()`].
+///
+/// A full delta contains the whole current value and can be applied over
+/// any generation, it's the fallback when a receiver reports a
+/// [`DeltaError::GenerationMismatch`](crate::DeltaError::GenerationMismatch).
+pub struct Delta<'a, E> {
+    base_generation: Option<u64>,
+    generation: u64,
+    current: &'a [E],
+    next: Option<&'a [E]>,
+}
+
+struct Changes<'a, E>(&'a Delta<'a, E>);
+
+impl<E: Serialize + PartialEq> Serialize for Changes<'_, E> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let Some(next) = self.0.next else {
+            return serializer.collect_seq(core::iter::once((0u64, self.0.current)));
+        };
+        // Some formats, like postcard, need the length of the sequence up front.
+        let len = Diff::new(self.0.current, next).count();
+        let mut seq = serializer.serialize_seq(Some(len))?;
+        for range in Diff::new(self.0.current, next) {
+            seq.serialize_element(&(range.start as u64, &next[range]))?;
+        }
+        seq.end()
+    }
+}
+
+impl<E: Serialize + PartialEq> Serialize for Delta<'_, E> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let len = self.next.unwrap_or(self.current).len() as u64;
+        let mut state = serializer.serialize_struct("Delta", 4)?;
+        state.serialize_field("base_generation", &self.base_generation)?;
+        state.serialize_field("generation", &self.generation)?;
+        state.serialize_field("len", &len)?;
+        state.serialize_field("changes", &Changes(self))?;
+        state.end()
+    }
+}
+
+impl<T, S> DoubleBuffer<T, S, Tracked<T>> {
+    /// Changes from the current value to the next value, to be serialized and
+    /// applied with [`DoubleBuffer::apply_delta()`] by a receiver at the same generation
+    /// before swapping.
+    ///
+    /// Only the changed ranges, see [`DoubleBuffer::diff()`], are serialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::{DoubleBuffer, TrackedBuffer};
+    /// let mut server: TrackedBuffer<[u8; 64]> = DoubleBuffer::tracked([0; 64], [0; 64]);
+    /// server[10] = 1;
+    ///
+    /// let mut delta = [0; 64];
+    /// let mut full = [0; 128];
+    /// let delta = postcard::to_slice(&server.delta(), &mut delta).unwrap();
+    /// let full = postcard::to_slice(&server.full_delta(), &mut full).unwrap();
+    /// assert!(delta.len() < full.len());
+    /// ```
+    #[inline]
+    pub fn delta<E>(&self) -> Delta<'_, E>
+    where
+        T: AsRef<[E]>,
+    {
+        Delta {
+            base_generation: Some(self.instrument.generation),
+            generation: self.instrument.generation.wrapping_add(1),
+            current: self.current().as_ref(),
+            next: Some(self.next().as_ref()),
+        }
+    }
+
+    /// The whole current value, to be serialized and applied with
+    /// [`DoubleBuffer::apply_delta()`] by a receiver at any generation.
+    #[inline]
+    pub fn full_delta<E>(&self) -> Delta<'_, E>
+    where
+        T: AsRef<[E]>,
+    {
+        Delta {
+            base_generation: None,
+            generation: self.instrument.generation,
+            current: self.current().as_ref(),
+            next: None,
+        }
+    }
+
+    /// Serializes [`DoubleBuffer::delta()`].
+    #[inline]
+    pub fn serialize_delta<E, W: Serializer>(&self, serializer: W) -> Result<W::Ok, W::Error>
+    where
+        T: AsRef<[E]>,
+        E: Serialize + PartialEq,
+    {
+        self.delta().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod apply {
+    use alloc::vec::Vec;
+
+    use serde::{Deserialize, Deserializer};
+
+    use crate::{DeltaError, DoubleBuffer, Tracked};
+
+    #[derive(Deserialize)]
+    #[serde(rename = "Delta")]
+    struct OwnedDelta<E> {
+        base_generation: Option<u64>,
+        generation: u64,
+        len: u64,
+        changes: Vec<(u64, Vec<E>)>,
+    }
+
+    impl<T, S> DoubleBuffer<T, S, Tracked<T>> {
+        /// Deserializes a [`Delta`](super::Delta) and publishes it.
+        ///
+        /// The next value is set to the current value with the changes of the delta,
+        /// then it's swapped to the generation of the delta. If the delta is not a full
+        /// one and it's based on another generation, it returns
+        /// [`DeltaError::GenerationMismatch`] without changing anything, so the
+        /// sender can send a full delta instead.
+        ///
+        /// Instead of panicking like [`DoubleBuffer::swap()`], it returns
+        /// [`DeltaError::Poisoned`] if the buffer is poisoned, and
+        /// [`DeltaError::Invalid`] if the value violates the invariant of the buffer,
+        /// checked in any build, leaving the next value as it was.
+        ///
+        /// The length of the value is fixed, a delta for a value of another length,
+        /// even a full one, returns [`DeltaError::LengthMismatch`], so a receiver
+        /// holding a `Vec` has to be resized to the length of the sender first.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use double_buffer::{DeltaError, TrackedBuffer};
+        /// let mut server: TrackedBuffer<[u8; 4]> = TrackedBuffer::default();
+        /// let mut client: TrackedBuffer<[u8; 4]> = TrackedBuffer::default();
+        ///
+        /// server[0] = 1;
+        /// let delta = postcard::to_allocvec(&server.delta()).unwrap();
+        /// server.swap_with_clone();
+        ///
+        /// client.apply_delta(&mut postcard::Deserializer::from_bytes(&delta)).unwrap();
+        /// assert_eq!(client, server);
+        /// assert_eq!(client.generation(), server.generation());
+        /// ```
+        ///
+        /// A receiver at another generation needs a full delta:
+        ///
+        /// ```
+        /// # use double_buffer::{DeltaError, TrackedBuffer};
+        /// let mut server: TrackedBuffer<[u8; 4]> = TrackedBuffer::default();
+        /// server.swap();
+        /// server[0] = 1;
+        ///
+        /// let mut client: TrackedBuffer<[u8; 4]> = TrackedBuffer::default();
+        /// let delta = postcard::to_allocvec(&server.delta()).unwrap();
+        /// let result = client.apply_delta(&mut postcard::Deserializer::from_bytes(&delta));
+        /// assert!(matches!(result, Err(DeltaError::GenerationMismatch { expected: 0, found: 1 })));
+        ///
+        /// let full = postcard::to_allocvec(&server.full_delta()).unwrap();
+        /// client.apply_delta(&mut postcard::Deserializer::from_bytes(&full)).unwrap();
+        /// assert_eq!(client.generation(), 1);
+        /// ```
+        pub fn apply_delta<'de, E, D>(
+            &mut self,
+            deserializer: D,
+        ) -> Result<(), DeltaError<D::Error>>
+        where
+            T: AsRef<[E]> + AsMut<[E]>,
+            E: Deserialize<'de> + Clone,
+            D: Deserializer<'de>,
+        {
+            let delta =
+                OwnedDelta::<E>::deserialize(deserializer).map_err(DeltaError::Deserialize)?;
+            if self.instrument.poisoned {
+                return Err(DeltaError::Poisoned);
+            }
+            if let Some(base_generation) = delta.base_generation {
+                if base_generation != self.instrument.generation {
+                    return Err(DeltaError::GenerationMismatch {
+                        expected: self.instrument.generation,
+                        found: base_generation,
+                    });
+                }
+            }
+            let expected = self.current().as_ref().len();
+            if delta.len != expected as u64 {
+                return Err(DeltaError::LengthMismatch {
+                    expected,
+                    found: delta.len as usize,
+                });
+            }
+            for (start, values) in &delta.changes {
+                let in_bounds = usize::try_from(*start)
+                    .ok()
+                    .and_then(|start| start.checked_add(values.len()))
+                    .is_some_and(|end| end <= expected);
+                if !in_bounds {
+                    return Err(DeltaError::OutOfBounds);
+                }
+            }
+
+            let backup = self
+                .instrument
+                .invariant
+                .is_some()
+                .then(|| self.next().as_ref().to_vec());
+            let (current, next) = self.split_mut();
+            let next = next.as_mut();
+            next.clone_from_slice(current.as_ref());
+            for (start, values) in delta.changes {
+                let start = start as usize;
+                next[start..start + values.len()].clone_from_slice(&values);
+            }
+            if !self.invariant_holds(self.next()) {
+                if let Some(backup) = backup {
+                    self.next_mut().as_mut().clone_from_slice(&backup);
+                }
+                return Err(DeltaError::Invalid);
+            }
+            self.instrument.generation = delta.generation.wrapping_sub(1);
+            self.move_swap();
+            Ok(())
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::DeltaError;
+    use crate::TrackedBuffer;
+
+    #[test]
+    fn test_apply_length_mismatch() {
+        let mut server: TrackedBuffer<[u8; 4]> = TrackedBuffer::default();
+        server[3] = 1;
+        let delta = postcard::to_allocvec(&server.delta()).unwrap();
+
+        let mut client: TrackedBuffer<[u8; 2]> = TrackedBuffer::default();
+        let result = client.apply_delta(&mut postcard::Deserializer::from_bytes(&delta));
+        assert_eq!(
+            result,
+            Err(DeltaError::LengthMismatch {
+                expected: 2,
+                found: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_delta_is_not_published() {
+        let mut server: TrackedBuffer<[u8; 2]> = TrackedBuffer::default();
+        server[0] = 200;
+        let delta = postcard::to_allocvec(&server.delta()).unwrap();
+
+        let mut client: TrackedBuffer<[u8; 2]> = DoubleBuffer::tracked([0; 2], [1; 2]);
+        client.set_invariant(|value| value[0] < 100);
+        let result = client.apply_delta(&mut postcard::Deserializer::from_bytes(&delta));
+        assert_eq!(result, Err(DeltaError::Invalid));
+        assert_eq!((*client, *client.next()), ([0; 2], [1; 2]));
+        assert_eq!(client.generation(), 0);
+    }
+
+    #[test]
+    fn test_full_delta_over_any_generation() {
+        let mut server: TrackedBuffer<[u8; 2]> = DoubleBuffer::tracked([0; 2], [1, 2]);
+        for _ in 0..3 {
+            server.swap_with_clone();
+        }
+        let full = postcard::to_allocvec(&server.full_delta()).unwrap();
+
+        let mut client: TrackedBuffer<[u8; 2]> = TrackedBuffer::default();
+        client.swap();
+        client
+            .apply_delta(&mut postcard::Deserializer::from_bytes(&full))
+            .unwrap();
+        assert_eq!(client, [1, 2]);
+        assert_eq!(client.generation(), 3);
+    }
+}
@@ -0,0 +1,173 @@
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use crate::{DoubleBuffer, Instrument};
+
+/// Iterator over the ranges of indices that differ between two slices,
+/// created by [`DoubleBuffer::diff()`].
+///
+/// The ranges are in order, not empty and not adjacent. If the slices have
+/// different lengths, the extra elements of the longer one are a changed range.
+#[derive(Debug, Clone)]
+pub struct Diff<'a, E> {
+    current: &'a [E],
+    next: &'a [E],
+    index: usize,
+}
+
+impl<'a, E: PartialEq> Diff<'a, E> {
+    /// Compares the slices element by element.
+    #[inline]
+    pub fn new(current: &'a [E], next: &'a [E]) -> Self {
+        Self {
+            current,
+            next,
+            index: 0,
+        }
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.current.len().max(self.next.len())
+    }
+
+    #[inline]
+    fn differs(&self, index: usize) -> bool {
+        self.current.get(index) != self.next.get(index)
+    }
+}
+
+impl<E: PartialEq> Iterator for Diff<'_, E> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.len();
+        while self.index < len && !self.differs(self.index) {
+            self.index += 1;
+        }
+        if self.index >= len {
+            return None;
+        }
+        let start = self.index;
+        while self.index < len && self.differs(self.index) {
+            self.index += 1;
+        }
+        Some(start..self.index)
+    }
+}
+
+impl<E: PartialEq> FusedIterator for Diff<'_, E> {}
+
+impl<T, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Iterates the ranges of indices where the next value differs from the current value,
+    /// so only what changed has to be processed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let mut buffer: DoubleBuffer<[u8; 8]> = DoubleBuffer::default();
+    /// buffer[1] = 1;
+    /// buffer[2] = 1;
+    /// buffer[6] = 1;
+    ///
+    /// assert!(buffer.diff().eq([1..3, 6..7]));
+    /// ```
+    #[inline]
+    pub fn diff<E: PartialEq>(&self) -> Diff<'_, E>
+    where
+        T: AsRef<[E]>,
+    {
+        Diff::new(self.current().as_ref(), self.next().as_ref())
+    }
+
+    /// Returns `true` if the next value differs from the current value, so
+    /// publishing, serializing or uploading this frame can be skipped if not.
+    ///
+    /// Unlike [`DoubleBuffer::has_pending_changes()`], it compares the values, so
+    /// writes that left the value as it was don't count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::{DoubleBuffer, TrackedBuffer};
+    /// let mut buffer: TrackedBuffer<u32> = DoubleBuffer::tracked(1, 1);
+    /// *buffer = 1;
+    /// assert!(buffer.has_pending_changes());
+    /// assert!(!buffer.changed());
+    ///
+    /// *buffer = 2;
+    /// assert!(buffer.changed());
+    /// ```
+    #[inline]
+    pub fn changed(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.current() != self.next()
+    }
+
+    /// Compares the next value with `other`, as comparing the buffer compares
+    /// its current value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let mut buffer: DoubleBuffer<u32> = DoubleBuffer::new(1, 0);
+    /// *buffer = 2;
+    ///
+    /// assert!(buffer == 1);
+    /// assert!(buffer.next_eq(&2));
+    /// ```
+    #[inline]
+    pub fn next_eq(&self, other: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.next() == other
+    }
+
+    /// Orders the next value with `other`, as ordering the buffer orders its
+    /// current value.
+    #[inline]
+    pub fn cmp_next(&self, other: &T) -> Ordering
+    where
+        T: Ord,
+    {
+        self.next().cmp(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_changes() {
+        let buffer: DoubleBuffer<[u8; 4]> = DoubleBuffer::default();
+        assert_eq!(buffer.diff().next(), None);
+    }
+
+    #[test]
+    fn test_changes_at_edges() {
+        let buffer: DoubleBuffer<[u8; 4]> = DoubleBuffer::new([0, 0, 0, 0], [1, 0, 0, 1]);
+        assert!(buffer.diff().eq([0..1, 3..4]));
+    }
+
+    #[test]
+    fn test_different_lengths() {
+        assert!(Diff::new(&[1, 2, 3, 4], &[1, 2, 0]).eq(core::iter::once(2..4)));
+        assert!(Diff::new(&[1, 2], &[1, 2, 3]).eq(core::iter::once(2..3)));
+        assert!(Diff::new(&[1, 2], &[0, 2, 3]).eq([0..1, 2..3]));
+    }
+
+    #[test]
+    fn test_compare_next() {
+        let buffer: DoubleBuffer<u8> = DoubleBuffer::new(2, 1);
+        assert_eq!(buffer.cmp(&DoubleBuffer::new(1, 2)), Ordering::Greater);
+        assert_eq!(buffer.cmp_next(&2), Ordering::Less);
+        assert!(!buffer.next_eq(&2));
+    }
+}
@@ -0,0 +1,164 @@
+use core::ops::{Deref, DerefMut};
+use core::ptr::NonNull;
+
+use embedded_dma::{ReadBuffer, ReadTarget, WriteBuffer, WriteTarget};
+
+use crate::{DoubleBuffer, Instrument, MoveSwap};
+
+/// Current value of a [`DoubleBuffer`] split with [`DoubleBuffer::split_halves()`].
+///
+/// With a `'static` lifetime it's a DMA [`ReadBuffer`], e.g. to stream it to a DAC or SPI.
+pub struct CurrentHalf<'a, T, S = MoveSwap, I = ()> {
+    buffer: NonNull<DoubleBuffer<T, S, I>>,
+    value: &'a T,
+}
+
+/// Next value of a [`DoubleBuffer`] split with [`DoubleBuffer::split_halves()`].
+///
+/// With a `'static` lifetime it's a DMA [`WriteBuffer`], e.g. to fill it from an ADC or I2S.
+pub struct NextHalf<'a, T, S = MoveSwap, I = ()> {
+    buffer: NonNull<DoubleBuffer<T, S, I>>,
+    value: &'a mut T,
+}
+
+impl<T, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Splits the buffer into its current and next values, like [`DoubleBuffer::split_mut()`],
+    /// but they can be joined back with [`CurrentHalf::join()`] to swap the buffer.
+    ///
+    /// It allows to give the halves of a `'static` buffer to DMA transfers,
+    /// which require `'static` buffers, and swap it after the transfers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let buffer: &'static mut DoubleBuffer<[u16; 64]> =
+    ///     Box::leak(Box::new(DoubleBuffer::new([0; 64], [0; 64])));
+    ///
+    /// let (samples_out, mut samples_in) = buffer.split_halves();
+    /// // Start a DMA transfer from `samples_out` and another one to `samples_in`,
+    /// // and wait for both to complete.
+    /// samples_in[0] = 1;
+    ///
+    /// let buffer = samples_out.join(samples_in).unwrap();
+    /// buffer.swap();
+    /// assert_eq!(buffer[0], 1);
+    /// ```
+    #[inline]
+    pub fn split_halves(&mut self) -> (CurrentHalf<'_, T, S, I>, NextHalf<'_, T, S, I>) {
+        let buffer = NonNull::from(self);
+        // SAFETY: the pointer comes from the exclusive borrow, which the halves keep
+        // for their lifetime, and both values are derived from it.
+        let (current, next) = unsafe { (*buffer.as_ptr()).split_mut() };
+        (
+            CurrentHalf {
+                buffer,
+                value: current,
+            },
+            NextHalf {
+                buffer,
+                value: next,
+            },
+        )
+    }
+}
+
+impl<'a, T, S, I> CurrentHalf<'a, T, S, I> {
+    /// Joins the halves back into the buffer they were split from.
+    ///
+    /// Returns `None` if they were split from different buffers.
+    #[inline]
+    pub fn join(self, next: NextHalf<'a, T, S, I>) -> Option<&'a mut DoubleBuffer<T, S, I>> {
+        if self.buffer != next.buffer {
+            return None;
+        }
+        // SAFETY: both halves of the exclusive borrow are consumed, so it's exclusive again.
+        Some(unsafe { &mut *self.buffer.as_ptr() })
+    }
+}
+
+impl<T, S, I> Deref for CurrentHalf<'_, T, S, I> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
+}
+
+impl<T, S, I> Deref for NextHalf<'_, T, S, I> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
+}
+
+impl<T, S, I> DerefMut for NextHalf<'_, T, S, I> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.value
+    }
+}
+
+// SAFETY: the halves are like the borrows of the values, and joining them moves the
+// exclusive borrow of the buffer where the last one is.
+unsafe impl<T: Sync, S, I> Send for CurrentHalf<'_, T, S, I> where DoubleBuffer<T, S, I>: Send {}
+
+// SAFETY: the halves are like the borrows of the values, and joining them moves the
+// exclusive borrow of the buffer where the last one is.
+unsafe impl<T, S, I> Send for NextHalf<'_, T, S, I> where DoubleBuffer<T, S, I>: Send {}
+
+// SAFETY: the value is borrowed for `'static` and it can't be moved or freed
+// while the half exists, and the half has no `&mut self` methods.
+unsafe impl<T: ReadTarget, S, I> ReadBuffer for CurrentHalf<'static, T, S, I> {
+    type Word = T::Word;
+
+    #[inline]
+    unsafe fn read_buffer(&self) -> (*const Self::Word, usize) {
+        self.value.as_read_buffer()
+    }
+}
+
+// SAFETY: the value is borrowed for `'static` and it can't be moved or freed
+// while the half exists, and `DerefMut` doesn't change where it points.
+unsafe impl<T: WriteTarget, S, I> WriteBuffer for NextHalf<'static, T, S, I> {
+    type Word = T::Word;
+
+    #[inline]
+    unsafe fn write_buffer(&mut self) -> (*mut Self::Word, usize) {
+        self.value.as_write_buffer()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join_different_buffers() {
+        let mut first: DoubleBuffer<[u8; 2]> = DoubleBuffer::new([1; 2], [2; 2]);
+        let mut second: DoubleBuffer<[u8; 2]> = DoubleBuffer::new([3; 2], [4; 2]);
+        let (current, _) = first.split_halves();
+        let (_, next) = second.split_halves();
+        assert!(current.join(next).is_none());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_dma_buffers() {
+        fn read<B: ReadBuffer<Word = u8>>(buffer: &B) -> (*const u8, usize) {
+            unsafe { buffer.read_buffer() }
+        }
+        fn write<B: WriteBuffer<Word = u8>>(buffer: &mut B) -> (*mut u8, usize) {
+            unsafe { buffer.write_buffer() }
+        }
+
+        let buffer = alloc::boxed::Box::new(DoubleBuffer::new([1u8; 4], [2; 4]));
+        let buffer: &'static mut DoubleBuffer<[u8; 4]> = alloc::boxed::Box::leak(buffer);
+        let (current, mut next) = buffer.split_halves();
+        assert_eq!(read(&current), (current.as_ptr(), 4));
+        assert_eq!(write(&mut next), (next.as_mut_ptr(), 4));
+    }
+}
@@ -0,0 +1,56 @@
+use alloc::collections::{vec_deque, VecDeque};
+use alloc::vec::{self, Vec};
+
+use crate::{DoubleBuffer, Instrument};
+
+impl<E, S, I: Instrument<Vec<E>>> DoubleBuffer<Vec<E>, S, I> {
+    /// Removes the items of the current value and iterates them, so they're
+    /// consumed without cloning them.
+    ///
+    /// The vector keeps its capacity, reused for the items written after the
+    /// next swap. It isn't a publish, the next value is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let mut jobs: DoubleBuffer<Vec<String>> = DoubleBuffer::default();
+    /// jobs.push("resize".to_string());
+    /// jobs.swap();
+    ///
+    /// let taken: Vec<String> = jobs.drain_current().collect();
+    /// assert_eq!(taken, ["resize"]);
+    /// assert!(jobs.is_empty());
+    /// ```
+    #[inline]
+    pub fn drain_current(&mut self) -> vec::Drain<'_, E> {
+        let (current, _) = self.current_mut_and_next();
+        current.drain(..)
+    }
+}
+
+impl<E, S, I: Instrument<VecDeque<E>>> DoubleBuffer<VecDeque<E>, S, I> {
+    /// Removes the items of the current value and iterates them from the front,
+    /// like [`DoubleBuffer::drain_current()`] for vectors.
+    #[inline]
+    pub fn drain_current(&mut self) -> vec_deque::Drain<'_, E> {
+        let (current, _) = self.current_mut_and_next();
+        current.drain(..)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TrackedBuffer;
+
+    #[test]
+    fn test_drain_current_deque() {
+        let mut queue: TrackedBuffer<VecDeque<u8>> =
+            DoubleBuffer::tracked(VecDeque::from([1, 2]), VecDeque::from([3]));
+        assert!(queue.drain_current().eq([1, 2]));
+        assert!(queue.is_empty());
+        assert_eq!(*queue.next(), [3]);
+        assert_eq!(queue.generation(), 0);
+    }
+}
@@ -0,0 +1,127 @@
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::geometry::Dimensions;
+use embedded_graphics_core::image::ImageDrawable;
+use embedded_graphics_core::primitives::Rectangle;
+use embedded_graphics_core::Pixel;
+
+use crate::{DoubleBuffer, Instrument, SwapStrategy};
+
+impl<T: Dimensions, S, I: Instrument<T>> Dimensions for DoubleBuffer<T, S, I> {
+    /// Bounding box of the next value, where drawing happens.
+    #[inline]
+    fn bounding_box(&self) -> Rectangle {
+        self.next().bounding_box()
+    }
+}
+
+/// Draws to the next value, to be shown after the swap.
+impl<T: DrawTarget, S, I: Instrument<T>> DrawTarget for DoubleBuffer<T, S, I> {
+    type Color = T::Color;
+    type Error = T::Error;
+
+    #[inline]
+    fn draw_iter<Iter>(&mut self, pixels: Iter) -> Result<(), Self::Error>
+    where
+        Iter: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        self.as_mut().draw_iter(pixels)
+    }
+
+    #[inline]
+    fn fill_contiguous<Iter>(&mut self, area: &Rectangle, colors: Iter) -> Result<(), Self::Error>
+    where
+        Iter: IntoIterator<Item = Self::Color>,
+    {
+        self.as_mut().fill_contiguous(area, colors)
+    }
+
+    #[inline]
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.as_mut().fill_solid(area, color)
+    }
+
+    #[inline]
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.as_mut().clear(color)
+    }
+}
+
+impl<T, S: SwapStrategy<T>, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Swaps the buffer and draws the new current value to the display.
+    ///
+    /// Draw the frame to the buffer, as it's a [`DrawTarget`] of the next value,
+    /// and present it when it's complete, so the display never shows a frame
+    /// being drawn.
+    #[inline]
+    pub fn present<D>(&mut self, display: &mut D) -> Result<(), D::Error>
+    where
+        T: ImageDrawable<Color = D::Color>,
+        D: DrawTarget,
+    {
+        self.swap();
+        self.current().draw(display)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics_core::geometry::{OriginDimensions, Size};
+    use embedded_graphics_core::pixelcolor::BinaryColor;
+
+    #[derive(Debug, Default, Clone, PartialEq)]
+    struct Screen([BinaryColor; 4]);
+
+    impl OriginDimensions for Screen {
+        fn size(&self) -> Size {
+            Size::new(4, 1)
+        }
+    }
+
+    impl DrawTarget for Screen {
+        type Color = BinaryColor;
+        type Error = core::convert::Infallible;
+
+        fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            for Pixel(point, color) in pixels {
+                if let Some(pixel) = self.0.get_mut(point.x as usize) {
+                    *pixel = color;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    impl ImageDrawable for Screen {
+        type Color = BinaryColor;
+
+        fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+        where
+            D: DrawTarget<Color = Self::Color>,
+        {
+            target.fill_contiguous(&self.bounding_box(), self.0)
+        }
+
+        fn draw_sub_image<D>(&self, target: &mut D, _area: &Rectangle) -> Result<(), D::Error>
+        where
+            D: DrawTarget<Color = Self::Color>,
+        {
+            self.draw(target)
+        }
+    }
+
+    #[test]
+    fn test_present() {
+        let mut frame: DoubleBuffer<Screen> = DoubleBuffer::default();
+        let mut display = Screen::default();
+
+        frame.clear(BinaryColor::On).unwrap();
+        assert_eq!(display, Screen::default());
+
+        frame.present(&mut display).unwrap();
+        assert_eq!(display, Screen([BinaryColor::On; 4]));
+    }
+}
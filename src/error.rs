@@ -0,0 +1,261 @@
+use core::error::Error;
+use core::fmt::{Debug, Display, Formatter};
+
+/// Error applying a [`Patch`](crate::Patch) with [`DoubleBuffer::apply_patch()`](crate::DoubleBuffer::apply_patch()).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatchError<E> {
+    /// The patch couldn't be applied.
+    Patch(E),
+    /// The patched value violates the invariant of the buffer.
+    Invalid,
+}
+
+impl<E: Display> Display for PatchError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Patch(error) => write!(f, "patch couldn't be applied: {error}"),
+            Self::Invalid => f.write_str("patched value violates the invariant of the buffer"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for PatchError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Patch(error) => Some(error),
+            Self::Invalid => None,
+        }
+    }
+}
+
+/// Error applying a [`Delta`](crate::Delta) with [`DoubleBuffer::apply_delta()`](crate::DoubleBuffer::apply_delta()).
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeltaError<E> {
+    /// The delta couldn't be deserialized.
+    Deserialize(E),
+    /// The delta is based on another generation, a full delta is required.
+    GenerationMismatch { expected: u64, found: u64 },
+    /// The delta is for a value of another length.
+    LengthMismatch { expected: usize, found: usize },
+    /// A change of the delta is out of the bounds of the value.
+    OutOfBounds,
+    /// A panic interrupted building the next value, see
+    /// [`DoubleBuffer::is_poisoned()`](crate::DoubleBuffer::is_poisoned()).
+    Poisoned,
+    /// The value with the delta applied violates the invariant of the buffer.
+    Invalid,
+}
+
+#[cfg(feature = "serde")]
+impl<E: Display> Display for DeltaError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Deserialize(error) => write!(f, "delta couldn't be deserialized: {error}"),
+            Self::GenerationMismatch { expected, found } => {
+                write!(
+                    f,
+                    "delta based on generation {found} but the buffer is at generation {expected}"
+                )
+            }
+            Self::LengthMismatch { expected, found } => {
+                write!(
+                    f,
+                    "delta for a value of length {found} but the value has length {expected}"
+                )
+            }
+            Self::OutOfBounds => f.write_str("delta change out of the bounds of the value"),
+            Self::Poisoned => f.write_str("a panic interrupted building the value to publish"),
+            Self::Invalid => {
+                f.write_str("value with the delta violates the invariant of the buffer")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<E: Error + 'static> Error for DeltaError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Deserialize(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Error persisting a snapshot with [`DoubleBuffer::persist_current()`](crate::DoubleBuffer::persist_current())
+/// or restoring it with [`DoubleBuffer::restore()`](crate::DoubleBuffer::restore()),
+/// and serializing the current value with [`DoubleBuffer::to_bytes()`](crate::DoubleBuffer::to_bytes())
+/// or deserializing it with [`DoubleBuffer::from_bytes()`](crate::DoubleBuffer::from_bytes()).
+#[cfg(feature = "postcard")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PersistError {
+    /// The buffer is too small for the snapshot.
+    BufferTooSmall,
+    /// The current value couldn't be serialized.
+    Serialize(postcard::Error),
+    /// The bytes are too short to be a snapshot.
+    Truncated,
+    /// The CRC-32 doesn't match, the snapshot is corrupted or partially written.
+    Checksum,
+    /// The snapshot couldn't be deserialized.
+    Deserialize(postcard::Error),
+    /// The compressed snapshot couldn't be decompressed.
+    Decompress,
+    /// The restored value violates the invariant of the buffer.
+    Invalid,
+}
+
+#[cfg(feature = "postcard")]
+impl Display for PersistError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::BufferTooSmall => f.write_str("buffer too small for the snapshot"),
+            Self::Serialize(error) => write!(f, "snapshot couldn't be serialized: {error}"),
+            Self::Truncated => f.write_str("bytes too short to be a snapshot"),
+            Self::Checksum => f.write_str("snapshot checksum mismatch"),
+            Self::Deserialize(error) => write!(f, "snapshot couldn't be deserialized: {error}"),
+            Self::Decompress => f.write_str("snapshot couldn't be decompressed"),
+            Self::Invalid => f.write_str("restored value violates the invariant of the buffer"),
+        }
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl Error for PersistError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Serialize(error) | Self::Deserialize(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Error reloading a configuration with [`ConfigBuffer::reload()`](crate::ConfigBuffer::reload()).
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError<D, V> {
+    /// The configuration couldn't be deserialized.
+    Deserialize(D),
+    /// The configuration was rejected by the validation.
+    Invalid(V),
+}
+
+#[cfg(feature = "serde")]
+impl<D: Display, V: Display> Display for ConfigError<D, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Deserialize(error) => {
+                write!(f, "configuration couldn't be deserialized: {error}")
+            }
+            Self::Invalid(error) => write!(f, "invalid configuration: {error}"),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<D: Error + 'static, V: Debug + Display> Error for ConfigError<D, V> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Deserialize(error) => Some(error),
+            Self::Invalid(_) => None,
+        }
+    }
+}
+
+/// Error publishing with [`DoubleBuffer::try_swap()`](crate::DoubleBuffer::try_swap()).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PublishError {
+    /// A panic interrupted building the next value, see
+    /// [`DoubleBuffer::is_poisoned()`](crate::DoubleBuffer::is_poisoned()).
+    Poisoned,
+    /// The next value violates the invariant of the buffer.
+    Invalid,
+}
+
+impl Display for PublishError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Poisoned => f.write_str("a panic interrupted building the value to publish"),
+            Self::Invalid => f.write_str("value to publish violates the invariant of the buffer"),
+        }
+    }
+}
+
+impl Error for PublishError {}
+
+/// Error publishing a frame of a [`ChunkedBuffer`](crate::ChunkedBuffer) before
+/// all its bytes arrived.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IncompleteFrame {
+    pub received: usize,
+    pub expected: usize,
+}
+
+impl Display for IncompleteFrame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "received {} of the {} bytes of the frame",
+            self.received, self.expected
+        )
+    }
+}
+
+impl Error for IncompleteFrame {}
+
+/// Error splitting a value of odd length into two halves, with the value.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OddLengthError<D> {
+    len: usize,
+    value: D,
+}
+
+#[cfg(feature = "alloc")]
+impl<D> OddLengthError<D> {
+    #[inline]
+    pub(crate) const fn new(len: usize, value: D) -> Self {
+        Self { len, value }
+    }
+
+    /// Returns the value that couldn't be split.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.value
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<D> Display for OddLengthError<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "length {} can't be split in two halves", self.len)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<D: Debug> Error for OddLengthError<D> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Corrupted;
+
+    impl Display for Corrupted {
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            f.write_str("corrupted")
+        }
+    }
+
+    impl Error for Corrupted {}
+
+    #[test]
+    fn test_source() {
+        let error: &dyn Error = &PatchError::Patch(Corrupted);
+        assert!(error.source().unwrap().is::<Corrupted>());
+        assert!(PatchError::<Corrupted>::Invalid.source().is_none());
+        assert!(PublishError::Poisoned.source().is_none());
+    }
+}
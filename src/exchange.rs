@@ -0,0 +1,79 @@
+use core::mem;
+
+use crate::{DoubleBuffer, Instrument};
+
+impl<T, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Exchanges the next values of two buffers, without cloning them.
+    ///
+    /// It counts as a mutable access of both for [`DoubleBuffer::has_pending_changes()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let mut decoded: DoubleBuffer<Vec<u8>> = DoubleBuffer::default();
+    /// let mut filtered: DoubleBuffer<Vec<u8>> = DoubleBuffer::default();
+    ///
+    /// decoded.extend([1, 2, 3]);
+    /// DoubleBuffer::swap_next_between(&mut decoded, &mut filtered);
+    /// assert!(decoded.next().is_empty());
+    ///
+    /// filtered.retain(|byte| byte % 2 == 1);
+    /// filtered.swap();
+    /// assert_eq!(*filtered, [1, 3]);
+    /// ```
+    #[inline]
+    pub fn swap_next_between(a: &mut Self, b: &mut Self) {
+        mem::swap(a.as_mut(), b.as_mut());
+    }
+
+    /// Exchanges the current value of `a` with the next value of `b`, without
+    /// cloning them, to hand a published value to the next stage of a pipeline.
+    ///
+    /// The current value of `a` is replaced in place, it isn't a swap of `a`,
+    /// so its generation isn't incremented and its hooks aren't called.
+    /// It counts as a mutable access of `b` for [`DoubleBuffer::has_pending_changes()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let mut decoder: DoubleBuffer<[u8; 4]> = DoubleBuffer::default();
+    /// let mut renderer: DoubleBuffer<[u8; 4]> = DoubleBuffer::default();
+    ///
+    /// *decoder = [1; 4];
+    /// decoder.swap();
+    ///
+    /// DoubleBuffer::swap_current_with_next(&mut decoder, &mut renderer);
+    /// assert_eq!(*decoder, [0; 4]);
+    ///
+    /// renderer.swap();
+    /// assert_eq!(*renderer, [1; 4]);
+    /// ```
+    #[inline]
+    pub fn swap_current_with_next(a: &mut Self, b: &mut Self) {
+        let (current, _) = a.current_mut_and_next();
+        mem::swap(current, b.as_mut());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TrackedBuffer;
+
+    #[test]
+    fn test_exchanges() {
+        let mut a: TrackedBuffer<u8> = DoubleBuffer::tracked(1, 2);
+        let mut b: TrackedBuffer<u8> = DoubleBuffer::tracked(3, 4);
+
+        DoubleBuffer::swap_next_between(&mut a, &mut b);
+        assert_eq!((*a, *a.next(), *b, *b.next()), (1, 4, 3, 2));
+        assert!(a.has_pending_changes());
+
+        DoubleBuffer::swap_current_with_next(&mut a, &mut b);
+        assert_eq!((*a, *a.next(), *b, *b.next()), (2, 4, 3, 1));
+        assert_eq!(a.generation(), 0);
+        assert!(b.has_pending_changes());
+    }
+}
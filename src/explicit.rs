@@ -0,0 +1,123 @@
+use core::fmt::{Debug, Formatter};
+
+use crate::{Clear, DoubleBuffer, Instrument, MoveSwap, SwapStrategy, Tracked};
+
+/// [`DoubleBuffer`] without the smart pointer traits, only with named methods,
+/// so every access says which value it uses.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::ExplicitDoubleBuffer;
+/// let mut score: ExplicitDoubleBuffer<u32> = ExplicitDoubleBuffer::default();
+///
+/// *score.stage_mut() = 10;
+/// assert_eq!(*score.read(), 0);
+///
+/// score.swap();
+/// assert_eq!(*score.read(), 10);
+/// ```
+pub struct ExplicitDoubleBuffer<T, S = MoveSwap, I = ()> {
+    buffer: DoubleBuffer<T, S, I>,
+}
+
+impl<T, S, I: Instrument<T>> ExplicitDoubleBuffer<T, S, I> {
+    #[inline]
+    pub const fn new(buffer: DoubleBuffer<T, S, I>) -> Self {
+        Self { buffer }
+    }
+
+    /// Gives access to the current value.
+    #[inline]
+    pub const fn read(&self) -> &T {
+        self.buffer.current()
+    }
+
+    /// Gives access to the next value.
+    #[inline]
+    pub const fn stage(&self) -> &T {
+        self.buffer.next()
+    }
+
+    /// Gives mutable access to the next value.
+    #[inline]
+    pub fn stage_mut(&mut self) -> &mut T {
+        self.buffer.as_mut()
+    }
+
+    /// Gives access to the current value and mutable access to the next value at once,
+    /// see [`DoubleBuffer::split_mut()`].
+    #[inline]
+    pub fn split_mut(&mut self) -> (&T, &mut T) {
+        self.buffer.split_mut()
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> DoubleBuffer<T, S, I> {
+        self.buffer
+    }
+}
+
+impl<T, S> ExplicitDoubleBuffer<T, S, Tracked<T>> {
+    /// Number of swaps since it was created, see [`DoubleBuffer::generation()`].
+    #[inline]
+    pub const fn generation(&self) -> u64 {
+        self.buffer.generation()
+    }
+}
+
+impl<T, S: SwapStrategy<T>, I: Instrument<T>> ExplicitDoubleBuffer<T, S, I> {
+    /// See [`DoubleBuffer::swap()`].
+    #[inline]
+    pub fn swap(&mut self) {
+        self.buffer.swap();
+    }
+}
+
+impl<T: Clone, S, I: Instrument<T>> ExplicitDoubleBuffer<T, S, I> {
+    /// See [`DoubleBuffer::swap_with_clone()`].
+    #[inline]
+    pub fn swap_with_clone(&mut self) {
+        self.buffer.swap_with_clone();
+    }
+}
+
+impl<T: Default, S, I: Instrument<T>> ExplicitDoubleBuffer<T, S, I> {
+    /// See [`DoubleBuffer::swap_with_default()`].
+    #[inline]
+    pub fn swap_with_default(&mut self) {
+        self.buffer.swap_with_default();
+    }
+}
+
+impl<T: Clear, S, I: Instrument<T>> ExplicitDoubleBuffer<T, S, I> {
+    /// See [`DoubleBuffer::swap_with_clear()`].
+    #[inline]
+    pub fn swap_with_clear(&mut self) {
+        self.buffer.swap_with_clear();
+    }
+}
+
+impl<T: Debug, S, I: Instrument<T>> Debug for ExplicitDoubleBuffer<T, S, I> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ExplicitDoubleBuffer")
+            .field("current", self.read())
+            .field("next", self.stage())
+            .finish()
+    }
+}
+
+impl<T: Default, S, I: Instrument<T>> Default for ExplicitDoubleBuffer<T, S, I> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(DoubleBuffer::default())
+    }
+}
+
+impl<T, S, I: Instrument<T>> From<DoubleBuffer<T, S, I>> for ExplicitDoubleBuffer<T, S, I> {
+    #[inline]
+    fn from(buffer: DoubleBuffer<T, S, I>) -> Self {
+        Self::new(buffer)
+    }
+}
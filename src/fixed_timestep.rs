@@ -0,0 +1,145 @@
+use core::time::Duration;
+
+use crate::{DoubleBuffer, Lerp, MoveSwap, SwapStrategy};
+
+/// Owns a [`DoubleBuffer`] and steps it at a fixed timestep, decoupled from the
+/// frame rate, like the game loop of "Game Programming Patterns".
+///
+/// [`FixedTimestep::advance()`] accumulates the elapsed time and runs as many
+/// steps as fit in it, swapping after each one. The time left over is exposed as
+/// [`FixedTimestep::alpha()`], to interpolate the rendered state between steps.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{DoubleBuffer, FixedTimestep};
+/// # use core::time::Duration;
+/// let mut position = FixedTimestep::new(DoubleBuffer::<f32>::default(), Duration::from_millis(10));
+///
+/// let steps = position.advance(Duration::from_millis(25), |current, next| *next = current + 1.0);
+///
+/// assert_eq!(steps, 2);
+/// assert_eq!(**position.buffer(), 2.0);
+/// assert_eq!(position.alpha(), 0.5);
+/// assert_eq!(position.interpolated(), 1.5);
+/// ```
+pub struct FixedTimestep<T, S = MoveSwap> {
+    buffer: DoubleBuffer<T, S>,
+    timestep: Duration,
+    accumulator: Duration,
+    max_steps: u32,
+}
+
+impl<T, S> FixedTimestep<T, S> {
+    /// Creates it stepping every `timestep`, running at most 8 steps per advance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestep` is zero.
+    #[inline]
+    pub const fn new(buffer: DoubleBuffer<T, S>, timestep: Duration) -> Self {
+        assert!(
+            !timestep.is_zero(),
+            "FixedTimestep timestep must not be zero"
+        );
+        Self {
+            buffer,
+            timestep,
+            accumulator: Duration::ZERO,
+            max_steps: 8,
+        }
+    }
+
+    /// Limits the steps run by each advance, so a slow step doesn't make the
+    /// following advances slower and slower. The time that doesn't fit is dropped.
+    #[inline]
+    pub const fn with_max_steps(mut self, max_steps: u32) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    #[inline]
+    pub const fn buffer(&self) -> &DoubleBuffer<T, S> {
+        &self.buffer
+    }
+
+    #[inline]
+    pub const fn timestep(&self) -> Duration {
+        self.timestep
+    }
+
+    /// Time accumulated that didn't fit in a step.
+    #[inline]
+    pub const fn accumulator(&self) -> Duration {
+        self.accumulator
+    }
+
+    /// Fraction of a step accumulated, from `0` to `1`.
+    #[inline]
+    pub fn alpha(&self) -> f32 {
+        self.accumulator.as_secs_f32() / self.timestep.as_secs_f32()
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> DoubleBuffer<T, S> {
+        self.buffer
+    }
+}
+
+impl<T, S: SwapStrategy<T>> FixedTimestep<T, S> {
+    /// Accumulates `elapsed` and calls `step` with the current value and the next
+    /// value, swapping after each call, while a whole timestep is accumulated.
+    ///
+    /// Returns the number of steps run.
+    #[inline]
+    pub fn advance(&mut self, elapsed: Duration, mut step: impl FnMut(&T, &mut T)) -> u32 {
+        self.accumulator = self.accumulator.saturating_add(elapsed);
+        let mut steps = 0;
+        while self.accumulator >= self.timestep {
+            if steps == self.max_steps {
+                self.accumulator = Duration::from_nanos(
+                    (self.accumulator.as_nanos() % self.timestep.as_nanos()) as u64,
+                );
+                break;
+            }
+            let (current, next) = self.buffer.split_mut();
+            step(current, next);
+            self.buffer.swap();
+            self.accumulator -= self.timestep;
+            steps += 1;
+        }
+        steps
+    }
+}
+
+impl<T: Lerp> FixedTimestep<T, MoveSwap> {
+    /// Interpolates from the value before the last step to the current value by
+    /// [`FixedTimestep::alpha()`].
+    ///
+    /// After a swap, the next value holds the value before the last step, until
+    /// it's written.
+    #[inline]
+    pub fn interpolated(&self) -> T {
+        self.buffer.next().lerp(self.buffer.current(), self.alpha())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_steps_drops_time() {
+        let mut counter =
+            FixedTimestep::new(DoubleBuffer::<u32>::default(), Duration::from_millis(10))
+                .with_max_steps(2);
+
+        let steps = counter.advance(Duration::from_millis(55), |current, next| {
+            *next = current + 1
+        });
+
+        assert_eq!(steps, 2);
+        assert_eq!(**counter.buffer(), 2);
+        assert_eq!(counter.accumulator(), Duration::from_millis(5));
+    }
+}
@@ -0,0 +1,206 @@
+use core::borrow::{Borrow, BorrowMut};
+use core::fmt::{Debug, Formatter};
+use core::ops::{Deref, DerefMut};
+
+/// Ring of `N` per-frame resources, like the command buffers or uniform buffers
+/// of a GPU pipeline with `N` frames in flight.
+///
+/// It dereferences, mutably too, to the slot of the frame being built.
+/// [`FramesInFlight::acquire()`] moves to the following slot, only if `ready`
+/// returns `true` for its index, e.g. when the fence of the frame that last
+/// used it was signaled.
+///
+/// `N` must be greater than zero. The first slot is acquired on creation.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::FramesInFlight;
+/// let mut fences = [true, true, false];
+/// let mut uniforms: FramesInFlight<u32, 3> = FramesInFlight::default();
+///
+/// *uniforms = 1;
+/// assert!(uniforms.acquire(|slot| fences[slot]).is_some());
+/// *uniforms = 2;
+///
+/// // The GPU is still using the third slot.
+/// assert!(uniforms.acquire(|slot| fences[slot]).is_none());
+/// assert_eq!(uniforms.index(), 1);
+///
+/// fences[2] = true;
+/// assert!(uniforms.acquire(|slot| fences[slot]).is_some());
+/// assert_eq!(uniforms.get(0), Some(&1));
+/// ```
+pub struct FramesInFlight<T, const N: usize> {
+    frame: u64,
+    index: usize,
+    slots: [T; N],
+}
+
+impl<T, const N: usize> FramesInFlight<T, N> {
+    const SLOTS: usize = {
+        assert!(N > 0, "FramesInFlight N must be greater than zero");
+        N
+    };
+
+    #[inline]
+    pub const fn new(slots: [T; N]) -> Self {
+        Self {
+            frame: 0,
+            index: 0,
+            slots,
+        }
+    }
+
+    /// Creates the slots calling `f` with their indexes.
+    #[inline]
+    pub fn from_fn(f: impl FnMut(usize) -> T) -> Self {
+        Self::new(core::array::from_fn(f))
+    }
+
+    /// Moves to the following slot if `ready` returns `true` for its index,
+    /// and gives mutable access to it.
+    ///
+    /// Returns `None`, staying in the current slot, if it's not ready.
+    #[inline]
+    pub fn acquire(&mut self, ready: impl FnOnce(usize) -> bool) -> Option<&mut T> {
+        let index = (self.index + 1) % Self::SLOTS;
+        if !ready(index) {
+            return None;
+        }
+        self.index = index;
+        self.frame = self.frame.wrapping_add(1);
+        Some(&mut self.slots[index])
+    }
+
+    /// Index of the slot of the frame being built.
+    #[inline]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Number of slots acquired since the container was created.
+    #[inline]
+    pub const fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.slots.get(index)
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.slots.get_mut(index)
+    }
+
+    /// Iterates the slots by index.
+    #[inline]
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.slots.iter()
+    }
+
+    /// Iterates the slots by index, mutably.
+    #[inline]
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.slots.iter_mut()
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> [T; N] {
+        self.slots
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for FramesInFlight<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FramesInFlight")
+            .field("frame", &self.frame)
+            .field("index", &self.index)
+            .field("slots", &self.slots)
+            .finish()
+    }
+}
+
+impl<T: Default, const N: usize> Default for FramesInFlight<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::from_fn(|_| T::default())
+    }
+}
+
+impl<T, const N: usize> Deref for FramesInFlight<T, N> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.slots[self.index]
+    }
+}
+
+impl<T, const N: usize> DerefMut for FramesInFlight<T, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.slots[self.index]
+    }
+}
+
+impl<T, const N: usize> Borrow<T> for FramesInFlight<T, N> {
+    #[inline]
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
+impl<T, const N: usize> BorrowMut<T> for FramesInFlight<T, N> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+impl<T, const N: usize> AsRef<T> for FramesInFlight<T, N> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T, const N: usize> AsMut<T> for FramesInFlight<T, N> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acquire_wraps_around() {
+        let mut frames: FramesInFlight<usize, 3> = FramesInFlight::from_fn(|index| index);
+        for frame in 1..=5 {
+            assert_eq!(frames.acquire(|_| true), Some(&mut (frame % 3)));
+        }
+        assert_eq!(frames.frame(), 5);
+        assert_eq!(*frames, 2);
+    }
+
+    #[test]
+    fn test_not_ready_stays() {
+        let mut frames: FramesInFlight<u8, 2> = FramesInFlight::new([1, 2]);
+        assert_eq!(frames.acquire(|index| index != 1), None);
+        assert_eq!(frames.index(), 0);
+        assert_eq!(frames.frame(), 0);
+        assert_eq!(*frames, 1);
+    }
+
+    #[test]
+    fn test_single_slot() {
+        let mut frames: FramesInFlight<u8, 1> = FramesInFlight::new([1]);
+        assert_eq!(frames.acquire(|index| index == 0), Some(&mut 1));
+    }
+}
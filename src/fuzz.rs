@@ -0,0 +1,63 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{DoubleBuffer, Instrument};
+
+/// Generates the current and next values, and whether the buffer was swapped an
+/// odd number of times, so the current value can be in either slot.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::DoubleBuffer;
+/// use arbitrary::{Arbitrary, Unstructured};
+///
+/// let mut input = Unstructured::new(&[1, 2, 1]);
+/// let buffer: DoubleBuffer<u8> = DoubleBuffer::arbitrary(&mut input).unwrap();
+/// assert_eq!((*buffer, *buffer.next()), (1, 2));
+/// assert_eq!(buffer.current_slot().index(), 1);
+/// ```
+impl<'a, T: Arbitrary<'a>, S, I: Instrument<T>> Arbitrary<'a> for DoubleBuffer<T, S, I> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let current = T::arbitrary(u)?;
+        let next = T::arbitrary(u)?;
+        if bool::arbitrary(u)? {
+            let mut buffer = Self::with_strategy(next, current);
+            buffer.flip();
+            Ok(buffer)
+        } else {
+            Ok(Self::with_strategy(current, next))
+        }
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and_all(&[
+            T::size_hint(depth),
+            T::size_hint(depth),
+            bool::size_hint(depth),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TrackedBuffer;
+
+    #[test]
+    fn test_both_orientations() {
+        let mut input = Unstructured::new(&[3, 4, 0, 5, 6, 1]);
+        let first: DoubleBuffer<u8> = DoubleBuffer::arbitrary(&mut input).unwrap();
+        let second: TrackedBuffer<u8> = TrackedBuffer::arbitrary(&mut input).unwrap();
+        assert_eq!(
+            (*first, *first.next(), first.current_slot().index()),
+            (3, 4, 0)
+        );
+        assert_eq!(
+            (*second, *second.next(), second.current_slot().index()),
+            (5, 6, 1)
+        );
+        assert_eq!(second.generation(), 0);
+    }
+}
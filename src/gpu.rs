@@ -0,0 +1,126 @@
+use core::ops::{Deref, DerefMut};
+
+use wgpu::{Buffer, Queue};
+
+use crate::{DoubleBuffer, Instrument, MoveSwap, SwapStrategy};
+
+impl<T: AsRef<[u8]>, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Schedules writing the current value to the start of a GPU buffer,
+    /// see [`Queue::write_buffer()`].
+    #[inline]
+    pub fn upload_current(&self, queue: &Queue, gpu_buffer: &Buffer) {
+        queue.write_buffer(gpu_buffer, 0, self.current().as_ref());
+    }
+}
+
+/// Pairs each physical buffer of a [`DoubleBuffer`] with a GPU buffer, so the
+/// CPU writes the next value while the GPU reads the buffer of the current one.
+///
+/// The GPU buffers are `wgpu` buffers by default, but any handle can be paired
+/// and uploaded to with [`StagingPair::publish_with()`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use double_buffer::StagingPair;
+/// fn frame<'a>(uniforms: &'a mut StagingPair<[u8; 16]>, queue: &wgpu::Queue) -> &'a wgpu::Buffer {
+///     uniforms[0] = 1;
+///     uniforms.publish(queue);
+///
+///     // Bind it to the render pass reading this frame's uniforms.
+///     uniforms.gpu_buffer()
+/// }
+/// ```
+pub struct StagingPair<T, S = MoveSwap, I = (), G = Buffer> {
+    buffer: DoubleBuffer<T, S, I>,
+    gpu_buffers: [G; 2],
+}
+
+impl<T, S, I: Instrument<T>, G> StagingPair<T, S, I, G> {
+    /// Creates the pair, the GPU buffers are bound to the [`SlotId`](crate::SlotId)s
+    /// by index.
+    #[inline]
+    pub const fn new(buffer: DoubleBuffer<T, S, I>, gpu_buffers: [G; 2]) -> Self {
+        Self {
+            buffer,
+            gpu_buffers,
+        }
+    }
+
+    /// GPU buffer bound to the current value, the one to read from in the GPU.
+    #[inline]
+    pub fn gpu_buffer(&self) -> &G {
+        &self.gpu_buffers[self.buffer.current_slot().index()]
+    }
+
+    /// GPU buffer bound to the next value, the one the GPU may still be reading
+    /// from the previous frame.
+    #[inline]
+    pub fn next_gpu_buffer(&self) -> &G {
+        &self.gpu_buffers[self.buffer.next_slot().index()]
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> (DoubleBuffer<T, S, I>, [G; 2]) {
+        (self.buffer, self.gpu_buffers)
+    }
+}
+
+impl<T, S: SwapStrategy<T>, I: Instrument<T>, G> StagingPair<T, S, I, G> {
+    /// Swaps the buffer and calls `upload` with the new current value and its GPU buffer.
+    #[inline]
+    pub fn publish_with(&mut self, upload: impl FnOnce(&T, &G)) {
+        self.buffer.swap();
+        upload(self.buffer.current(), self.gpu_buffer());
+    }
+}
+
+impl<T: AsRef<[u8]>, S: SwapStrategy<T>, I: Instrument<T>> StagingPair<T, S, I> {
+    /// Swaps the buffer and uploads the new current value to its GPU buffer.
+    #[inline]
+    pub fn publish(&mut self, queue: &Queue) {
+        self.publish_with(|current, gpu_buffer| {
+            queue.write_buffer(gpu_buffer, 0, current.as_ref());
+        });
+    }
+}
+
+impl<T, S, I: Instrument<T>, G> Deref for StagingPair<T, S, I, G> {
+    type Target = DoubleBuffer<T, S, I>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+impl<T, S, I: Instrument<T>, G> DerefMut for StagingPair<T, S, I, G> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_publish_uploads_to_the_current_slot() {
+        let mut pair: StagingPair<[u8; 2], MoveSwap, (), &str> =
+            StagingPair::new(DoubleBuffer::new([0; 2], [0; 2]), ["first", "second"]);
+        let mut uploads = [None; 2];
+
+        for frame in 1..=3 {
+            pair[0] = frame;
+            pair.publish_with(|current, gpu_buffer| {
+                uploads[usize::from(frame % 2 == 0)] = Some((*gpu_buffer, current[0]));
+            });
+        }
+        assert_eq!(uploads, [Some(("second", 3)), Some(("first", 2))]);
+        assert_eq!(
+            (*pair.gpu_buffer(), *pair.next_gpu_buffer()),
+            ("second", "first")
+        );
+    }
+}
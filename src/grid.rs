@@ -0,0 +1,219 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::{DoubleBuffer, MoveSwap, SwapStrategy};
+
+/// Read access to a grid of `width` columns over a row-major slice of cells.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Grid<'a, C> {
+    cells: &'a [C],
+    width: usize,
+}
+
+impl<C> Clone for Grid<'_, C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for Grid<'_, C> {}
+
+impl<'a, C> Grid<'a, C> {
+    /// Creates a grid over the cells, the last row is dropped if it's incomplete.
+    #[inline]
+    pub const fn new(cells: &'a [C], width: usize) -> Self {
+        Self { cells, width }
+    }
+
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    pub const fn height(&self) -> usize {
+        match self.cells.len().checked_div(self.width) {
+            Some(height) => height,
+            None => 0,
+        }
+    }
+
+    #[inline]
+    pub const fn cells(&self) -> &'a [C] {
+        self.cells
+    }
+
+    /// Gives access to a cell, or `None` if it's out of the grid.
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a C> {
+        if x >= self.width || y >= self.height() {
+            return None;
+        }
+        self.cells.get(y * self.width + x)
+    }
+
+    /// Gives access to a cell wrapping around the edges, like in a torus.
+    ///
+    /// Returns `None` only if the grid is empty.
+    #[inline]
+    pub fn get_wrapping(&self, x: isize, y: isize) -> Option<&'a C> {
+        let width = isize::try_from(self.width).ok()?;
+        let height = isize::try_from(self.height()).ok()?;
+        if width == 0 || height == 0 {
+            return None;
+        }
+        self.get(x.rem_euclid(width) as usize, y.rem_euclid(height) as usize)
+    }
+
+    /// Iterates the up to eight neighbors of a cell inside the grid.
+    #[inline]
+    pub fn neighbors(&self, x: usize, y: usize) -> impl Iterator<Item = &'a C> + 'a {
+        let grid = *self;
+        [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (-1, 0),
+            (1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+        ]
+        .into_iter()
+        .filter_map(move |(dx, dy)| grid.get(x.checked_add_signed(dx)?, y.checked_add_signed(dy)?))
+    }
+}
+
+/// Cell of the next value of a [`GridBuffer`] being written by [`GridBuffer::step()`].
+#[derive(Debug)]
+pub struct CellMut<'a, C> {
+    pub x: usize,
+    pub y: usize,
+    value: &'a mut C,
+}
+
+impl<C> Deref for CellMut<'_, C> {
+    type Target = C;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
+}
+
+impl<C> DerefMut for CellMut<'_, C> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.value
+    }
+}
+
+/// Wraps a [`DoubleBuffer`] of a flat slice of cells as a grid of `width` columns,
+/// stepping cellular automata and simulations where every cell of the next value
+/// is computed from the cells of the current value.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{DoubleBuffer, GridBuffer};
+/// // A blinker of the Game of Life.
+/// let mut life: GridBuffer<[bool; 9]> = GridBuffer::new(DoubleBuffer::new([false; 9], [false; 9]), 3);
+/// life.step(|_, mut cell| *cell = cell.y == 1);
+///
+/// for _ in 0..2 {
+///     life.step(|grid, mut cell| {
+///         let alive = grid.neighbors(cell.x, cell.y).filter(|alive| **alive).count();
+///         *cell = alive == 3 || (alive == 2 && grid.get(cell.x, cell.y) == Some(&true));
+///     });
+///     assert_eq!(life.grid().get(1, 0), Some(&true));
+///     life.step(|grid, mut cell| {
+///         let alive = grid.neighbors(cell.x, cell.y).filter(|alive| **alive).count();
+///         *cell = alive == 3 || (alive == 2 && grid.get(cell.x, cell.y) == Some(&true));
+///     });
+///     assert_eq!(life.grid().get(0, 1), Some(&true));
+/// }
+/// ```
+pub struct GridBuffer<T, S = MoveSwap> {
+    buffer: DoubleBuffer<T, S>,
+    width: usize,
+}
+
+impl<T, S> GridBuffer<T, S> {
+    #[inline]
+    pub const fn new(buffer: DoubleBuffer<T, S>, width: usize) -> Self {
+        Self { buffer, width }
+    }
+
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Gives access to the current value as a grid.
+    #[inline]
+    pub fn grid<C>(&self) -> Grid<'_, C>
+    where
+        T: AsRef<[C]>,
+    {
+        Grid::new(self.buffer.as_ref().as_ref(), self.width)
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> DoubleBuffer<T, S> {
+        self.buffer
+    }
+}
+
+impl<T, S: SwapStrategy<T>> GridBuffer<T, S> {
+    /// Calls `f` for every cell of the next value with the grid of the current value,
+    /// then swaps.
+    #[inline]
+    pub fn step<C>(&mut self, mut f: impl FnMut(&Grid<'_, C>, CellMut<'_, C>))
+    where
+        T: AsRef<[C]> + AsMut<[C]>,
+    {
+        let width = self.width;
+        let (current, next) = self.buffer.split_mut();
+        let grid = Grid::new(current.as_ref(), width);
+        for (index, value) in next.as_mut().iter_mut().enumerate() {
+            let (x, y) = match index.checked_div(width) {
+                Some(y) => (index % width, y),
+                None => (index, 0),
+            };
+            f(&grid, CellMut { x, y, value });
+        }
+        self.buffer.swap();
+    }
+}
+
+impl<T, S> Deref for GridBuffer<T, S> {
+    type Target = DoubleBuffer<T, S>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighbors_at_corner() {
+        let cells = [1, 2, 3, 4, 5, 6];
+        let grid = Grid::new(&cells, 3);
+        assert_eq!(grid.height(), 2);
+        assert!(grid.neighbors(0, 0).copied().eq([2, 4, 5]));
+        assert_eq!(grid.get(3, 0), None);
+    }
+
+    #[test]
+    fn test_get_wrapping() {
+        let cells = [1, 2, 3, 4];
+        let grid = Grid::new(&cells, 2);
+        assert_eq!(grid.get_wrapping(-1, -1), Some(&4));
+        assert_eq!(grid.get_wrapping(2, 3), Some(&3));
+        assert_eq!(Grid::<u8>::new(&[], 0).get_wrapping(0, 0), None);
+    }
+}
@@ -0,0 +1,458 @@
+use core::fmt::{Debug, Formatter};
+use core::ops::{Deref, DerefMut};
+
+/// Generalization of [`DoubleBuffer`](crate::DoubleBuffer) retaining the last
+/// `DEPTH` published values.
+///
+/// Like [`DoubleBuffer`](crate::DoubleBuffer), it dereferences to the current
+/// value, mutably dereferences to the next value, and [`HistoryBuffer::swap()`]
+/// publishes the next value. Older values are accessible with [`HistoryBuffer::get()`].
+///
+/// Swapping rotates the values without moving them, the oldest value is reused
+/// as the next value and writes will be over it.
+///
+/// `DEPTH` must be greater than zero.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::HistoryBuffer;
+/// let mut inputs: HistoryBuffer<u8, 3> = HistoryBuffer::default();
+///
+/// for input in 1..=4 {
+///     *inputs = input;
+///     inputs.swap();
+/// }
+///
+/// assert_eq!(*inputs, 4);
+/// assert_eq!(inputs.get(1), Some(&3));
+/// assert_eq!(inputs.get(2), Some(&2));
+/// assert_eq!(inputs.get(3), None);
+/// ```
+pub struct HistoryBuffer<T, const DEPTH: usize> {
+    generation: u64,
+    // Published values retained, including the current one, at most `DEPTH`.
+    retained: usize,
+    next: usize,
+    slots: [T; DEPTH],
+    spare: T,
+}
+
+impl<T, const DEPTH: usize> HistoryBuffer<T, DEPTH> {
+    const SLOTS: usize = {
+        assert!(DEPTH > 0, "HistoryBuffer DEPTH must be greater than zero");
+        DEPTH + 1
+    };
+
+    /// Creates a buffer with the given current and next values, and no older values.
+    #[inline]
+    pub fn new(current: T, next: T) -> Self
+    where
+        T: Default,
+    {
+        let mut buffer = Self::from_fn(T::default);
+        *buffer.slot_mut(0) = current;
+        *buffer.slot_mut(1) = next;
+        buffer
+    }
+
+    /// Creates a buffer calling `f` for every value, starting with the current
+    /// one, then the next one and the rest.
+    #[inline]
+    pub fn from_fn(mut f: impl FnMut() -> T) -> Self {
+        let slots = core::array::from_fn(|_| f());
+        Self {
+            generation: 0,
+            retained: 1,
+            next: 1 % Self::SLOTS,
+            slots,
+            spare: f(),
+        }
+    }
+
+    /// Publishes the next value, then writes will be over the oldest value.
+    #[inline]
+    pub const fn swap(&mut self) {
+        self.next = (self.next + 1) % Self::SLOTS;
+        self.generation = self.generation.wrapping_add(1);
+        if self.retained < DEPTH {
+            self.retained += 1;
+        }
+    }
+
+    /// Number of swaps since the buffer was created.
+    #[inline]
+    pub const fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Gives access to the value published `nth_back` swaps ago, `0` being
+    /// the current value.
+    ///
+    /// Returns `None` if it's older than `DEPTH` swaps or than the buffer, or
+    /// was discarded by [`HistoryBuffer::rollback()`].
+    #[inline]
+    pub fn get(&self, nth_back: usize) -> Option<&T> {
+        if nth_back >= self.len() {
+            return None;
+        }
+        Some(self.slot(self.offset_back(nth_back)))
+    }
+
+    /// Number of published values retained, including the current one.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.retained
+    }
+
+    /// Always `false`, there is always a current value.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Iterates the retained published values, from the current one to the oldest one.
+    #[inline]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        (0..self.len()).map(move |nth_back| self.slot(self.offset_back(nth_back)))
+    }
+
+    /// Iterates the last `n` retained published values, from the current one to the
+    /// oldest one, like a window of [`HistoryBuffer::iter()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::HistoryBuffer;
+    /// let mut latency: HistoryBuffer<u32, 8> = HistoryBuffer::default();
+    /// for ms in [30, 10, 20, 60] {
+    ///     *latency = ms;
+    ///     latency.swap();
+    /// }
+    ///
+    /// let window = latency.windowed(3);
+    /// let average = window.clone().sum::<u32>() / window.len() as u32;
+    /// assert_eq!(average, 30);
+    /// ```
+    #[inline]
+    pub fn windowed(
+        &self,
+        n: usize,
+    ) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + Clone + '_ {
+        (0..n.min(self.len())).map(move |nth_back| self.slot(self.offset_back(nth_back)))
+    }
+
+    /// Folds the retained published values in the order they were published, from
+    /// the oldest one to the current one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::HistoryBuffer;
+    /// let mut level: HistoryBuffer<i32, 4> = HistoryBuffer::new(5, 0);
+    /// for value in [6, 8, 7] {
+    ///     *level = value;
+    ///     level.swap();
+    /// }
+    ///
+    /// let (rises, _) = level.fold_history((0, None), |(rises, previous), &value| {
+    ///     let rose = previous.is_some_and(|previous| value > previous);
+    ///     (rises + u32::from(rose), Some(value))
+    /// });
+    /// assert_eq!(rises, 2);
+    /// ```
+    #[inline]
+    pub fn fold_history<B>(&self, init: B, f: impl FnMut(B, &T) -> B) -> B {
+        self.iter().rev().fold(init, f)
+    }
+
+    /// Returns the smallest retained published value, the current one if there
+    /// are no older values.
+    #[inline]
+    pub fn min_history(&self) -> &T
+    where
+        T: Ord,
+    {
+        self.iter().min().unwrap_or_else(|| self.current())
+    }
+
+    /// Returns the largest retained published value, the current one if there
+    /// are no older values.
+    #[inline]
+    pub fn max_history(&self) -> &T
+    where
+        T: Ord,
+    {
+        self.iter().max().unwrap_or_else(|| self.current())
+    }
+
+    /// Gives access to the current value and mutable access to the next value at once,
+    /// like [`DoubleBuffer::split_mut()`](crate::DoubleBuffer::split_mut).
+    #[inline]
+    pub fn split_mut(&mut self) -> (&T, &mut T) {
+        let current = self.offset_back(0);
+        let next = self.next;
+        if next == DEPTH {
+            (&self.slots[current], &mut self.spare)
+        } else if current == DEPTH {
+            (&self.spare, &mut self.slots[next])
+        } else if current < next {
+            let (head, tail) = self.slots.split_at_mut(next);
+            (&head[current], &mut tail[0])
+        } else {
+            let (head, tail) = self.slots.split_at_mut(current);
+            (&tail[0], &mut head[next])
+        }
+    }
+
+    /// Restores the value published at `generation` as the current value,
+    /// discarding the newer values.
+    ///
+    /// Returns `false`, without changing anything, if that generation is not retained.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::HistoryBuffer;
+    /// let mut states: HistoryBuffer<u32, 4> = HistoryBuffer::default();
+    /// for state in 1..=3 {
+    ///     *states = state;
+    ///     states.swap();
+    /// }
+    ///
+    /// assert!(states.rollback(1));
+    /// assert_eq!(*states, 1);
+    /// assert_eq!(states.generation(), 1);
+    /// assert_eq!(states.get(1), Some(&0));
+    /// assert_eq!(states.get(2), None);
+    /// ```
+    pub fn rollback(&mut self, generation: u64) -> bool {
+        let Some(nth_back) = self.generation.checked_sub(generation) else {
+            return false;
+        };
+        let Ok(nth_back) = usize::try_from(nth_back) else {
+            return false;
+        };
+        if nth_back >= self.len() {
+            return false;
+        }
+        self.next = (self.offset_back(nth_back) + 1) % Self::SLOTS;
+        self.generation = generation;
+        // The newer values are overwritten as the next values.
+        self.retained -= nth_back;
+        true
+    }
+
+    /// Rolls back to `generation` and steps forward again to the current generation,
+    /// calling `step` with the generation to produce, the current value and the next value,
+    /// and swapping after each step.
+    ///
+    /// It's the base of rollback netcode: restore the last confirmed state and
+    /// re-apply the inputs of every frame after it.
+    ///
+    /// Returns `false`, without changing anything, if that generation is not retained.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::HistoryBuffer;
+    /// let mut inputs = [1, 1, 1];
+    /// let mut position: HistoryBuffer<i32, 8> = HistoryBuffer::default();
+    /// for generation in 1..=3 {
+    ///     let (current, next) = position.split_mut();
+    ///     *next = current + inputs[generation - 1];
+    ///     position.swap();
+    /// }
+    /// assert_eq!(*position, 3);
+    ///
+    /// // The input of the second frame was corrected.
+    /// inputs[1] = -1;
+    /// position.resimulate_from(1, |generation, current, next| {
+    ///     *next = current + inputs[generation as usize - 1];
+    /// });
+    /// assert_eq!(*position, 1);
+    /// assert_eq!(position.generation(), 3);
+    /// ```
+    pub fn resimulate_from(
+        &mut self,
+        generation: u64,
+        mut step: impl FnMut(u64, &T, &mut T),
+    ) -> bool {
+        let target = self.generation;
+        if !self.rollback(generation) {
+            return false;
+        }
+        while self.generation != target {
+            let generation = self.generation.wrapping_add(1);
+            let (current, next) = self.split_mut();
+            step(generation, current, next);
+            self.swap();
+        }
+        true
+    }
+
+    #[inline]
+    fn offset_back(&self, nth_back: usize) -> usize {
+        (self.next + Self::SLOTS - 1 - nth_back) % Self::SLOTS
+    }
+
+    #[inline]
+    fn slot(&self, offset: usize) -> &T {
+        self.slots.get(offset).unwrap_or(&self.spare)
+    }
+
+    #[inline]
+    fn slot_mut(&mut self, offset: usize) -> &mut T {
+        self.slots.get_mut(offset).unwrap_or(&mut self.spare)
+    }
+
+    #[inline]
+    fn current(&self) -> &T {
+        self.slot(self.offset_back(0))
+    }
+
+    #[inline]
+    fn next(&self) -> &T {
+        self.slot(self.next)
+    }
+
+    #[inline]
+    fn next_mut(&mut self) -> &mut T {
+        self.slot_mut(self.next)
+    }
+}
+
+impl<T: Debug, const DEPTH: usize> Debug for HistoryBuffer<T, DEPTH> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        struct History<'a, T, const DEPTH: usize>(&'a HistoryBuffer<T, DEPTH>);
+
+        impl<T: Debug, const DEPTH: usize> Debug for History<'_, T, DEPTH> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                f.debug_list().entries(self.0.iter()).finish()
+            }
+        }
+
+        f.debug_struct("HistoryBuffer")
+            .field("history", &History(self))
+            .field("next", self.next())
+            .finish()
+    }
+}
+
+impl<T: Default, const DEPTH: usize> Default for HistoryBuffer<T, DEPTH> {
+    #[inline]
+    fn default() -> Self {
+        Self::from_fn(T::default)
+    }
+}
+
+impl<T, const DEPTH: usize> Deref for HistoryBuffer<T, DEPTH> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.current()
+    }
+}
+
+impl<T, const DEPTH: usize> DerefMut for HistoryBuffer<T, DEPTH> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.next_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let mut buffer: HistoryBuffer<u32, 2> = HistoryBuffer::new(1, 2);
+        assert_eq!(*buffer, 1);
+        assert_eq!(buffer.len(), 1);
+
+        buffer.swap();
+        assert_eq!(*buffer, 2);
+        assert_eq!(buffer.get(1), Some(&1));
+    }
+
+    #[test]
+    fn test_oldest_is_reused_as_next() {
+        let mut buffer: HistoryBuffer<u32, 2> = HistoryBuffer::default();
+        for value in 1..=3 {
+            *buffer = value;
+            buffer.swap();
+        }
+        assert_eq!(*buffer.next(), 1);
+        assert!(buffer.iter().copied().eq([3, 2]));
+    }
+
+    #[test]
+    fn test_rollback_out_of_history() {
+        let mut buffer: HistoryBuffer<u32, 2> = HistoryBuffer::default();
+        for value in 1..=3 {
+            *buffer = value;
+            buffer.swap();
+        }
+        assert!(!buffer.rollback(1));
+        assert!(!buffer.rollback(4));
+        assert_eq!(*buffer, 3);
+
+        assert!(buffer.rollback(2));
+        assert_eq!(*buffer, 2);
+        assert_eq!(buffer.len(), 1);
+        assert_eq!(buffer.get(1), None);
+    }
+
+    #[test]
+    fn test_rollback_after_wraparound() {
+        let mut buffer: HistoryBuffer<u32, 4> = HistoryBuffer::default();
+        for value in 1..=100 {
+            *buffer = value;
+            buffer.swap();
+        }
+        *buffer = 9999;
+        assert!(buffer.rollback(98));
+        assert!(buffer.iter().copied().eq([98, 97]));
+
+        *buffer = 99;
+        buffer.swap();
+        assert!(buffer.iter().copied().eq([99, 98, 97]));
+    }
+
+    #[test]
+    fn test_split_mut_with_spare() {
+        let mut buffer: HistoryBuffer<u32, 2> = HistoryBuffer::default();
+        for value in 1..=4 {
+            let (current, next) = buffer.split_mut();
+            assert_eq!(*current, value - 1);
+            *next = value;
+            buffer.swap();
+        }
+        assert_eq!(*buffer, 4);
+    }
+
+    #[test]
+    fn test_min_max_only_retained() {
+        let mut buffer: HistoryBuffer<u32, 2> = HistoryBuffer::new(9, 0);
+        assert_eq!((buffer.min_history(), buffer.max_history()), (&9, &9));
+
+        for value in [1, 5, 3] {
+            *buffer = value;
+            buffer.swap();
+        }
+        assert_eq!((buffer.min_history(), buffer.max_history()), (&3, &5));
+        assert!(buffer.windowed(8).copied().eq([3, 5]));
+    }
+
+    #[test]
+    fn test_depth_one_is_double_buffer() {
+        let mut buffer: HistoryBuffer<u32, 1> = HistoryBuffer::new(1, 2);
+        buffer.swap();
+        assert_eq!(*buffer, 2);
+        assert_eq!(*buffer.next(), 1);
+        assert_eq!(buffer.get(1), None);
+    }
+}
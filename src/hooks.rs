@@ -0,0 +1,400 @@
+use core::hash::{Hash, Hasher};
+
+use crate::{DoubleBuffer, Instrument, SlotId, Tracked};
+
+/// Callback invoked on every swap with the new generation and the index (`0` or `1`)
+/// of the physical buffer that became the current value.
+///
+/// It's set from a function pointer with [`DoubleBuffer::set_on_swap()`], or from a
+/// closure with [`DoubleBuffer::set_on_swap_boxed()`] when the `alloc` feature is enabled.
+#[non_exhaustive]
+pub enum OnSwap {
+    Fn(fn(u64, usize)),
+    #[cfg(feature = "alloc")]
+    Boxed(alloc::boxed::Box<dyn FnMut(u64, usize) + Send>),
+}
+
+// SAFETY: the closure is only called through `&mut`, a shared reference can't use it.
+unsafe impl Sync for OnSwap {}
+
+impl OnSwap {
+    #[inline]
+    pub(crate) fn call(&mut self, generation: u64, current: usize) {
+        match self {
+            Self::Fn(on_swap) => on_swap(generation, current),
+            #[cfg(feature = "alloc")]
+            Self::Boxed(on_swap) => on_swap(generation, current),
+        }
+    }
+}
+
+/// Callback invoked on every swap with the generation and the physical buffer of
+/// the value that stopped being the current value.
+///
+/// It's set from a function pointer with [`DoubleBuffer::set_on_retire()`], or from a
+/// closure with [`DoubleBuffer::set_on_retire_boxed()`] when the `alloc` feature is enabled.
+#[non_exhaustive]
+pub enum OnRetire {
+    Fn(fn(u64, SlotId)),
+    #[cfg(feature = "alloc")]
+    Boxed(alloc::boxed::Box<dyn FnMut(u64, SlotId) + Send>),
+}
+
+// SAFETY: the closure is only called through `&mut`, a shared reference can't use it.
+unsafe impl Sync for OnRetire {}
+
+impl OnRetire {
+    #[inline]
+    pub(crate) fn call(&mut self, generation: u64, slot: SlotId) {
+        match self {
+            Self::Fn(on_retire) => on_retire(generation, slot),
+            #[cfg(feature = "alloc")]
+            Self::Boxed(on_retire) => on_retire(generation, slot),
+        }
+    }
+}
+
+/// Check run against the value about to be published, returning `false` if it's corrupted.
+///
+/// It's set from a function pointer with [`DoubleBuffer::set_invariant()`], or from a
+/// closure with [`DoubleBuffer::set_invariant_boxed()`] when the `alloc` feature is enabled.
+#[non_exhaustive]
+pub enum Invariant<T> {
+    Fn(fn(&T) -> bool),
+    #[cfg(feature = "alloc")]
+    Boxed(alloc::boxed::Box<dyn Fn(&T) -> bool + Send + Sync>),
+}
+
+impl<T> Invariant<T> {
+    #[inline]
+    pub(crate) fn call(&self, value: &T) -> bool {
+        match self {
+            Self::Fn(invariant) => invariant(value),
+            #[cfg(feature = "alloc")]
+            Self::Boxed(invariant) => invariant(value),
+        }
+    }
+}
+
+/// Computes a checksum of every published value.
+///
+/// It's set from a function pointer with [`DoubleBuffer::set_checksum()`], or from a
+/// closure with [`DoubleBuffer::set_checksum_boxed()`] when the `alloc` feature is enabled.
+#[non_exhaustive]
+pub enum ChecksumFn<T> {
+    Fn(fn(&T) -> u64),
+    #[cfg(feature = "alloc")]
+    Boxed(alloc::boxed::Box<dyn Fn(&T) -> u64 + Send + Sync>),
+}
+
+impl<T> ChecksumFn<T> {
+    #[inline]
+    pub(crate) fn call(&self, value: &T) -> u64 {
+        match self {
+            Self::Fn(checksum) => checksum(value),
+            #[cfg(feature = "alloc")]
+            Self::Boxed(checksum) => checksum(value),
+        }
+    }
+}
+
+fn hash_checksum<T: Hash, H: Hasher + Default>(value: &T) -> u64 {
+    let mut hasher = H::default();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl<T, S> DoubleBuffer<T, S, Tracked<T>> {
+    /// Sets the callback invoked on every swap, replacing the previous one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::TrackedBuffer;
+    /// let mut buffer: TrackedBuffer<u32> = TrackedBuffer::default();
+    /// buffer.set_on_swap(|generation, current| {
+    ///     println!("generation {generation} published from buffer {current}");
+    /// });
+    /// buffer.swap();
+    /// ```
+    #[inline]
+    pub fn set_on_swap(&mut self, on_swap: fn(u64, usize)) {
+        self.instrument.on_swap = Some(OnSwap::Fn(on_swap));
+    }
+
+    /// Sets a closure as the callback invoked on every swap, replacing the previous one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::TrackedBuffer;
+    /// let mut buffer: TrackedBuffer<u32> = TrackedBuffer::default();
+    /// let mut swaps = 0;
+    /// buffer.set_on_swap_boxed(move |_, _| swaps += 1);
+    /// buffer.swap();
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn set_on_swap_boxed(&mut self, on_swap: impl FnMut(u64, usize) + Send + 'static) {
+        self.instrument.on_swap = Some(OnSwap::Boxed(alloc::boxed::Box::new(on_swap)));
+    }
+
+    /// Removes the callback invoked on every swap, returning it.
+    #[inline]
+    pub fn take_on_swap(&mut self) -> Option<OnSwap> {
+        self.instrument.on_swap.take()
+    }
+
+    /// Sets the callback invoked when a value stops being the current value,
+    /// replacing the previous one.
+    ///
+    /// It receives the generation of the retired value and its physical buffer,
+    /// which is the next value after swaps that move, or the current value after
+    /// swaps that clone over it. Resources tied to that buffer can be recycled once
+    /// nothing reads that generation anymore, e.g. after a GPU fence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::TrackedBuffer;
+    /// let mut buffer: TrackedBuffer<u32> = TrackedBuffer::default();
+    /// buffer.set_on_retire(|generation, slot| {
+    ///     println!("buffer {} retired after generation {generation}", slot.index());
+    /// });
+    /// buffer.swap();
+    /// ```
+    #[inline]
+    pub fn set_on_retire(&mut self, on_retire: fn(u64, SlotId)) {
+        self.instrument.on_retire = Some(OnRetire::Fn(on_retire));
+    }
+
+    /// Sets a closure as the callback invoked when a value stops being the current
+    /// value, replacing the previous one, see [`DoubleBuffer::set_on_retire()`].
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn set_on_retire_boxed(&mut self, on_retire: impl FnMut(u64, SlotId) + Send + 'static) {
+        self.instrument.on_retire = Some(OnRetire::Boxed(alloc::boxed::Box::new(on_retire)));
+    }
+
+    /// Removes the callback invoked when a value stops being the current value, returning it.
+    #[inline]
+    pub fn take_on_retire(&mut self) -> Option<OnRetire> {
+        self.instrument.on_retire.take()
+    }
+
+    /// Sets the invariant checked against the next value before every swap,
+    /// replacing the previous one.
+    ///
+    /// It's only checked in debug builds, and it panics if the invariant is violated.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use double_buffer::TrackedBuffer;
+    /// let mut buffer: TrackedBuffer<u8> = TrackedBuffer::default();
+    /// buffer.set_invariant(|value| *value < 100);
+    ///
+    /// *buffer = 200;
+    /// buffer.swap(); // Panics in debug builds.
+    /// ```
+    #[inline]
+    pub fn set_invariant(&mut self, invariant: fn(&T) -> bool) {
+        self.instrument.invariant = Some(Invariant::Fn(invariant));
+    }
+
+    /// Sets a closure as the invariant checked against the next value before every
+    /// swap, replacing the previous one, see [`DoubleBuffer::set_invariant()`].
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn set_invariant_boxed(&mut self, invariant: impl Fn(&T) -> bool + Send + Sync + 'static) {
+        self.instrument.invariant = Some(Invariant::Boxed(alloc::boxed::Box::new(invariant)));
+    }
+
+    /// Removes the invariant checked before every swap, returning it.
+    #[inline]
+    pub fn take_invariant(&mut self) -> Option<Invariant<T>> {
+        self.instrument.invariant.take()
+    }
+
+    /// Sets the function computing the checksum of every published value,
+    /// replacing the previous one.
+    ///
+    /// The last checksum is available with [`DoubleBuffer::last_checksum()`].
+    #[inline]
+    pub fn set_checksum(&mut self, checksum: fn(&T) -> u64) {
+        self.instrument.checksum = Some(ChecksumFn::Fn(checksum));
+    }
+
+    /// Sets a closure computing the checksum of every published value, replacing
+    /// the previous one, see [`DoubleBuffer::set_checksum()`].
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn set_checksum_boxed(&mut self, checksum: impl Fn(&T) -> u64 + Send + Sync + 'static) {
+        self.instrument.checksum = Some(ChecksumFn::Boxed(alloc::boxed::Box::new(checksum)));
+    }
+
+    /// Sets the checksum of every published value to its [`Hash`] computed with
+    /// a new `H` hasher, replacing the previous checksum function.
+    ///
+    /// To compare checksums between machines, use a hasher with deterministic output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::TrackedBuffer;
+    /// # #[derive(Default)]
+    /// # struct Fnv(u64);
+    /// # impl core::hash::Hasher for Fnv {
+    /// #     fn finish(&self) -> u64 { self.0 }
+    /// #     fn write(&mut self, bytes: &[u8]) {
+    /// #         for byte in bytes { self.0 = (self.0 ^ *byte as u64).wrapping_mul(0x100000001b3); }
+    /// #     }
+    /// # }
+    /// let mut buffer: TrackedBuffer<[u8; 4]> = TrackedBuffer::default();
+    /// buffer.set_hash_checksum::<Fnv>();
+    /// assert_eq!(buffer.last_checksum(), None);
+    ///
+    /// buffer[0] = 1;
+    /// buffer.swap();
+    /// let (generation, checksum) = buffer.last_checksum().unwrap();
+    /// assert_eq!(generation, 1);
+    /// ```
+    #[inline]
+    pub fn set_hash_checksum<H: Hasher + Default>(&mut self)
+    where
+        T: Hash,
+    {
+        self.set_checksum(hash_checksum::<T, H>);
+    }
+
+    /// Removes the function computing the checksum of every published value, returning it.
+    #[inline]
+    pub fn take_checksum(&mut self) -> Option<ChecksumFn<T>> {
+        self.instrument.checksum.take()
+    }
+
+    /// Generation and checksum of the last value published with a checksum function set.
+    #[inline]
+    pub const fn last_checksum(&self) -> Option<(u64, u64)> {
+        self.instrument.last_checksum
+    }
+}
+
+impl<T, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Returns `true` if there is no invariant or the value holds it, in any build.
+    #[inline]
+    pub(crate) fn invariant_holds(&self, value: &T) -> bool {
+        self.instrument
+            .tracked()
+            .is_none_or(|tracked| tracked.invariant_holds(value))
+    }
+
+    #[inline]
+    pub(crate) fn check_invariant(&self) {
+        if let Some(tracked) = self.instrument.tracked() {
+            tracked.check(self.next());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TrackedBuffer;
+    use core::sync::atomic::{AtomicU64, Ordering};
+
+    static LAST_SWAP: AtomicU64 = AtomicU64::new(0);
+
+    fn record_swap(generation: u64, current: usize) {
+        LAST_SWAP.store(generation << 1 | current as u64, Ordering::Relaxed);
+    }
+
+    #[test]
+    fn test_on_swap() {
+        let mut buffer: TrackedBuffer<u32> = TrackedBuffer::default();
+        buffer.set_on_swap(record_swap);
+
+        buffer.swap();
+        assert_eq!(LAST_SWAP.load(Ordering::Relaxed), 1 << 1 | 1);
+
+        buffer.swap_with_clone();
+        assert_eq!(LAST_SWAP.load(Ordering::Relaxed), 2 << 1 | 1);
+
+        assert!(buffer.take_on_swap().is_some());
+        buffer.swap();
+        assert_eq!(LAST_SWAP.load(Ordering::Relaxed), 2 << 1 | 1);
+    }
+
+    static LAST_RETIRE: AtomicU64 = AtomicU64::new(0);
+
+    fn record_retire(generation: u64, slot: SlotId) {
+        LAST_RETIRE.store(generation << 1 | slot.index() as u64, Ordering::Relaxed);
+    }
+
+    #[test]
+    fn test_on_retire() {
+        let mut buffer: TrackedBuffer<u32> = TrackedBuffer::default();
+        buffer.set_on_retire(record_retire);
+
+        buffer.swap();
+        assert_eq!(LAST_RETIRE.load(Ordering::Relaxed), 0);
+
+        buffer.swap_with_clone();
+        assert_eq!(LAST_RETIRE.load(Ordering::Relaxed), 1 << 1 | 1);
+
+        assert!(buffer.take_on_retire().is_some());
+        buffer.swap();
+        assert_eq!(LAST_RETIRE.load(Ordering::Relaxed), 1 << 1 | 1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_boxed_on_swap() {
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+        let swaps = alloc::sync::Arc::new(AtomicU64::new(0));
+        let mut buffer: TrackedBuffer<u32> = TrackedBuffer::default();
+        let counted = alloc::sync::Arc::clone(&swaps);
+        buffer.set_on_swap_boxed(move |_, _| {
+            counted.fetch_add(1, Ordering::Relaxed);
+        });
+        assert_send_sync(&buffer);
+
+        buffer.swap();
+        buffer.swap();
+        assert_eq!(swaps.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn test_checksum() {
+        let mut buffer: TrackedBuffer<u8> = TrackedBuffer::default();
+        buffer.set_checksum(|value| u64::from(*value) * 2);
+
+        *buffer = 21;
+        buffer.swap();
+        assert_eq!(buffer.last_checksum(), Some((1, 42)));
+
+        assert!(buffer.take_checksum().is_some());
+        buffer.swap();
+        assert_eq!(buffer.last_checksum(), Some((1, 42)));
+    }
+
+    #[test]
+    fn test_invariant_holds() {
+        let mut buffer: TrackedBuffer<u8> = TrackedBuffer::default();
+        buffer.set_invariant(|value| *value < 100);
+        *buffer = 99;
+        buffer.swap_with_clone();
+        assert_eq!(buffer, 99);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "generation 1")]
+    fn test_invariant_violated() {
+        let mut buffer: TrackedBuffer<u8> = TrackedBuffer::default();
+        buffer.set_invariant(|value| *value < 100);
+        *buffer = 100;
+        buffer.swap_with_default();
+    }
+}
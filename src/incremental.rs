@@ -0,0 +1,137 @@
+use core::ops::Deref;
+
+use crate::{DoubleBuffer, MoveSwap, SwapStrategy};
+
+/// Wraps a [`DoubleBuffer`] of a slice to keep the next value a copy of the current
+/// one, like [`DoubleBuffer::swap_with_clone()`], but copying it across several
+/// frames to bound the time spent in each one.
+///
+/// [`IncrementalSync::publish()`] swaps and starts the copy of the new current
+/// value to the next value, and [`IncrementalSync::sync_some()`] copies up to a
+/// budget of bytes. The next value can't be written, and the buffer can't be
+/// published again, until the copy completes.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{DoubleBuffer, IncrementalSync};
+/// let mut frame = IncrementalSync::new(DoubleBuffer::new([0u8; 8], [0u8; 8]));
+///
+/// frame.next_mut().unwrap()[0] = 1;
+/// assert!(frame.publish());
+///
+/// // The next value is being synced, so it can't be written or published yet.
+/// assert!(frame.next_mut().is_none());
+/// assert!(!frame.sync_some::<u8>(4));
+/// assert!(!frame.publish());
+///
+/// assert!(frame.sync_some::<u8>(4));
+/// assert_eq!(frame.next_mut().unwrap()[0], 1);
+/// ```
+pub struct IncrementalSync<T, S = MoveSwap> {
+    buffer: DoubleBuffer<T, S>,
+    copied: Option<usize>,
+}
+
+impl<T, S> IncrementalSync<T, S> {
+    /// Creates it without a copy in progress, the next value is written as it is.
+    #[inline]
+    pub const fn new(buffer: DoubleBuffer<T, S>) -> Self {
+        Self {
+            buffer,
+            copied: None,
+        }
+    }
+
+    /// Starts copying the current value to the next value, restarting it if it
+    /// was in progress.
+    #[inline]
+    pub fn begin_sync(&mut self) {
+        self.copied = Some(0);
+    }
+
+    /// Returns `true` if there isn't a copy in progress.
+    #[inline]
+    pub const fn is_synced(&self) -> bool {
+        self.copied.is_none()
+    }
+
+    /// Copies the current value to the next value up to `budget_bytes`,
+    /// at least one element.
+    ///
+    /// Returns `true` if the copy completed, or there wasn't one in progress.
+    #[inline]
+    pub fn sync_some<E: Clone>(&mut self, budget_bytes: usize) -> bool
+    where
+        T: AsRef<[E]> + AsMut<[E]>,
+    {
+        let Some(copied) = self.copied else {
+            return true;
+        };
+        let (current, next) = self.buffer.split_mut();
+        let (current, next) = (current.as_ref(), next.as_mut());
+        let len = current.len().min(next.len());
+        let end = copied
+            .saturating_add((budget_bytes / size_of::<E>().max(1)).max(1))
+            .min(len);
+        next[copied..end].clone_from_slice(&current[copied..end]);
+        self.copied = (end < len).then_some(end);
+        self.copied.is_none()
+    }
+
+    /// Gives mutable access to the next value, or `None` while it's being synced.
+    #[inline]
+    pub fn next_mut(&mut self) -> Option<&mut T> {
+        if self.is_synced() {
+            Some(self.buffer.as_mut())
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> DoubleBuffer<T, S> {
+        self.buffer
+    }
+}
+
+impl<T, S: SwapStrategy<T>> IncrementalSync<T, S> {
+    /// Swaps the buffer and starts syncing the next value, if there isn't a copy in progress.
+    ///
+    /// Returns `true` if it was published.
+    #[inline]
+    pub fn publish(&mut self) -> bool {
+        if !self.is_synced() {
+            return false;
+        }
+        self.buffer.swap();
+        self.begin_sync();
+        true
+    }
+}
+
+impl<T, S> Deref for IncrementalSync<T, S> {
+    type Target = DoubleBuffer<T, S>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_budget_in_bytes() {
+        let mut buffer = IncrementalSync::new(DoubleBuffer::new([1u32; 4], [0u32; 4]));
+        buffer.begin_sync();
+
+        assert!(!buffer.sync_some::<u32>(9));
+        assert_eq!(buffer.next(), &[1, 1, 0, 0]);
+        assert!(!buffer.sync_some::<u32>(0));
+        assert!(buffer.sync_some::<u32>(usize::MAX));
+        assert_eq!(buffer.next(), &[1; 4]);
+    }
+}
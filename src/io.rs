@@ -0,0 +1,134 @@
+use core::convert::Infallible;
+
+use embedded_io::{BufRead, ErrorType, Read, ReadReady, Write};
+
+use crate::{DoubleBuffer, Instrument};
+
+/// Reader of the bytes of the current value of a [`DoubleBuffer`], created with
+/// [`DoubleBuffer::current_reader()`].
+#[derive(Debug, Clone)]
+pub struct CurrentReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<T: AsRef<[u8]>, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Gives a reader of the bytes of the current value, to stream it to a
+    /// `no_std` network or serial stack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// use embedded_io::Read;
+    ///
+    /// let buffer: DoubleBuffer<[u8; 4]> = DoubleBuffer::new([1, 2, 3, 4], [0; 4]);
+    /// let mut reader = buffer.current_reader();
+    ///
+    /// let mut packet = [0; 3];
+    /// assert_eq!(reader.read(&mut packet), Ok(3));
+    /// assert_eq!(reader.read(&mut packet), Ok(1));
+    /// assert_eq!(packet[0], 4);
+    /// ```
+    #[inline]
+    pub fn current_reader(&self) -> CurrentReader<'_> {
+        CurrentReader {
+            bytes: self.current().as_ref(),
+            position: 0,
+        }
+    }
+}
+
+impl CurrentReader<'_> {
+    /// Number of bytes read.
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    #[inline]
+    fn remaining(&self) -> &[u8] {
+        &self.bytes[self.position..]
+    }
+}
+
+impl ErrorType for CurrentReader<'_> {
+    type Error = Infallible;
+}
+
+impl Read for CurrentReader<'_> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let len = buf.len().min(self.bytes.len() - self.position);
+        buf[..len].copy_from_slice(&self.remaining()[..len]);
+        self.position += len;
+        Ok(len)
+    }
+}
+
+impl BufRead for CurrentReader<'_> {
+    #[inline]
+    fn fill_buf(&mut self) -> Result<&[u8], Self::Error> {
+        Ok(self.remaining())
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.position = self.bytes.len().min(self.position + amt);
+    }
+}
+
+impl ReadReady for CurrentReader<'_> {
+    #[inline]
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+}
+
+impl<T: ErrorType, S, I: Instrument<T>> ErrorType for DoubleBuffer<T, S, I> {
+    type Error = T::Error;
+}
+
+/// Writes to the next value, like `Vec<u8>` appending the bytes.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::DoubleBuffer;
+/// use embedded_io::Write;
+///
+/// let mut buffer: DoubleBuffer<Vec<u8>> = DoubleBuffer::default();
+/// buffer.write_all(b"frame").unwrap();
+/// assert!(buffer.is_empty());
+///
+/// buffer.swap();
+/// assert_eq!(*buffer, b"frame");
+/// ```
+impl<T: Write, S, I: Instrument<T>> Write for DoubleBuffer<T, S, I> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        self.as_mut().write(buf)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.next_mut().flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buf_read() {
+        let buffer: DoubleBuffer<[u8; 4]> = DoubleBuffer::new([1, 2, 3, 4], [0; 4]);
+        let mut reader = buffer.current_reader();
+        reader.consume(1);
+        assert_eq!(reader.fill_buf(), Ok(&[2, 3, 4][..]));
+
+        reader.consume(8);
+        assert_eq!(reader.position(), 4);
+        assert_eq!(reader.read(&mut [0; 2]), Ok(0));
+    }
+}
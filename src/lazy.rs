@@ -0,0 +1,171 @@
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use core::borrow::Borrow;
+use core::fmt::{Debug, Formatter};
+use core::ops::Deref;
+
+use crate::{DoubleBuffer, TrackedBuffer};
+
+/// Double buffer that starts borrowing its published value, and only allocates
+/// both values from the first publish.
+///
+/// Until then, it reads the borrowed value without copying it, so read-mostly
+/// consumers of large static data don't pay for two owned copies up front. The
+/// first write to the staged value copies the borrowed one, and the first
+/// publish copies it again as the next value, like [`MoveSwap`](crate::MoveSwap).
+///
+/// It dereferences to the published value.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::LazyBuffer;
+/// static DEFAULT_PALETTE: [u8; 4] = [0, 85, 170, 255];
+///
+/// let mut palette: LazyBuffer<[u8]> = LazyBuffer::new(&DEFAULT_PALETTE);
+/// assert!(palette.is_borrowed());
+///
+/// palette.staged_mut()[0] = 10;
+/// assert_eq!(*palette, [0, 85, 170, 255]);
+///
+/// palette.publish();
+/// assert!(!palette.is_borrowed());
+/// assert_eq!(*palette, [10, 85, 170, 255]);
+/// ```
+pub struct LazyBuffer<'a, B: ?Sized + ToOwned> {
+    state: State<'a, B>,
+}
+
+enum State<'a, B: ?Sized + ToOwned> {
+    Borrowed {
+        current: &'a B,
+        next: Option<B::Owned>,
+    },
+    Owned(Box<TrackedBuffer<B::Owned>>),
+}
+
+impl<'a, B: ?Sized + ToOwned> LazyBuffer<'a, B> {
+    /// Creates it with `current` published and staged, without copying it.
+    #[inline]
+    pub const fn new(current: &'a B) -> Self {
+        Self {
+            state: State::Borrowed {
+                current,
+                next: None,
+            },
+        }
+    }
+
+    /// Returns `true` if it hasn't been published yet, so the published value is
+    /// still borrowed.
+    #[inline]
+    pub const fn is_borrowed(&self) -> bool {
+        matches!(self.state, State::Borrowed { .. })
+    }
+
+    /// Gives access to the staged value.
+    #[inline]
+    pub fn staged(&self) -> &B {
+        match &self.state {
+            State::Borrowed {
+                next: Some(next), ..
+            } => next.borrow(),
+            State::Borrowed { current, .. } => current,
+            State::Owned(buffer) => buffer.next().borrow(),
+        }
+    }
+
+    /// Gives mutable access to the staged value, copying the borrowed value first
+    /// if it's the first write.
+    #[inline]
+    pub fn staged_mut(&mut self) -> &mut B::Owned {
+        match &mut self.state {
+            State::Borrowed { current, next } => next.get_or_insert_with(|| (*current).to_owned()),
+            State::Owned(buffer) => buffer.as_mut(),
+        }
+    }
+
+    /// Publishes the staged value, the first publish copies the borrowed value as
+    /// the next value.
+    #[inline]
+    pub fn publish(&mut self) {
+        if let State::Borrowed { current, next } = &mut self.state {
+            let staged = next.take().unwrap_or_else(|| current.to_owned());
+            self.state = State::Owned(Box::new(DoubleBuffer::tracked(current.to_owned(), staged)));
+        }
+        if let State::Owned(buffer) = &mut self.state {
+            buffer.swap();
+        }
+    }
+
+    /// Number of publishes since it was created, see [`DoubleBuffer::generation()`].
+    #[inline]
+    pub const fn generation(&self) -> u64 {
+        match &self.state {
+            State::Borrowed { .. } => 0,
+            State::Owned(buffer) => buffer.generation(),
+        }
+    }
+
+    /// Returns the owned buffer, copying the borrowed value if it wasn't published yet.
+    #[inline]
+    pub fn into_owned(self) -> TrackedBuffer<B::Owned> {
+        match self.state {
+            State::Borrowed { current, next } => {
+                let staged = next.unwrap_or_else(|| current.to_owned());
+                DoubleBuffer::tracked(current.to_owned(), staged)
+            }
+            State::Owned(buffer) => *buffer,
+        }
+    }
+}
+
+impl<B: ?Sized + ToOwned + Debug> Debug for LazyBuffer<'_, B> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LazyBuffer")
+            .field("published", &&**self)
+            .field("staged", &self.staged())
+            .field("borrowed", &self.is_borrowed())
+            .finish()
+    }
+}
+
+impl<'a, B: ?Sized + ToOwned> From<&'a B> for LazyBuffer<'a, B> {
+    #[inline]
+    fn from(current: &'a B) -> Self {
+        Self::new(current)
+    }
+}
+
+impl<B: ?Sized + ToOwned> Deref for LazyBuffer<'_, B> {
+    type Target = B;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        match &self.state {
+            State::Borrowed { current, .. } => current,
+            State::Owned(buffer) => buffer.current().borrow(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_publish_without_writes() {
+        let mut name = LazyBuffer::new("first");
+        assert_eq!(name.staged(), "first");
+
+        name.publish();
+        assert_eq!(&*name, "first");
+        assert_eq!(name.generation(), 1);
+
+        name.staged_mut().push_str(" second");
+        name.publish();
+        assert_eq!(&*name, "first second");
+        assert_eq!(name.into_owned().next(), "first");
+    }
+}
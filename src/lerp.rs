@@ -0,0 +1,150 @@
+/// Linear interpolation between two values.
+///
+/// The factor type `A` is `f32` by default, `0` gives `self` and `1` gives `to`.
+///
+/// With the `fixed` feature, it's implemented for the fixed-point numbers of the
+/// `fixed` crate and for integers, with fixed-point factors, for targets without
+/// a floating-point unit.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::Lerp;
+/// #[derive(Debug, PartialEq)]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// impl Lerp for Position {
+///     fn lerp(&self, to: &Self, alpha: f32) -> Self {
+///         Position { x: self.x.lerp(&to.x, alpha), y: self.y.lerp(&to.y, alpha) }
+///     }
+/// }
+///
+/// let from = Position { x: 0.0, y: 2.0 };
+/// let to = Position { x: 4.0, y: 0.0 };
+/// assert_eq!(from.lerp(&to, 0.5), Position { x: 2.0, y: 1.0 });
+/// ```
+pub trait Lerp<A = f32> {
+    fn lerp(&self, to: &Self, alpha: A) -> Self;
+}
+
+impl Lerp for f32 {
+    #[inline]
+    fn lerp(&self, to: &Self, alpha: f32) -> Self {
+        self + (to - self) * alpha
+    }
+}
+
+impl Lerp<f64> for f64 {
+    #[inline]
+    fn lerp(&self, to: &Self, alpha: f64) -> Self {
+        self + (to - self) * alpha
+    }
+}
+
+impl Lerp<f32> for f64 {
+    #[inline]
+    fn lerp(&self, to: &Self, alpha: f32) -> Self {
+        self.lerp(to, f64::from(alpha))
+    }
+}
+
+impl<T: Lerp<A>, A: Copy, const N: usize> Lerp<A> for [T; N] {
+    #[inline]
+    fn lerp(&self, to: &Self, alpha: A) -> Self {
+        core::array::from_fn(|index| self[index].lerp(&to[index], alpha))
+    }
+}
+
+/// Fixed-point numbers are interpolated with a factor of the same fixed-point type,
+/// with any number of fractional bits, and integers with a factor of the
+/// fixed-point type of the same size.
+///
+/// The fixed-point types have an inherent `lerp()` method, with the factor as
+/// receiver, so call it as `Lerp::lerp()` outside of generic code.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{DoubleBufferWithPrevious, Lerp};
+/// use fixed::types::{I16F16, I8F8};
+///
+/// let mut position = DoubleBufferWithPrevious::new(I16F16::ZERO, I16F16::from_num(2), I16F16::ZERO);
+/// *position = I16F16::from_num(3);
+/// position.swap();
+/// assert_eq!(position.interpolated(I16F16::from_num(0.5)), 2.5);
+///
+/// // The midpoint of two integers.
+/// assert_eq!(Lerp::lerp(&10i16, &20, I8F8::from_num(0.5)), 15);
+/// ```
+#[cfg(feature = "fixed")]
+mod fixed_point {
+    use fixed::types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8, U0};
+    use fixed::{
+        FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
+        FixedU8,
+    };
+
+    use super::Lerp;
+
+    macro_rules! impl_lerp_for_fixed {
+        ($($int:ty => $fixed:ident: $le_eq:ident),* $(,)?) => {
+            $(
+                impl<Frac, AlphaFrac: $le_eq> Lerp<$fixed<AlphaFrac>> for $fixed<Frac> {
+                    #[inline]
+                    fn lerp(&self, to: &Self, alpha: $fixed<AlphaFrac>) -> Self {
+                        alpha.lerp(*self, *to)
+                    }
+                }
+
+                impl<AlphaFrac: $le_eq> Lerp<$fixed<AlphaFrac>> for $int {
+                    #[inline]
+                    fn lerp(&self, to: &Self, alpha: $fixed<AlphaFrac>) -> Self {
+                        let from = $fixed::<U0>::from_bits(*self);
+                        let to = $fixed::<U0>::from_bits(*to);
+                        alpha.lerp(from, to).to_bits()
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_lerp_for_fixed!(
+        i8 => FixedI8: LeEqU8,
+        i16 => FixedI16: LeEqU16,
+        i32 => FixedI32: LeEqU32,
+        i64 => FixedI64: LeEqU64,
+        i128 => FixedI128: LeEqU128,
+        u8 => FixedU8: LeEqU8,
+        u16 => FixedU16: LeEqU16,
+        u32 => FixedU32: LeEqU32,
+        u64 => FixedU64: LeEqU64,
+        u128 => FixedU128: LeEqU128,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lerp_array() {
+        let from = [0.0f32, 10.0];
+        let to = [1.0f32, 20.0];
+        assert_eq!(from.lerp(&to, 0.0), from);
+        assert_eq!(from.lerp(&to, 0.5), [0.5, 15.0]);
+        assert_eq!(from.lerp(&to, 1.0), to);
+    }
+
+    #[cfg(feature = "fixed")]
+    #[test]
+    fn test_lerp_integers() {
+        use fixed::types::{I16F16, U0F8, U16F16};
+
+        assert_eq!(Lerp::lerp(&200u8, &100, U0F8::from_num(0.25)), 175);
+        assert_eq!(Lerp::lerp(&-8i32, &8, I16F16::from_num(0.75)), 4);
+        assert_eq!(Lerp::lerp(&0u32, &10, U16F16::from_num(1)), 10);
+    }
+}
@@ -1,9 +1,227 @@
 #![doc = include_str!("../README.md")]
 #![no_std]
 
-use core::ops::{Deref, DerefMut};
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 use core::borrow::{Borrow, BorrowMut};
 use core::fmt::{Debug, Formatter, Pointer};
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+
+mod access;
+mod aged;
+mod array;
+mod audio;
+mod auto_swap;
+mod batch;
+#[cfg(feature = "alloc")]
+mod boxed;
+#[cfg(feature = "alloc")]
+mod budgeted_history;
+mod byte_size;
+mod cell;
+mod chunked;
+mod clear;
+mod clock;
+#[cfg(feature = "lz4")]
+mod compressed_history;
+#[cfg(feature = "serde")]
+mod config;
+mod copy;
+#[cfg(feature = "alloc")]
+mod cow;
+mod damage;
+#[cfg(feature = "serde")]
+mod delta;
+mod diff;
+#[cfg(feature = "embedded-dma")]
+mod dma;
+#[cfg(feature = "alloc")]
+mod drain;
+#[cfg(feature = "embedded-graphics")]
+mod embedded_graphics;
+mod error;
+mod exchange;
+mod explicit;
+mod fixed_timestep;
+mod frames_in_flight;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
+#[cfg(feature = "wgpu")]
+mod gpu;
+mod grid;
+mod history;
+mod hooks;
+mod incremental;
+#[cfg(feature = "embedded-io")]
+mod io;
+#[cfg(feature = "alloc")]
+mod lazy;
+mod lerp;
+mod lifecycle;
+#[cfg(feature = "alloc")]
+mod log_buffer;
+#[cfg(feature = "alloc")]
+mod map;
+mod merge;
+mod multi_channel;
+#[cfg(feature = "std")]
+mod offload;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod patch;
+#[cfg(feature = "postcard")]
+mod persist;
+#[cfg(feature = "embedded-hal-async")]
+mod ping_pong;
+mod poison;
+mod policy;
+#[cfg(feature = "alloc")]
+mod pool;
+#[cfg(feature = "proptest")]
+mod property;
+mod raw;
+mod record;
+mod redacted;
+#[cfg(feature = "alloc")]
+mod registry;
+#[cfg(feature = "std")]
+mod scheduler;
+mod shared;
+mod slot;
+#[cfg(feature = "alloc")]
+mod split;
+mod stale;
+mod stats;
+mod strategy;
+mod swap;
+#[cfg(feature = "metrics")]
+mod telemetry;
+#[cfg(feature = "std")]
+mod thread_local;
+mod throttle;
+mod timestamped;
+#[cfg(feature = "timing")]
+mod timing;
+mod tracked;
+mod trigger;
+mod typestate;
+#[cfg(feature = "alloc")]
+mod vec;
+#[cfg(kani)]
+mod verification;
+mod view;
+#[cfg(feature = "wasm")]
+mod wasm;
+mod watchdog;
+#[cfg(feature = "zeroize")]
+mod wipe;
+#[cfg(feature = "bincode")]
+mod wire;
+mod with_previous;
+
+pub use aged::Aged;
+pub use array::DoubleBufferArray;
+pub use audio::AudioBlocks;
+pub use auto_swap::AutoSwap;
+pub use batch::Batch;
+#[cfg(feature = "alloc")]
+pub use budgeted_history::{BudgetedHistory, Changes, DropOldest, EvictionPolicy, KeyframeDelta};
+pub use byte_size::ByteSize;
+pub use cell::DoubleBufferCell;
+pub use chunked::ChunkedBuffer;
+pub use clear::Clear;
+pub use clock::Clock;
+#[cfg(feature = "std")]
+pub use clock::StdClock;
+#[cfg(feature = "lz4")]
+pub use compressed_history::CompressedHistory;
+#[cfg(feature = "serde")]
+pub use config::ConfigBuffer;
+#[cfg(feature = "alloc")]
+pub use cow::CowBuffer;
+pub use damage::{DamageTracked, Rect};
+#[cfg(feature = "serde")]
+pub use delta::Delta;
+pub use diff::Diff;
+#[cfg(feature = "embedded-dma")]
+pub use dma::{CurrentHalf, NextHalf};
+#[cfg(feature = "derive")]
+pub use double_buffer_derive::DoubleBuffered;
+#[cfg(feature = "derive")]
+pub use double_buffer_derive::Swap;
+#[cfg(feature = "alloc")]
+pub use error::OddLengthError;
+#[cfg(feature = "postcard")]
+pub use error::PersistError;
+#[cfg(feature = "serde")]
+pub use error::{ConfigError, DeltaError};
+pub use error::{IncompleteFrame, PatchError, PublishError};
+pub use explicit::ExplicitDoubleBuffer;
+pub use fixed_timestep::FixedTimestep;
+pub use frames_in_flight::FramesInFlight;
+#[cfg(feature = "wgpu")]
+pub use gpu::StagingPair;
+pub use grid::{CellMut, Grid, GridBuffer};
+pub use history::HistoryBuffer;
+pub use hooks::{ChecksumFn, Invariant, OnRetire, OnSwap};
+pub use incremental::IncrementalSync;
+#[cfg(feature = "embedded-io")]
+pub use io::CurrentReader;
+#[cfg(feature = "alloc")]
+pub use lazy::LazyBuffer;
+pub use lerp::Lerp;
+#[cfg(feature = "alloc")]
+pub use log_buffer::LogBuffer;
+#[cfg(feature = "alloc")]
+pub use map::DoubleBufferedMap;
+pub use merge::Merge;
+pub use multi_channel::MultiChannelBuffer;
+#[cfg(feature = "std")]
+pub use offload::PendingClone;
+pub use patch::Patch;
+#[cfg(feature = "embedded-hal-async")]
+pub use ping_pong::PingPong;
+pub use policy::{AccessBuffer, AccessPolicy, ReadCurrentWriteNext, ReadWriteCurrent};
+#[cfg(feature = "alloc")]
+pub use pool::Pool;
+pub use record::{Recorded, Recorder};
+pub use redacted::Redacted;
+#[cfg(feature = "alloc")]
+pub use registry::BufferRegistry;
+#[cfg(feature = "std")]
+pub use scheduler::SwapScheduler;
+pub use shared::SharedDoubleBuffer;
+pub use slot::SlotId;
+#[cfg(feature = "alloc")]
+pub use split::SplitBuffer;
+use stats::SwapKind;
+pub use stats::{BufferStats, SwapStats};
+pub use strategy::{ClearSwap, CloneSwap, DefaultSwap, MoveSwap, SwapStrategy};
+pub use swap::Swap;
+#[cfg(feature = "alloc")]
+pub use swap::SwapGroup;
+#[cfg(feature = "std")]
+pub use thread_local::{ThreadLocalBuffer, ThreadSlot};
+pub use throttle::Throttled;
+pub use timestamped::Timestamped;
+#[cfg(feature = "timing")]
+pub use timing::FRAME_WINDOW;
+pub use tracked::{Instrument, Tracked, TrackedBuffer};
+pub use trigger::SwapTrigger;
+pub use typestate::{Reading, Writing};
+#[cfg(feature = "alloc")]
+pub use vec::{DoubleBufferedVec, EventBuffer};
+pub use view::{Projection, ReadOnlyView, Staged, WriteOnlyView};
+#[cfg(feature = "wasm")]
+pub use wasm::JsDoubleBuffer;
+pub use watchdog::Watchdog;
+#[cfg(feature = "zeroize")]
+pub use wipe::ZeroizeOnSwap;
+pub use with_previous::DoubleBufferWithPrevious;
 
 /// Encapsulates a piece of state that can be modified and
 /// we want all outside code to see the edit as a single
@@ -16,13 +234,18 @@ use core::fmt::{Debug, Formatter, Pointer};
 ///
 /// # Swapping
 ///
-/// There are three ways to swap:
+/// There are four ways to swap:
 ///
 /// 1. [`DoubleBuffer::swap()`] - when swapping, the next value will have the previous current value.
 /// 2. [`DoubleBuffer::swap_with_clone()`] - when swapping, the next value will keep same and will be cloned to the current value.
 /// 3. [`DoubleBuffer::swap_with_default()`] - like [`DoubleBuffer::swap()`] but the next value will be set to the default value of the type.
+/// 4. [`DoubleBuffer::swap_with_clear()`] - like [`DoubleBuffer::swap_with_default()`] but the next value is cleared in place.
+///
+/// Note that for the third way, the type must implement [`Default`], and for the fourth way, [`Clear`].
 ///
-/// Note that for the third way, the type must implement [`Default`].
+/// The way [`DoubleBuffer::swap()`] publishes is chosen by the [`SwapStrategy`] type
+/// parameter, [`MoveSwap`] by default, so every call site swaps the same way for the
+/// same buffer. See [`CloneSwap`], [`DefaultSwap`] and [`ClearSwap`] for the other ways.
 ///
 /// You can read about the two ways [how the buffers are swapped](https://gameprogrammingpatterns.com/double-buffer.html#how-are-the-buffers-swapped)
 /// in "Game Programming Patterns" by Robert Nystrom.
@@ -35,8 +258,21 @@ use core::fmt::{Debug, Formatter, Pointer};
 /// Or [`DoubleBuffer::swap_with_default()`] if the type implements [`Default`] and
 /// starts with the default value is important.
 ///
+/// Prefer [`DoubleBuffer::swap_with_clear()`] over [`DoubleBuffer::swap_with_default()`]
+/// for heap allocated types like `Vec<T>`, because it keeps the capacity of the next value.
+///
 /// Only use [`DoubleBuffer::swap_with_clone()`] if it's important to keep the pointer
-/// address of the current value unchanged.
+/// address of the current value unchanged. It reuses the capacity of the current value,
+/// but still copies the whole next value. For arrays and vectors of [`Copy`] elements,
+/// like byte framebuffers, [`DoubleBuffer::swap_with_copy()`] is guaranteed to be a `memcpy`.
+///
+/// ## Size
+///
+/// Swapping only flips which value is current, so besides its two values a buffer
+/// keeps the [`SlotId`] of the current one. It takes a byte more than
+/// `2 * size_of::<T>()`, rounded up to the alignment of `T`, and `Option` of it
+/// takes no more. For many small values, [`DoubleBufferArray`] shares one slot
+/// between all of them. A [`TrackedBuffer`] also keeps its [`Tracked`] state.
 ///
 /// # Examples
 ///
@@ -65,18 +301,57 @@ use core::fmt::{Debug, Formatter, Pointer};
 /// buffer.swap_with_default();
 /// print!("{:?}", buffer); // DoubleBuffer { current: [3, ...], next: [0, ...] }
 /// ```
-pub struct DoubleBuffer<T> {
-    swapped: bool,
+///
+/// The swap strategy can be part of the type:
+///
+/// ```
+/// # use double_buffer::{DoubleBuffer, CloneSwap};
+/// let mut buffer: DoubleBuffer<u32, CloneSwap> = DoubleBuffer::with_strategy(1, 2);
+///
+/// buffer.swap();
+/// assert_eq!(buffer, 2);
+///
+/// buffer.swap();
+/// assert_eq!(buffer, 2);
+/// ```
+///
+/// Values of different types implementing a trait can be double buffered as
+/// `Box<dyn Trait>`, the boxes are coerced when the type of the buffer is known:
+///
+/// ```
+/// # use double_buffer::DoubleBuffer;
+/// use core::fmt::Display;
+///
+/// let mut label: DoubleBuffer<Box<dyn Display>> = DoubleBuffer::new(Box::new(1), Box::new("none"));
+/// *label = Box::new('a');
+/// label.swap();
+/// assert_eq!(label.to_string(), "a");
+/// ```
+///
+/// See [`DoubleBuffer::new_dyn()`] to name the type in the constructor instead.
+pub struct DoubleBuffer<T, S = MoveSwap, I = ()> {
+    current: SlotId,
+    instrument: I,
     buffers: [T; 2],
+    strategy: PhantomData<fn() -> S>,
 }
 
+// Without instrumentation, a buffer is only its two values and the current slot,
+// a byte more than `2 * size_of::<T>()`, whose niche is left for `Option`.
+const _: () = assert!(size_of::<DoubleBuffer<[u8; 3]>>() == 7);
+const _: () = assert!(size_of::<Option<DoubleBuffer<[u8; 3]>>>() == 7);
+
 impl<T> DoubleBuffer<T> {
     #[inline]
     pub const fn new(current: T, next: T) -> Self {
-        Self { swapped: false, buffers: [current, next] }
+        Self::with_strategy(current, next)
     }
+}
 
-    /// Swaps the current and next values,
+impl<T, S: SwapStrategy<T>, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Swaps the buffers using the [`SwapStrategy`] of the buffer type.
+    ///
+    /// With the default [`MoveSwap`] strategy, the current and next values are swapped,
     /// then writes will be over the previous current value.
     ///
     /// This changes the pointer address of the current value.
@@ -94,52 +369,280 @@ impl<T> DoubleBuffer<T> {
     /// ```
     #[inline]
     pub fn swap(&mut self) {
-        self.swapped = !self.swapped;
+        S::swap(self);
+    }
+
+    /// Swaps like [`DoubleBuffer::swap()`], but instead of panicking, returns an error
+    /// if the buffer is poisoned or the next value violates the invariant of the
+    /// buffer, see [`DoubleBuffer::set_invariant()`], which is checked in any build.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::{DoubleBuffer, PublishError, TrackedBuffer};
+    /// let mut buffer: TrackedBuffer<u32> = DoubleBuffer::tracked(1, 2);
+    /// buffer.set_invariant(|value| *value < 100);
+    ///
+    /// *buffer = 100;
+    /// assert_eq!(buffer.try_swap(), Err(PublishError::Invalid));
+    /// assert_eq!(buffer, 1);
+    ///
+    /// *buffer = 3;
+    /// assert_eq!(buffer.try_swap(), Ok(()));
+    /// assert_eq!(buffer, 3);
+    /// ```
+    #[inline]
+    pub fn try_swap(&mut self) -> Result<(), PublishError> {
+        if self.poisoned() {
+            return Err(PublishError::Poisoned);
+        }
+        if !self.invariant_holds(self.next()) {
+            return Err(PublishError::Invalid);
+        }
+        self.swap();
+        Ok(())
     }
+}
 
+impl<T, S: SwapStrategy<T>> DoubleBuffer<T, S, Tracked<T>> {
+    /// Swaps like [`DoubleBuffer::swap()`] only if the next value was
+    /// mutably accessed since the last swap.
+    ///
+    /// Returns `true` if the buffer was swapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::{DoubleBuffer, TrackedBuffer};
+    /// let mut buffer: TrackedBuffer<u32> = DoubleBuffer::tracked(1, 2);
+    /// assert!(!buffer.swap_if_dirty());
+    /// assert_eq!(buffer, 1);
+    ///
+    /// *buffer = 3;
+    /// assert!(buffer.swap_if_dirty());
+    /// assert_eq!(buffer, 3);
+    /// ```
     #[inline]
-    const fn current_offset(&self) -> usize {
-        if self.swapped {
-            return 1;
+    pub fn swap_if_dirty(&mut self) -> bool {
+        if !self.instrument.dirty {
+            return false;
         }
-        return 0;
+        self.swap();
+        true
     }
+}
 
+impl<T, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Creates a buffer which [`DoubleBuffer::swap()`] uses the strategy `S`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::{DoubleBuffer, DefaultSwap};
+    /// let mut buffer = DoubleBuffer::<u32, DefaultSwap>::with_strategy(1, 2);
+    /// buffer.swap();
+    /// assert_eq!(buffer, 2);
+    ///
+    /// // The next value was reset to the default value.
+    /// *buffer += 1;
+    /// buffer.swap();
+    /// assert_eq!(buffer, 1);
+    /// ```
     #[inline]
-    const fn next_offset(&self) -> usize {
-        if self.swapped {
-            return 0;
+    pub const fn with_strategy(current: T, next: T) -> Self {
+        Self {
+            current: SlotId::FIRST,
+            instrument: I::NEW,
+            buffers: [current, next],
+            strategy: PhantomData,
+        }
+    }
+
+    /// Swaps buffers like [`MoveSwap`] and sets the next value to the value
+    /// produced by `reset`, only if it could be produced.
+    ///
+    /// If `reset` fails, the buffer is not swapped and the error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let mut buffer: DoubleBuffer<u32> = DoubleBuffer::new(1, 2);
+    ///
+    /// assert_eq!(buffer.try_swap_with(|| Err("busy")), Err("busy"));
+    /// assert_eq!(buffer, 1);
+    ///
+    /// assert_eq!(buffer.try_swap_with(|| Ok::<_, ()>(0)), Ok(()));
+    /// assert_eq!(buffer, 2);
+    /// ```
+    #[inline]
+    pub fn try_swap_with<E>(&mut self, reset: impl FnOnce() -> Result<T, E>) -> Result<(), E> {
+        let next = reset()?;
+        self.check_invariant();
+        self.flip();
+        *self.next_mut() = next;
+        self.published(SwapKind::With);
+        Ok(())
+    }
+
+    /// Publishes `value` directly, without writing it to the next value first,
+    /// for values produced outside the buffer, like a decoded frame.
+    ///
+    /// The previous current value becomes the next value, like with [`MoveSwap`],
+    /// and the next value that was staged is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::{DoubleBuffer, TrackedBuffer};
+    /// let mut buffer: TrackedBuffer<u32> = DoubleBuffer::tracked(1, 2);
+    ///
+    /// assert_eq!(buffer.replace_current(3), 2);
+    /// assert_eq!(buffer, 3);
+    /// assert_eq!(*buffer.next(), 1);
+    /// assert_eq!(buffer.generation(), 1);
+    /// ```
+    #[inline]
+    pub fn replace_current(&mut self, value: T) -> T {
+        let staged = core::mem::replace(self.as_mut(), value);
+        self.check_invariant();
+        self.flip();
+        self.published(SwapKind::With);
+        staged
+    }
+
+    #[inline]
+    const fn flip(&mut self) {
+        self.current = self.current.other();
+    }
+
+    #[inline]
+    fn move_swap(&mut self) {
+        self.check_invariant();
+        self.flip();
+        self.published(SwapKind::Move);
+    }
+
+    #[inline]
+    fn mark_read(&self) {
+        if let Some(tracked) = self.instrument.tracked() {
+            tracked.read();
+        }
+    }
+
+    #[inline]
+    fn mark_dirty(&mut self) {
+        if let Some(tracked) = self.instrument.tracked_mut() {
+            tracked.write();
         }
-        return 1;
     }
 
     #[inline]
-    const fn current(&self) -> &T {
-        &self.buffers[self.current_offset()]
+    fn published(&mut self, kind: SwapKind) {
+        if let Some(tracked) = self.instrument.tracked_mut() {
+            tracked.published(kind, &self.buffers[self.current.index()], self.current);
+        }
+    }
+
+    #[inline]
+    const fn current_offset(&self) -> usize {
+        self.current.index()
+    }
+
+    #[inline]
+    const fn next_offset(&self) -> usize {
+        self.current.other().index()
+    }
+
+    /// Gives access to the current value, like [`Deref`] but usable in `const` contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// static BUFFER: DoubleBuffer<u32> = DoubleBuffer::new(1, 2);
+    /// static CURRENT: &u32 = BUFFER.current();
+    /// assert_eq!(*CURRENT, 1);
+    /// ```
+    #[inline]
+    pub const fn current(&self) -> &T {
+        // SAFETY: the offset is `0` or `1`, so it's in bounds.
+        unsafe { &*self.buffers.as_ptr().add(self.current_offset()) }
+    }
+
+    /// Gives access to the next value, the one being written.
+    ///
+    /// Unlike with [`DerefMut`], it doesn't count as a mutable access for
+    /// [`DoubleBuffer::has_pending_changes()`].
+    #[inline]
+    pub const fn next(&self) -> &T {
+        // SAFETY: the offset is `0` or `1`, so it's in bounds.
+        unsafe { &*self.buffers.as_ptr().add(self.next_offset()) }
+    }
+
+    /// Gives access to the current value and mutable access to the next value at once,
+    /// so the next value can be computed from the current one.
+    ///
+    /// It counts as a mutable access for [`DoubleBuffer::has_pending_changes()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let mut buffer: DoubleBuffer<[u8; 3]> = DoubleBuffer::new([1, 2, 3], [0; 3]);
+    /// let (current, next) = buffer.split_mut();
+    /// for (next, current) in next.iter_mut().zip(current) {
+    ///     *next = current * 2;
+    /// }
+    /// buffer.swap();
+    /// assert_eq!(buffer, [2, 4, 6]);
+    /// ```
+    #[inline]
+    pub fn split_mut(&mut self) -> (&T, &mut T) {
+        self.mark_dirty();
+        let (current, next) = self.both_mut();
+        (current, next)
     }
 
     #[inline]
-    const fn next(&self) -> &T {
-        &self.buffers[self.next_offset()]
+    const fn next_mut(&mut self) -> &mut T {
+        let (_, next) = self.both_mut();
+        next
     }
 
+    /// Mutable access to the current value and access to the next value at once, to publish in place.
     #[inline]
-    fn current_mut(&mut self) -> &mut T {
-        &mut self.buffers[self.current_offset()]
+    const fn current_mut_and_next(&mut self) -> (&mut T, &T) {
+        let (current, next) = self.both_mut();
+        (current, next)
     }
 
+    /// Mutable access to the current value and the next value at once, without branching.
     #[inline]
-    fn next_mut(&mut self) -> &mut T {
-        &mut self.buffers[self.next_offset()]
+    const fn both_mut(&mut self) -> (&mut T, &mut T) {
+        let buffers = self.buffers.as_mut_ptr();
+        // SAFETY: the offsets are `0` and `1` in some order, so they are in bounds
+        // and the references don't alias.
+        unsafe {
+            (
+                &mut *buffers.add(self.current_offset()),
+                &mut *buffers.add(self.next_offset()),
+            )
+        }
     }
 }
 
-impl<T: Clone> DoubleBuffer<T> {
+impl<T: Clone, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
     /// Clone the next value to the current value,
     /// then writes will continue over the same next value.
     ///
     /// This let the pointer address of the current value unchanged.
     ///
+    /// It clones with [`Clone::clone_from()`], so types like `Vec<T>` and `String`
+    /// reuse the capacity of the current value instead of allocating a new value
+    /// and dropping the old one on every swap. Once the current value has grown to
+    /// the size of the next one, swapping doesn't allocate.
+    ///
     /// # Examples
     ///
     /// ```
@@ -151,27 +654,151 @@ impl<T: Clone> DoubleBuffer<T> {
     /// // The addresses are different.
     /// assert_eq!(first_address, second_address);
     /// ```
+    ///
+    /// The allocation of the current value is reused:
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let mut buffer: DoubleBuffer<Vec<u8>> = DoubleBuffer::new(Vec::with_capacity(64), vec![1, 2, 3]);
+    /// let allocation = buffer.as_ptr();
+    /// buffer.swap_with_clone();
+    /// assert_eq!(buffer.as_ptr(), allocation);
+    /// assert_eq!(*buffer, [1, 2, 3]);
+    /// ```
     #[inline]
     pub fn swap_with_clone(&mut self) {
-        let next = self.next().clone();
-        let current = self.current_mut();
-        *current = next;
+        self.check_invariant();
+        let (current, next) = self.current_mut_and_next();
+        // It reuses the resources of the current value, like the capacity of a `Vec`.
+        current.clone_from(next);
+        self.published(SwapKind::Clone);
     }
 }
 
-impl<T: Default> DoubleBuffer<T> {
-    /// Swaps buffers like [`DoubleBuffer::swap()`] and sets the next
+impl<T: Default, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Swaps buffers like [`MoveSwap`] and sets the next
     /// value to the default value of the type, then writes will be
     /// over the default value.
+    ///
+    /// For heap allocated types, the old value is dropped and the default one
+    /// allocates again when written. Prefer [`DoubleBuffer::swap_with_clear()`]
+    /// to reset it in place, or [`DoubleBuffer::swap_with_take()`] to drop it
+    /// somewhere else.
     #[inline]
     pub fn swap_with_default(&mut self) {
-        self.swap();
-        let next = self.next_mut();
-        *next = T::default();
+        self.swap_with_take();
+    }
+
+    /// Like [`DoubleBuffer::swap_with_default()`], but returns the previous current
+    /// value instead of dropping it, so it can be reused or dropped out of the hot path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let mut buffer: DoubleBuffer<Vec<u8>> = DoubleBuffer::new(vec![1], vec![2]);
+    /// let retired = buffer.swap_with_take();
+    /// assert_eq!(retired, [1]);
+    /// assert_eq!(*buffer, [2]);
+    ///
+    /// buffer.push(3);
+    /// buffer.swap_with_take();
+    /// assert_eq!(*buffer, [3]);
+    /// ```
+    #[inline]
+    pub fn swap_with_take(&mut self) -> T {
+        self.check_invariant();
+        self.flip();
+        let retired = core::mem::take(self.next_mut());
+        self.published(SwapKind::Default);
+        retired
+    }
+
+    /// Swaps buffers like [`MoveSwap`] and sets the next value to the previous
+    /// current value transformed by `f`, taking it by value, to recycle it
+    /// without a clone, like clearing, shrinking or re-seeding it.
+    ///
+    /// The default value is only a placeholder while `f` runs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let mut buffer: DoubleBuffer<Vec<u8>> = DoubleBuffer::new(vec![0; 1024], vec![1]);
+    ///
+    /// buffer.swap_map(|mut retired| {
+    ///     retired.clear();
+    ///     retired.shrink_to(64);
+    ///     retired
+    /// });
+    /// assert_eq!(*buffer, [1]);
+    /// assert!(buffer.next().is_empty());
+    /// assert!(buffer.next().capacity() < 1024);
+    /// ```
+    #[inline]
+    pub fn swap_map(&mut self, f: impl FnOnce(T) -> T) {
+        self.check_invariant();
+        self.flip();
+        let retired = core::mem::take(self.next_mut());
+        *self.next_mut() = f(retired);
+        self.published(SwapKind::With);
     }
 }
 
-impl<T: Debug> Debug for DoubleBuffer<T> {
+impl<T, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Replaces the next value with the elements of `iter`, collected like
+    /// [`Iterator::collect()`], so a pipeline can end in the next value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let mut visible: DoubleBuffer<Vec<u32>> = DoubleBuffer::default();
+    /// let entities = [3, 8, 12, 21];
+    ///
+    /// visible.collect_next(entities.iter().filter(|&&x| x > 5).copied());
+    /// visible.swap();
+    /// assert_eq!(*visible, [8, 12, 21]);
+    /// ```
+    #[inline]
+    pub fn collect_next<Iter: IntoIterator>(&mut self, iter: Iter)
+    where
+        T: FromIterator<Iter::Item>,
+    {
+        *self.as_mut() = iter.into_iter().collect();
+    }
+}
+
+impl<T: Clear, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Swaps buffers like [`MoveSwap`] and clears the next value
+    /// in place, then writes will be over the cleared value.
+    ///
+    /// Unlike [`DoubleBuffer::swap_with_default()`], this keeps the
+    /// allocated capacity of the next value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let mut buffer: DoubleBuffer<[u8; 4]> = DoubleBuffer::new([1; 4], [2; 4]);
+    /// buffer.swap_with_clear();
+    /// assert_eq!(buffer, [2; 4]);
+    ///
+    /// // The next value was cleared.
+    /// buffer[0] = 3;
+    /// buffer.swap_with_clear();
+    /// assert_eq!(buffer, [3, 0, 0, 0]);
+    /// ```
+    #[inline]
+    pub fn swap_with_clear(&mut self) {
+        self.check_invariant();
+        self.flip();
+        self.next_mut().clear();
+        self.published(SwapKind::Clear);
+    }
+}
+
+impl<T: Debug, S, I: Instrument<T>> Debug for DoubleBuffer<T, S, I> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("DoubleBuffer")
@@ -181,95 +808,154 @@ impl<T: Debug> Debug for DoubleBuffer<T> {
     }
 }
 
-impl<T> Pointer for DoubleBuffer<T> {
+impl<T, S, I: Instrument<T>> Pointer for DoubleBuffer<T, S, I> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:p}", self.current())
     }
 }
 
-impl<T: Default> Default for DoubleBuffer<T> {
+impl<T: Default, S, I: Instrument<T>> Default for DoubleBuffer<T, S, I> {
     #[inline]
     fn default() -> Self {
-        Self::new(T::default(), T::default())
+        Self::with_strategy(T::default(), T::default())
+    }
+}
+
+/// Collects the elements as the next value, with the default value as the
+/// current value, see [`DoubleBuffer::collect_next()`].
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::DoubleBuffer;
+/// let mut squares: DoubleBuffer<Vec<u32>> = (1..=3).map(|x| x * x).collect();
+/// assert!(squares.is_empty());
+///
+/// squares.swap();
+/// assert_eq!(*squares, [1, 4, 9]);
+/// ```
+impl<E, T: FromIterator<E> + Default, S, I: Instrument<T>> FromIterator<E>
+    for DoubleBuffer<T, S, I>
+{
+    #[inline]
+    fn from_iter<Iter: IntoIterator<Item = E>>(iter: Iter) -> Self {
+        let mut buffer = Self::with_strategy(T::default(), iter.into_iter().collect());
+        buffer.mark_dirty();
+        buffer
     }
 }
 
-impl<T> Deref for DoubleBuffer<T> {
+impl<T, S, I: Instrument<T>> Deref for DoubleBuffer<T, S, I> {
     type Target = T;
 
     #[inline]
     fn deref(&self) -> &Self::Target {
+        self.mark_read();
         self.current()
     }
 }
 
-impl<T> DerefMut for DoubleBuffer<T> {
+impl<T, S, I: Instrument<T>> DerefMut for DoubleBuffer<T, S, I> {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
+        self.mark_dirty();
         self.next_mut()
     }
 }
 
-impl<T> Borrow<T> for DoubleBuffer<T> {
+impl<T, S, I: Instrument<T>> Borrow<T> for DoubleBuffer<T, S, I> {
     #[inline]
     fn borrow(&self) -> &T {
+        self.mark_read();
         self.current()
     }
 }
 
-impl<T> BorrowMut<T> for DoubleBuffer<T> {
+impl<T, S, I: Instrument<T>> BorrowMut<T> for DoubleBuffer<T, S, I> {
     #[inline]
     fn borrow_mut(&mut self) -> &mut T {
+        self.mark_dirty();
         self.next_mut()
     }
 }
 
-impl<T> AsRef<T> for DoubleBuffer<T> {
+impl<T, S, I: Instrument<T>> AsRef<T> for DoubleBuffer<T, S, I> {
     #[inline]
     fn as_ref(&self) -> &T {
+        self.mark_read();
         self.current()
     }
 }
 
-impl<T> AsMut<T> for DoubleBuffer<T> {
+impl<T, S, I: Instrument<T>> AsMut<T> for DoubleBuffer<T, S, I> {
     #[inline]
     fn as_mut(&mut self) -> &mut T {
+        self.mark_dirty();
         self.next_mut()
     }
 }
 
-impl<T: PartialEq> PartialEq<T> for DoubleBuffer<T> {
+/// Iterates the current value, like iterating it through [`Deref`].
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::DoubleBuffer;
+/// let mut particles: DoubleBuffer<Vec<u32>> = DoubleBuffer::new(vec![1, 2], Vec::new());
+/// particles.push(3);
+///
+/// let mut total = 0;
+/// for particle in &particles {
+///     total += particle;
+/// }
+/// assert_eq!(total, 3);
+/// ```
+impl<'a, T, S, I: Instrument<T>> IntoIterator for &'a DoubleBuffer<T, S, I>
+where
+    &'a T: IntoIterator,
+{
+    type Item = <&'a T as IntoIterator>::Item;
+    type IntoIter = <&'a T as IntoIterator>::IntoIter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        let current: &T = self;
+        current.into_iter()
+    }
+}
+
+impl<T: PartialEq, S, I: Instrument<T>> PartialEq<T> for DoubleBuffer<T, S, I> {
     #[inline]
     fn eq(&self, other: &T) -> bool {
         self.current().eq(other)
     }
 }
 
-impl<T: PartialEq> PartialEq for DoubleBuffer<T> {
+impl<T: PartialEq, S, I: Instrument<T>> PartialEq for DoubleBuffer<T, S, I> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
         self.current().eq(other.current())
     }
 }
 
-impl<T: Eq> Eq for DoubleBuffer<T> {}
+impl<T: Eq, S, I: Instrument<T>> Eq for DoubleBuffer<T, S, I> {}
 
-impl<T: PartialOrd> PartialOrd<T> for DoubleBuffer<T> {
+impl<T: PartialOrd, S, I: Instrument<T>> PartialOrd<T> for DoubleBuffer<T, S, I> {
     #[inline]
     fn partial_cmp(&self, other: &T) -> Option<core::cmp::Ordering> {
         self.current().partial_cmp(other)
     }
 }
 
-impl<T: PartialOrd> PartialOrd for DoubleBuffer<T> {
+impl<T: PartialOrd, S, I: Instrument<T>> PartialOrd for DoubleBuffer<T, S, I> {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         self.current().partial_cmp(other.current())
     }
 }
 
-impl<T: Ord> Ord for DoubleBuffer<T> {
+impl<T: Ord, S, I: Instrument<T>> Ord for DoubleBuffer<T, S, I> {
     #[inline]
     fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.current().cmp(other.current())
@@ -349,6 +1035,105 @@ mod tests {
         assert_eq!(*buffer.next(), 0);
     }
 
+    #[test]
+    fn test_swap_with_clone_strategy() {
+        let mut buffer: DoubleBuffer<u32, CloneSwap> = DoubleBuffer::with_strategy(1, 2);
+        buffer.swap();
+        assert_eq!(*buffer.current(), 2);
+        assert_eq!(*buffer.next(), 2);
+    }
+
+    #[test]
+    fn test_swap_with_default_strategy() {
+        let mut buffer: DoubleBuffer<u32, DefaultSwap> = DoubleBuffer::with_strategy(1, 2);
+        buffer.swap();
+        assert_eq!(*buffer.current(), 2);
+        assert_eq!(*buffer.next(), 0);
+    }
+
+    #[test]
+    fn test_swap_with_clear() {
+        let mut buffer: DoubleBuffer<[u32; 2]> = DoubleBuffer::new([1, 1], [2, 2]);
+        buffer.swap_with_clear();
+        assert_eq!(*buffer.current(), [2, 2]);
+        assert_eq!(*buffer.next(), [0, 0]);
+    }
+
+    #[test]
+    fn test_swap_with_clear_strategy() {
+        let mut buffer: DoubleBuffer<[u32; 2], ClearSwap> =
+            DoubleBuffer::with_strategy([1, 1], [2, 2]);
+        buffer.swap();
+        assert_eq!(*buffer.current(), [2, 2]);
+        assert_eq!(*buffer.next(), [0, 0]);
+    }
+
+    #[test]
+    fn test_pending_changes() {
+        let mut buffer: TrackedBuffer<u32> = DoubleBuffer::tracked(1, 2);
+        assert!(!buffer.has_pending_changes());
+
+        *buffer.as_mut() = 3;
+        assert!(buffer.has_pending_changes());
+
+        buffer.swap_with_clone();
+        assert!(!buffer.has_pending_changes());
+
+        *buffer = 4;
+        assert!(buffer.has_pending_changes());
+
+        buffer.swap_with_default();
+        assert!(!buffer.has_pending_changes());
+        assert!(!buffer.swap_if_dirty());
+    }
+
+    #[test]
+    fn test_try_swap_with() {
+        let mut buffer: TrackedBuffer<u32> = DoubleBuffer::tracked(1, 2);
+        assert_eq!(buffer.try_swap_with(|| Err(())), Err(()));
+        assert_eq!(*buffer.current(), 1);
+        assert_eq!(*buffer.next(), 2);
+        assert_eq!(buffer.generation(), 0);
+
+        assert_eq!(buffer.try_swap_with(|| Ok::<_, ()>(3)), Ok(()));
+        assert_eq!(*buffer.current(), 2);
+        assert_eq!(*buffer.next(), 3);
+        assert_eq!(buffer.generation(), 1);
+    }
+
+    #[test]
+    fn test_swap_map() {
+        let mut buffer: TrackedBuffer<u32> = DoubleBuffer::tracked(1, 2);
+        buffer.swap_map(|retired| retired + 10);
+        assert_eq!(*buffer.current(), 2);
+        assert_eq!(*buffer.next(), 11);
+        assert_eq!(buffer.generation(), 1);
+    }
+
+    #[test]
+    fn test_replace_current() {
+        let mut buffer: TrackedBuffer<u32> = DoubleBuffer::tracked(1, 2);
+        assert_eq!(buffer.replace_current(3), 2);
+        assert_eq!(buffer.replace_current(4), 1);
+        assert_eq!((*buffer.current(), *buffer.next()), (4, 3));
+        assert!(!buffer.has_pending_changes());
+        assert_eq!(buffer.stats().swaps_with, 2);
+    }
+
+    #[test]
+    fn test_split_mut() {
+        let mut buffer: TrackedBuffer<u32> = DoubleBuffer::tracked(1, 2);
+        buffer.swap();
+
+        let (current, next) = buffer.split_mut();
+        assert_eq!(*current, 2);
+        *next = *current + 1;
+        assert!(buffer.has_pending_changes());
+
+        buffer.swap();
+        assert_eq!(buffer, 3);
+    }
+
     #[test]
     fn test_greater_and_less_than() {
         let mut buffer: DoubleBuffer<i32> = DoubleBuffer::default();
@@ -0,0 +1,128 @@
+use crate::{DoubleBuffer, Tracked};
+
+/// State of the frame opened with [`DoubleBuffer::begin_frame()`], when debug
+/// assertions are enabled.
+#[cfg(debug_assertions)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FrameLifecycle {
+    /// Frames were never opened, so nothing is checked.
+    Unused,
+    Closed,
+    Open {
+        published: bool,
+    },
+}
+
+#[cfg(debug_assertions)]
+impl FrameLifecycle {
+    #[inline]
+    pub(crate) fn write(self) {
+        assert!(
+            self != Self::Closed,
+            "DoubleBuffer next value written outside a frame, is begin_frame() missing?"
+        );
+    }
+
+    #[inline]
+    pub(crate) fn published(&mut self) {
+        if let Self::Open { published } = self {
+            assert!(
+                !*published,
+                "DoubleBuffer published twice in the same frame"
+            );
+            *published = true;
+        }
+    }
+}
+
+impl<T, S> DoubleBuffer<T, S, Tracked<T>> {
+    /// Opens a frame, checking the frame lifecycle when debug assertions are
+    /// enabled, from the first call.
+    ///
+    /// The next value must only be written inside a frame, closed with
+    /// [`DoubleBuffer::end_frame()`], and published at most once per frame, so
+    /// violations of the protocol of a codebase panic in development builds.
+    /// Writes are counted like for [`DoubleBuffer::has_pending_changes()`].
+    ///
+    /// # Panics
+    ///
+    /// If a frame is already open, when debug assertions are enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use double_buffer::TrackedBuffer;
+    /// let mut buffer: TrackedBuffer<u32> = TrackedBuffer::default();
+    ///
+    /// buffer.begin_frame();
+    /// *buffer = 1;
+    /// buffer.swap();
+    /// buffer.end_frame();
+    ///
+    /// // Written after the frame was closed.
+    /// *buffer = 2;
+    /// ```
+    #[inline]
+    pub fn begin_frame(&mut self) {
+        #[cfg(debug_assertions)]
+        {
+            assert!(
+                !matches!(self.instrument.lifecycle, FrameLifecycle::Open { .. }),
+                "DoubleBuffer frame begun inside another frame"
+            );
+            self.instrument.lifecycle = FrameLifecycle::Open { published: false };
+        }
+    }
+
+    /// Closes the frame opened with [`DoubleBuffer::begin_frame()`].
+    ///
+    /// # Panics
+    ///
+    /// If no frame is open, when debug assertions are enabled.
+    #[inline]
+    pub fn end_frame(&mut self) {
+        #[cfg(debug_assertions)]
+        {
+            assert!(
+                matches!(self.instrument.lifecycle, FrameLifecycle::Open { .. }),
+                "DoubleBuffer frame ended without begin_frame()"
+            );
+            self.instrument.lifecycle = FrameLifecycle::Closed;
+        }
+    }
+}
+
+#[cfg(all(test, debug_assertions))]
+mod tests {
+    use crate::TrackedBuffer;
+
+    #[test]
+    fn test_one_publish_per_frame() {
+        let mut buffer: TrackedBuffer<u32> = TrackedBuffer::default();
+        for frame in 1..4 {
+            buffer.begin_frame();
+            *buffer = frame;
+            buffer.swap();
+            buffer.end_frame();
+        }
+        assert_eq!(*buffer, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "published twice")]
+    fn test_two_publishes_panic() {
+        let mut buffer: TrackedBuffer<u32> = TrackedBuffer::default();
+        buffer.begin_frame();
+        buffer.swap();
+        buffer.swap();
+    }
+
+    #[test]
+    #[should_panic(expected = "outside a frame")]
+    fn test_write_after_end_frame_panics() {
+        let mut buffer: TrackedBuffer<u32> = TrackedBuffer::default();
+        buffer.begin_frame();
+        buffer.end_frame();
+        *buffer = 1;
+    }
+}
@@ -0,0 +1,143 @@
+use alloc::string::{self, String};
+use core::fmt::{self, Debug, Formatter, Write};
+
+use crate::DoubleBuffer;
+
+/// Log lines written to a staged side and flushed from a published side, so
+/// producers only append to a string and the slow output happens once per flush.
+///
+/// Lines are written with [`core::fmt::Write`], like with [`write!`], or with
+/// [`LogBuffer::write_line()`]. [`LogBuffer::swap()`] publishes them, and the
+/// flusher reads them with [`LogBuffer::lines()`] or takes them with
+/// [`LogBuffer::drain()`]. The strings keep their capacity across swaps. To
+/// flush from another thread, share it in a `Mutex` and only hold the lock to
+/// write or swap.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::LogBuffer;
+/// use core::fmt::Write;
+///
+/// let mut log = LogBuffer::with_capacity(1024);
+/// writeln!(log, "request {} took {}ms", 1, 12).unwrap();
+/// log.write_line(format_args!("request {} took {}ms", 2, 8));
+///
+/// log.swap();
+/// assert!(log.lines().eq(["request 1 took 12ms", "request 2 took 8ms"]));
+///
+/// let mut output = String::new();
+/// output.extend(log.drain());
+/// assert_eq!(log.published(), "");
+/// ```
+pub struct LogBuffer {
+    buffer: DoubleBuffer<String>,
+}
+
+impl LogBuffer {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            buffer: DoubleBuffer::new(String::new(), String::new()),
+        }
+    }
+
+    /// Creates it with the given capacity in bytes in both sides.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: DoubleBuffer::new(
+                String::with_capacity(capacity),
+                String::with_capacity(capacity),
+            ),
+        }
+    }
+
+    /// Writes the formatted arguments to the staged side followed by a line break.
+    #[inline]
+    pub fn write_line(&mut self, args: fmt::Arguments<'_>) {
+        let staged = self.buffer.as_mut();
+        // Formatting into a string only fails if a `Display` implementation fails.
+        let _ = staged.write_fmt(args);
+        staged.push('\n');
+    }
+
+    /// Gives access to the staged text.
+    #[inline]
+    pub fn staged(&self) -> &str {
+        self.buffer.next()
+    }
+
+    /// Gives access to the published text.
+    #[inline]
+    pub fn published(&self) -> &str {
+        self.buffer.current()
+    }
+
+    /// Iterates the published lines.
+    #[inline]
+    pub fn lines(&self) -> core::str::Lines<'_> {
+        self.published().lines()
+    }
+
+    /// Publishes the staged text, then lines will be written to an empty staged side.
+    ///
+    /// The published text that wasn't drained is discarded.
+    #[inline]
+    pub fn swap(&mut self) {
+        self.buffer.swap_with_clear();
+    }
+
+    /// Removes the published text and iterates its characters, keeping the capacity.
+    #[inline]
+    pub fn drain(&mut self) -> string::Drain<'_> {
+        self.buffer.current_mut_and_next().0.drain(..)
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> DoubleBuffer<String> {
+        self.buffer
+    }
+}
+
+impl Write for LogBuffer {
+    /// Appends the text to the staged side.
+    #[inline]
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        self.buffer.as_mut().push_str(text);
+        Ok(())
+    }
+}
+
+impl Debug for LogBuffer {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LogBuffer")
+            .field("published", &self.published())
+            .field("staged", &self.staged())
+            .finish()
+    }
+}
+
+impl Default for LogBuffer {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap_discards_undrained() {
+        let mut log = LogBuffer::new();
+        log.write_line(format_args!("first"));
+        log.swap();
+        log.write_str("second\n").unwrap();
+        log.swap();
+        assert!(log.lines().eq(["second"]));
+        assert_eq!(log.staged(), "");
+    }
+}
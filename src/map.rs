@@ -0,0 +1,211 @@
+use alloc::vec::Vec;
+use core::fmt::{Debug, Formatter};
+use core::hash::{BuildHasher, Hash};
+use core::ops::Deref;
+
+use hashbrown::{DefaultHashBuilder, Equivalent, HashMap};
+
+use crate::DoubleBuffer;
+
+/// Map whose inserts and removes are staged and become visible at once when
+/// published, like a configuration table or an entity registry.
+///
+/// It dereferences to the published map. [`DoubleBufferedMap::publish()`] publishes
+/// the staged map and carries it forward, so the next changes are over it, only
+/// applying the changed entries to the other map. [`DoubleBufferedMap::publish_clearing()`]
+/// starts the next changes over an empty map.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::DoubleBufferedMap;
+/// let mut config: DoubleBufferedMap<&str, u32> = DoubleBufferedMap::new();
+///
+/// config.insert("width", 640);
+/// config.insert("height", 480);
+/// assert!(config.is_empty());
+///
+/// config.publish();
+/// assert_eq!(config.get("width"), Some(&640));
+///
+/// config.remove("height");
+/// config.publish();
+/// assert_eq!(config.get("width"), Some(&640));
+/// assert_eq!(config.get("height"), None);
+/// ```
+pub struct DoubleBufferedMap<K, V, H = DefaultHashBuilder> {
+    buffer: DoubleBuffer<HashMap<K, V, H>>,
+    changed: Vec<K>,
+    resync: bool,
+}
+
+impl<K, V> DoubleBufferedMap<K, V> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_hasher(DefaultHashBuilder::default())
+    }
+}
+
+impl<K, V, H: Clone> DoubleBufferedMap<K, V, H> {
+    #[inline]
+    pub fn with_hasher(hasher: H) -> Self {
+        let buffer = DoubleBuffer::new(
+            HashMap::with_hasher(hasher.clone()),
+            HashMap::with_hasher(hasher),
+        );
+        Self {
+            buffer,
+            changed: Vec::new(),
+            resync: false,
+        }
+    }
+}
+
+impl<K, V, H> DoubleBufferedMap<K, V, H> {
+    /// Gives access to the staged map.
+    #[inline]
+    pub fn staged(&self) -> &HashMap<K, V, H> {
+        self.buffer.next()
+    }
+
+    /// Removes all the staged entries.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.buffer.as_mut().clear();
+        self.changed.clear();
+        self.resync = true;
+    }
+
+    /// Publishes the staged map, then changes will be over an empty map.
+    #[inline]
+    pub fn publish_clearing(&mut self) {
+        self.buffer.swap();
+        self.clear();
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> DoubleBuffer<HashMap<K, V, H>> {
+        self.buffer
+    }
+}
+
+impl<K: Eq + Hash + Clone, V, H: BuildHasher> DoubleBufferedMap<K, V, H> {
+    /// Stages an entry, returning the value staged before for the key.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.changed.push(key.clone());
+        self.buffer.as_mut().insert(key, value)
+    }
+
+    /// Stages the removal of an entry, returning its staged value.
+    #[inline]
+    pub fn remove<Q: Hash + Equivalent<K> + ?Sized>(&mut self, key: &Q) -> Option<V> {
+        let (key, value) = self.buffer.as_mut().remove_entry(key)?;
+        self.changed.push(key);
+        Some(value)
+    }
+
+    /// Gives mutable access to a staged value.
+    #[inline]
+    pub fn get_staged_mut<Q: Hash + Equivalent<K> + ?Sized>(&mut self, key: &Q) -> Option<&mut V> {
+        let (key, value) = self.buffer.as_mut().get_key_value_mut(key)?;
+        self.changed.push(key.clone());
+        Some(value)
+    }
+
+    /// Publishes the staged map, then changes will be over it.
+    ///
+    /// Only the changed entries are cloned to the other map, unless the staged map
+    /// was cleared, then it's cloned whole.
+    #[inline]
+    pub fn publish(&mut self)
+    where
+        V: Clone,
+        H: Clone,
+    {
+        if self.resync {
+            self.buffer.swap_with_clone();
+            self.changed.clear();
+            self.resync = false;
+            return;
+        }
+        self.buffer.swap();
+        let (current, next) = self.buffer.split_mut();
+        for key in self.changed.drain(..) {
+            match current.get(&key) {
+                Some(value) => next.insert(key, value.clone()),
+                None => next.remove(&key),
+            };
+        }
+    }
+}
+
+impl<K: Debug, V: Debug, H> Debug for DoubleBufferedMap<K, V, H> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DoubleBufferedMap")
+            .field("published", self.buffer.current())
+            .field("staged", self.buffer.next())
+            .finish()
+    }
+}
+
+impl<K, V> Default for DoubleBufferedMap<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, H> Deref for DoubleBufferedMap<K, V, H> {
+    type Target = HashMap<K, V, H>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+impl<K: Eq + Hash + Clone, V, H: BuildHasher> Extend<(K, V)> for DoubleBufferedMap<K, V, H> {
+    /// Stages the entries.
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_publish_carries_forward() {
+        let mut map: DoubleBufferedMap<u8, u8> = DoubleBufferedMap::new();
+        map.extend([(1, 1), (2, 2)]);
+        map.publish();
+        assert_eq!(map.staged(), &*map);
+
+        *map.get_staged_mut(&1).unwrap() = 10;
+        map.remove(&2);
+        map.publish();
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get(&1), Some(&10));
+        assert_eq!(map.staged(), &*map);
+    }
+
+    #[test]
+    fn test_publish_clearing() {
+        let mut map: DoubleBufferedMap<u8, u8> = DoubleBufferedMap::new();
+        map.insert(1, 1);
+        map.publish_clearing();
+        assert!(map.staged().is_empty());
+
+        map.insert(2, 2);
+        map.publish();
+        assert_eq!(map.get(&1), None);
+        assert_eq!(map.get(&2), Some(&2));
+        assert_eq!(map.staged(), &*map);
+    }
+}
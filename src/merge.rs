@@ -0,0 +1,135 @@
+use crate::{DoubleBuffer, Instrument, SwapKind};
+
+/// Folds a staged value into a published one, for buffers accumulating values
+/// across frames instead of replacing them.
+///
+/// It's used by [`DoubleBuffer::swap_with_merge()`]. The staged value is left as
+/// the following frame starts from, usually empty or zero, so it isn't folded twice.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::Merge;
+/// #[derive(Default)]
+/// struct FrameStats {
+///     draw_calls: u32,
+///     slowest_us: u32,
+/// }
+///
+/// impl Merge for FrameStats {
+///     fn merge(&mut self, staged: &mut Self) {
+///         self.draw_calls.merge(&mut staged.draw_calls);
+///         self.slowest_us = self.slowest_us.max(core::mem::take(&mut staged.slowest_us));
+///     }
+/// }
+/// ```
+pub trait Merge {
+    /// Folds `staged` into this value, leaving `staged` ready to be written again.
+    fn merge(&mut self, staged: &mut Self);
+}
+
+macro_rules! impl_merge_for_numbers {
+    ($($number:ty),*) => {
+        $(
+            /// Adds the staged number, setting it to zero.
+            impl Merge for $number {
+                #[inline]
+                fn merge(&mut self, staged: &mut Self) {
+                    *self += core::mem::take(staged);
+                }
+            }
+        )*
+    };
+}
+
+impl_merge_for_numbers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// ORs the staged flag, setting it to `false`, like a dirty flag.
+impl Merge for bool {
+    #[inline]
+    fn merge(&mut self, staged: &mut Self) {
+        *self |= core::mem::take(staged);
+    }
+}
+
+/// Merges each element with the staged element in the same position.
+impl<T: Merge, const N: usize> Merge for [T; N] {
+    #[inline]
+    fn merge(&mut self, staged: &mut Self) {
+        for (item, staged) in self.iter_mut().zip(staged) {
+            item.merge(staged);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod alloc_impls {
+    use super::Merge;
+    use alloc::collections::VecDeque;
+    use alloc::vec::Vec;
+
+    /// Appends the staged items, leaving the staged vector empty with its capacity.
+    impl<T> Merge for Vec<T> {
+        #[inline]
+        fn merge(&mut self, staged: &mut Self) {
+            self.append(staged);
+        }
+    }
+
+    /// Appends the staged items, leaving the staged deque empty with its capacity.
+    impl<T> Merge for VecDeque<T> {
+        #[inline]
+        fn merge(&mut self, staged: &mut Self) {
+            self.append(staged);
+        }
+    }
+}
+
+impl<T: Merge, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Publishes by folding the next value into the current value with [`Merge`],
+    /// instead of replacing it, then writes will be over what the merge left in
+    /// the next value.
+    ///
+    /// The current value stays in place, like [`DoubleBuffer::swap_with_clone()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// // Dirty masks of the tiles of each frame, published as the tiles to redraw.
+    /// let mut dirty: DoubleBuffer<[bool; 4]> = DoubleBuffer::default();
+    ///
+    /// dirty[1] = true;
+    /// dirty.swap_with_merge();
+    /// dirty[3] = true;
+    /// dirty.swap_with_merge();
+    ///
+    /// assert_eq!(dirty, [false, true, false, true]);
+    /// assert_eq!(*dirty.next(), [false; 4]);
+    /// ```
+    #[inline]
+    pub fn swap_with_merge(&mut self) {
+        self.check_invariant();
+        let (current, next) = self.both_mut();
+        current.merge(next);
+        self.published(SwapKind::Merge);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TrackedBuffer;
+
+    #[test]
+    fn test_swap_with_merge_sums() {
+        let mut frames: TrackedBuffer<u64> = DoubleBuffer::tracked(10, 0);
+        *frames = 5;
+        frames.swap_with_merge();
+        *frames = 2;
+        frames.swap_with_merge();
+
+        assert_eq!((*frames, *frames.next()), (17, 0));
+        assert_eq!(frames.stats().swaps_with_merge, 2);
+    }
+}
@@ -0,0 +1,151 @@
+use core::fmt::{Debug, Formatter};
+
+use crate::{SlotId, Swap};
+
+/// `C` channels double buffered in a structure of arrays, swapped together by a
+/// single flag so they can't drift out of phase, like the channels of audio or
+/// the axes of a sensor.
+///
+/// Unlike [`DoubleBufferArray`](crate::DoubleBufferArray), the published values
+/// of every channel are contiguous, and so are the staged ones, so all channels
+/// are accessible at once as an array with [`MultiChannelBuffer::published()`]
+/// and [`MultiChannelBuffer::staged_mut()`].
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::MultiChannelBuffer;
+/// // Stereo blocks of 4 samples.
+/// let mut audio: MultiChannelBuffer<[f32; 4], 2> = MultiChannelBuffer::default();
+///
+/// audio.staged_channel_mut(0).unwrap().fill(0.5);
+/// audio.staged_channel_mut(1).unwrap().fill(-0.5);
+/// audio.swap();
+///
+/// assert_eq!(audio.channel(0), Some(&[0.5; 4]));
+/// assert_eq!(audio.published(), &[[0.5; 4], [-0.5; 4]]);
+/// ```
+pub struct MultiChannelBuffer<T, const C: usize> {
+    current: SlotId,
+    channels: [[T; C]; 2],
+}
+
+impl<T, const C: usize> MultiChannelBuffer<T, C> {
+    /// Creates it from the current values and the next values of the channels.
+    #[inline]
+    pub const fn new(current: [T; C], next: [T; C]) -> Self {
+        Self {
+            current: SlotId::FIRST,
+            channels: [current, next],
+        }
+    }
+
+    /// Creates the current and next values of the channels calling `f` with their indexes.
+    #[inline]
+    pub fn from_fn(mut f: impl FnMut(usize) -> T) -> Self {
+        Self::new(core::array::from_fn(&mut f), core::array::from_fn(f))
+    }
+
+    /// Number of channels.
+    #[inline]
+    pub const fn channels(&self) -> usize {
+        C
+    }
+
+    /// Publishes the staged values of every channel.
+    #[inline]
+    pub const fn swap(&mut self) {
+        self.current = self.current.other();
+    }
+
+    /// Gives access to the published values of every channel.
+    #[inline]
+    pub const fn published(&self) -> &[T; C] {
+        &self.channels[self.current.index()]
+    }
+
+    /// Gives access to the staged values of every channel.
+    #[inline]
+    pub const fn staged(&self) -> &[T; C] {
+        &self.channels[self.current.other().index()]
+    }
+
+    /// Gives mutable access to the staged values of every channel.
+    #[inline]
+    pub const fn staged_mut(&mut self) -> &mut [T; C] {
+        &mut self.channels[self.current.other().index()]
+    }
+
+    /// Gives access to the published value of a channel.
+    #[inline]
+    pub fn channel(&self, index: usize) -> Option<&T> {
+        self.published().get(index)
+    }
+
+    /// Gives access to the staged value of a channel.
+    #[inline]
+    pub fn staged_channel(&self, index: usize) -> Option<&T> {
+        self.staged().get(index)
+    }
+
+    /// Gives mutable access to the staged value of a channel.
+    #[inline]
+    pub fn staged_channel_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.staged_mut().get_mut(index)
+    }
+
+    /// Gives access to the published values and mutable access to the staged
+    /// values of every channel at once.
+    #[inline]
+    pub const fn split_mut(&mut self) -> (&[T; C], &mut [T; C]) {
+        let [first, second] = &mut self.channels;
+        if self.current.index() == SlotId::FIRST.index() {
+            (first, second)
+        } else {
+            (second, first)
+        }
+    }
+}
+
+impl<T, const C: usize> Swap for MultiChannelBuffer<T, C> {
+    #[inline]
+    fn swap_buffers(&mut self) {
+        self.swap();
+    }
+}
+
+impl<T: Debug, const C: usize> Debug for MultiChannelBuffer<T, C> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MultiChannelBuffer")
+            .field("published", self.published())
+            .field("staged", self.staged())
+            .finish()
+    }
+}
+
+impl<T: Default, const C: usize> Default for MultiChannelBuffer<T, C> {
+    #[inline]
+    fn default() -> Self {
+        Self::from_fn(|_| T::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_channels_swap_together() {
+        let mut axes = MultiChannelBuffer::new([0, 0, 0], [1, 2, 3]);
+        axes.swap();
+        let (published, staged) = axes.split_mut();
+        assert_eq!(*published, [1, 2, 3]);
+        staged[2] = 9;
+
+        axes.swap();
+        assert_eq!(axes.published(), &[0, 0, 9]);
+        assert_eq!(axes.staged_channel(0), Some(&1));
+        assert_eq!(axes.channel(3), None);
+    }
+}
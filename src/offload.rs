@@ -0,0 +1,98 @@
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+
+use core::ops::Deref;
+
+use crate::{DoubleBuffer, Instrument, SwapKind};
+
+impl<T: Clone + Default + Send + Sync + 'static, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Publishes like [`DoubleBuffer::swap_with_clone()`], but the clone is done in
+    /// another thread, so the calling thread doesn't pay for copying a large value.
+    ///
+    /// The published value is readable through the returned [`PendingClone`],
+    /// and the buffer is available again once it's joined. The allocation of the
+    /// current value is reused for the clone.
+    ///
+    /// If the [`PendingClone`] is leaked, the buffer is left with default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let mut frame: DoubleBuffer<Vec<u8>> = DoubleBuffer::new(vec![0; 1024], vec![0; 1024]);
+    /// frame[0] = 1;
+    ///
+    /// let pending = frame.swap_with_clone_async();
+    /// assert_eq!(pending[0], 1);
+    /// pending.join();
+    ///
+    /// // Both values are the published one.
+    /// assert_eq!(frame[0], 1);
+    /// frame[1] = 2;
+    /// frame.swap();
+    /// assert_eq!(frame[..2], [1, 2]);
+    /// ```
+    pub fn swap_with_clone_async(&mut self) -> PendingClone<'_, T, S, I> {
+        self.check_invariant();
+        let published = Arc::new(core::mem::take(self.next_mut()));
+        let (current, _) = self.current_mut_and_next();
+        let mut clone = core::mem::take(current);
+        let source = Arc::clone(&published);
+        let worker = thread::spawn(move || {
+            clone.clone_from(&source);
+            clone
+        });
+        PendingClone {
+            buffer: self,
+            published,
+            worker,
+        }
+    }
+}
+
+/// Clone in progress started by [`DoubleBuffer::swap_with_clone_async()`].
+///
+/// It dereferences to the published value.
+#[must_use = "the buffer isn't published until the clone is joined"]
+pub struct PendingClone<'a, T, S, I = ()> {
+    buffer: &'a mut DoubleBuffer<T, S, I>,
+    published: Arc<T>,
+    worker: JoinHandle<T>,
+}
+
+impl<T, S, I: Instrument<T>> PendingClone<'_, T, S, I> {
+    /// Returns `true` if the clone completed, so joining won't block.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.worker.is_finished()
+    }
+
+    /// Waits for the clone and publishes the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if cloning the value panicked.
+    pub fn join(self) {
+        let clone = self
+            .worker
+            .join()
+            .unwrap_or_else(|payload| std::panic::resume_unwind(payload));
+        // The worker dropped its reference when it finished.
+        let Ok(published) = Arc::try_unwrap(self.published) else {
+            unreachable!("the worker thread keeps the published value")
+        };
+        let (current, _) = self.buffer.current_mut_and_next();
+        *current = published;
+        *self.buffer.next_mut() = clone;
+        self.buffer.published(SwapKind::Clone);
+    }
+}
+
+impl<T, S, I> Deref for PendingClone<'_, T, S, I> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.published
+    }
+}
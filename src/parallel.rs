@@ -0,0 +1,61 @@
+use rayon::iter::{IndexedParallelIterator, IntoParallelRefMutIterator, ParallelIterator};
+
+use crate::{DoubleBuffer, Instrument};
+
+impl<T, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Computes every element of the next value in parallel, calling `f` with its
+    /// index and the elements of the current value.
+    ///
+    /// It's the data-parallel update of cellular automata, stencils and particle
+    /// systems. Swap after it to publish the next value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let mut heat: DoubleBuffer<Vec<f32>> = DoubleBuffer::new(vec![0.0, 9.0, 0.0], vec![0.0; 3]);
+    ///
+    /// heat.par_step(|index, current: &[f32]| {
+    ///     let left = current[index.saturating_sub(1)];
+    ///     let right = current[(index + 1).min(current.len() - 1)];
+    ///     (left + current[index] + right) / 3.0
+    /// });
+    /// heat.swap();
+    ///
+    /// assert_eq!(*heat, [3.0, 3.0, 3.0]);
+    /// ```
+    #[inline]
+    pub fn par_step<E>(&mut self, f: impl Fn(usize, &[E]) -> E + Sync)
+    where
+        T: AsRef<[E]> + AsMut<[E]>,
+        E: Send + Sync,
+    {
+        let (current, next) = self.split_mut();
+        let current = current.as_ref();
+        next.as_mut()
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(index, element)| {
+                *element = f(index, current);
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TrackedBuffer;
+
+    #[test]
+    fn test_par_step_marks_pending_changes() {
+        let mut buffer: TrackedBuffer<[u32; 64]> = DoubleBuffer::tracked([1; 64], [0; 64]);
+        buffer.par_step(|index, current: &[u32]| current[index] + index as u32);
+        assert!(buffer.has_pending_changes());
+
+        buffer.swap();
+        assert!(buffer
+            .iter()
+            .enumerate()
+            .all(|(index, value)| *value == index as u32 + 1));
+    }
+}
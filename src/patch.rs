@@ -0,0 +1,125 @@
+use crate::{DoubleBuffer, Instrument, PatchError};
+
+/// Change that can be applied to a value, like a diff received from the network.
+///
+/// It's implemented for closures returning a [`Result`].
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::Patch;
+/// struct SetPixel {
+///     index: usize,
+///     value: u8,
+/// }
+///
+/// impl<const N: usize> Patch<[u8; N]> for SetPixel {
+///     type Error = ();
+///
+///     fn apply(self, target: &mut [u8; N]) -> Result<(), ()> {
+///         *target.get_mut(self.index).ok_or(())? = self.value;
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait Patch<T: ?Sized> {
+    type Error;
+
+    /// Applies the change to the value.
+    fn apply(self, target: &mut T) -> Result<(), Self::Error>;
+}
+
+impl<T: ?Sized, E, F: FnOnce(&mut T) -> Result<(), E>> Patch<T> for F {
+    type Error = E;
+
+    #[inline]
+    fn apply(self, target: &mut T) -> Result<(), E> {
+        self(target)
+    }
+}
+
+impl<T: Clone, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Applies the patch to the next value and validates the result with the
+    /// invariant of the buffer, see [`DoubleBuffer::set_invariant()`], in any build.
+    ///
+    /// If the patch fails or the result is invalid, the next value is restored to
+    /// its value before the call, keeping the patches applied before, so a partially
+    /// patched value is never published. The next value is cloned to restore it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::{PatchError, TrackedBuffer};
+    /// let mut buffer: TrackedBuffer<[u8; 4]> = TrackedBuffer::default();
+    /// buffer.set_invariant(|value| value.iter().all(|byte| *byte < 100));
+    ///
+    /// let set = |index: usize, value: u8| {
+    ///     move |next: &mut [u8; 4]| -> Result<(), ()> {
+    ///         next[index] = value;
+    ///         Ok(())
+    ///     }
+    /// };
+    ///
+    /// buffer.apply_patch(set(0, 1)).unwrap();
+    /// assert_eq!(buffer.apply_patch(set(1, 200)), Err(PatchError::Invalid));
+    ///
+    /// buffer.swap();
+    /// assert_eq!(buffer, [1, 0, 0, 0]);
+    /// ```
+    pub fn apply_patch<P: Patch<T>>(&mut self, patch: P) -> Result<(), PatchError<P::Error>> {
+        let backup = self.next().clone();
+        let result = patch.apply(self.as_mut()).map_err(PatchError::Patch);
+        let result = result.and_then(|()| match self.invariant_holds(self.next()) {
+            true => Ok(()),
+            false => Err(PatchError::Invalid),
+        });
+        if result.is_err() {
+            *self.next_mut() = backup;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failed_patch_restores_next() {
+        let mut buffer: DoubleBuffer<[u8; 2]> = DoubleBuffer::new([1, 1], [2, 2]);
+        let result = buffer.apply_patch(|next: &mut [u8; 2]| {
+            next[0] = 3;
+            Err("truncated")
+        });
+        assert_eq!(result, Err(PatchError::Patch("truncated")));
+        assert_eq!(*buffer.next(), [2, 2]);
+    }
+
+    #[test]
+    fn test_failed_patch_keeps_earlier_patches() {
+        let mut buffer: DoubleBuffer<[u8; 2]> = DoubleBuffer::new([1, 1], [2, 2]);
+        let set_first = |next: &mut [u8; 2]| {
+            next[0] = 3;
+            Ok::<_, &str>(())
+        };
+        let fail_second = |next: &mut [u8; 2]| {
+            next[1] = 4;
+            Err("truncated")
+        };
+        assert_eq!(buffer.apply_patch(set_first), Ok(()));
+        assert!(buffer.apply_patch(fail_second).is_err());
+        assert_eq!(*buffer.next(), [3, 2]);
+    }
+
+    #[test]
+    fn test_patch_applied_to_next() {
+        let mut buffer: DoubleBuffer<[u8; 2]> = DoubleBuffer::new([1, 1], [2, 2]);
+        let result = buffer.apply_patch(|next: &mut [u8; 2]| {
+            next[1] = 3;
+            Ok::<_, ()>(())
+        });
+        assert_eq!(result, Ok(()));
+        assert_eq!(*buffer.next(), [2, 3]);
+        assert_eq!(buffer, [1, 1]);
+    }
+}
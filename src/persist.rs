@@ -0,0 +1,156 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::{DoubleBuffer, Instrument, PersistError, Tracked};
+
+/// Bytes of the CRC-32 after the serialized snapshot.
+const CRC_LEN: usize = 4;
+
+impl<T: Serialize, S> DoubleBuffer<T, S, Tracked<T>> {
+    /// Serializes the current value and the generation with `postcard` into `buf`,
+    /// followed by a CRC-32 of them, returning the length of the snapshot.
+    ///
+    /// The snapshot is restored with [`DoubleBuffer::restore()`], so devices can
+    /// checkpoint the published state to flash and recover it after a reset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::{DoubleBuffer, TrackedBuffer};
+    /// let mut setpoints: TrackedBuffer<[u16; 3]> = DoubleBuffer::tracked([0; 3], [0; 3]);
+    /// *setpoints = [20, 40, 60];
+    /// setpoints.swap();
+    ///
+    /// let mut flash = [0xFF; 64];
+    /// let len = setpoints.persist_current(&mut flash).unwrap();
+    ///
+    /// let mut restored: TrackedBuffer<[u16; 3]> = DoubleBuffer::tracked([0; 3], [0; 3]);
+    /// restored.restore(&flash[..len]).unwrap();
+    /// assert_eq!(restored, [20, 40, 60]);
+    /// assert_eq!(*restored.next(), [20, 40, 60]);
+    /// assert_eq!(restored.generation(), 1);
+    /// ```
+    pub fn persist_current(&self, buf: &mut [u8]) -> Result<usize, PersistError> {
+        let len = postcard::to_slice(&(self.instrument.generation, self.current()), buf)
+            .map_err(|error| match error {
+                postcard::Error::SerializeBufferFull => PersistError::BufferTooSmall,
+                error => PersistError::Serialize(error),
+            })?
+            .len();
+        let crc = crc32(&buf[..len]).to_le_bytes();
+        buf.get_mut(len..len + CRC_LEN)
+            .ok_or(PersistError::BufferTooSmall)?
+            .copy_from_slice(&crc);
+        Ok(len + CRC_LEN)
+    }
+}
+
+impl<T: Serialize, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Serializes the current value with `postcard` into `buf`, returning the
+    /// length of the bytes, to ship it to another machine.
+    ///
+    /// Unlike [`DoubleBuffer::persist_current()`], the bytes are only the value,
+    /// without the generation or a checksum, for transports checking integrity
+    /// themselves. The buffer is rebuilt with [`DoubleBuffer::from_bytes()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let mut scene: DoubleBuffer<(u8, [i16; 2])> = DoubleBuffer::default();
+    /// *scene = (3, [-10, 20]);
+    /// scene.swap();
+    ///
+    /// let mut packet = [0; 32];
+    /// let len = scene.to_bytes(&mut packet).unwrap();
+    ///
+    /// let received: DoubleBuffer<(u8, [i16; 2])> = DoubleBuffer::from_bytes(&packet[..len]).unwrap();
+    /// assert_eq!(received, (3, [-10, 20]));
+    /// ```
+    pub fn to_bytes(&self, buf: &mut [u8]) -> Result<usize, PersistError> {
+        let bytes = postcard::to_slice(self.current(), buf).map_err(|error| match error {
+            postcard::Error::SerializeBufferFull => PersistError::BufferTooSmall,
+            error => PersistError::Serialize(error),
+        })?;
+        Ok(bytes.len())
+    }
+}
+
+impl<T: DeserializeOwned + Clone, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Creates a buffer with the value serialized by [`DoubleBuffer::to_bytes()`]
+    /// as the current value and the next value.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PersistError> {
+        let value: T = postcard::from_bytes(bytes).map_err(PersistError::Deserialize)?;
+        Ok(Self::with_strategy(value.clone(), value))
+    }
+}
+
+impl<T: DeserializeOwned + Clone, S> DoubleBuffer<T, S, Tracked<T>> {
+    /// Restores a snapshot of [`DoubleBuffer::persist_current()`] as the current
+    /// value and the next value, with its generation, and clears the poison.
+    ///
+    /// Nothing is changed if the CRC-32 doesn't match, the snapshot can't be
+    /// deserialized, or the value violates the invariant of the buffer.
+    pub fn restore(&mut self, bytes: &[u8]) -> Result<(), PersistError> {
+        let Some((snapshot, crc)) = bytes.split_last_chunk::<CRC_LEN>() else {
+            return Err(PersistError::Truncated);
+        };
+        if crc32(snapshot) != u32::from_le_bytes(*crc) {
+            return Err(PersistError::Checksum);
+        }
+        let (generation, value): (u64, T) =
+            postcard::from_bytes(snapshot).map_err(PersistError::Deserialize)?;
+        if !self.invariant_holds(&value) {
+            return Err(PersistError::Invalid);
+        }
+        let (current, next) = self.both_mut();
+        *current = value;
+        next.clone_from(current);
+        self.instrument.generation = generation;
+        self.instrument.dirty = false;
+        self.instrument.poisoned = false;
+        Ok(())
+    }
+}
+
+/// CRC-32 of IEEE 802.3, the one of zip and Ethernet.
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TrackedBuffer;
+
+    #[test]
+    fn test_crc32_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_restore_corrupted() {
+        let buffer: TrackedBuffer<u32> = DoubleBuffer::tracked(7, 0);
+        let mut bytes = [0; 16];
+        let len = buffer.persist_current(&mut bytes).unwrap();
+        assert_eq!(
+            buffer.persist_current(&mut bytes[..len - 1]),
+            Err(PersistError::BufferTooSmall)
+        );
+
+        let mut restored: TrackedBuffer<u32> = DoubleBuffer::tracked(1, 2);
+        bytes[0] ^= 1;
+        assert_eq!(restored.restore(&bytes[..len]), Err(PersistError::Checksum));
+        assert_eq!(restored.restore(&bytes[..4]), Err(PersistError::Checksum));
+        assert_eq!(restored.restore(&bytes[..3]), Err(PersistError::Truncated));
+        assert_eq!((*restored, *restored.next()), (1, 2));
+    }
+}
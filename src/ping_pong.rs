@@ -0,0 +1,144 @@
+use core::future::{poll_fn, Future};
+use core::pin::pin;
+use core::task::Poll;
+
+use embedded_hal_async::spi::SpiBus;
+
+use crate::{DoubleBuffer, Instrument, MoveSwap, SwapStrategy};
+
+/// Streams a [`DoubleBuffer`] to an SPI bus, like audio samples to a DAC or
+/// frames to a display, sending the current value while the next one is filled.
+///
+/// Every [`PingPong::step()`] starts sending the current value, fills the next
+/// value, waits for the transfer to complete and swaps, so continuous streaming
+/// is a loop of steps.
+pub struct PingPong<T, B, S = MoveSwap, I = ()> {
+    buffer: DoubleBuffer<T, S, I>,
+    bus: B,
+}
+
+impl<T, B, S, I: Instrument<T>> PingPong<T, B, S, I> {
+    #[inline]
+    pub const fn new(buffer: DoubleBuffer<T, S, I>, bus: B) -> Self {
+        Self { buffer, bus }
+    }
+
+    #[inline]
+    pub const fn buffer(&self) -> &DoubleBuffer<T, S, I> {
+        &self.buffer
+    }
+
+    #[inline]
+    pub fn bus(&mut self) -> &mut B {
+        &mut self.bus
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> (DoubleBuffer<T, S, I>, B) {
+        (self.buffer, self.bus)
+    }
+}
+
+impl<T, B, S: SwapStrategy<T>, I: Instrument<T>> PingPong<T, B, S, I> {
+    /// Starts sending the current value, calls `fill` with the current value and the
+    /// next value while it's sent, waits for the transfer to complete and swaps.
+    ///
+    /// If the transfer fails, the buffer isn't swapped.
+    pub async fn step<W>(&mut self, fill: impl FnOnce(&T, &mut T)) -> Result<(), B::Error>
+    where
+        W: Copy + 'static,
+        B: SpiBus<W>,
+        T: AsRef<[W]>,
+    {
+        {
+            let (current, next) = self.buffer.split_mut();
+            let mut transfer = pin!(self.bus.write(current.as_ref()));
+            // Poll it once to start the transfer before filling the next value.
+            let started = poll_fn(|cx| Poll::Ready(transfer.as_mut().poll(cx))).await;
+            fill(current, next);
+            match started {
+                Poll::Ready(result) => result?,
+                Poll::Pending => transfer.await?,
+            }
+        }
+        self.bus.flush().await?;
+        self.buffer.swap();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::convert::Infallible;
+    use core::task::{Context, Waker};
+    use embedded_hal_async::spi::ErrorType;
+
+    #[derive(Default)]
+    struct Bus {
+        pending: bool,
+        sent: [u8; 4],
+    }
+
+    impl ErrorType for Bus {
+        type Error = Infallible;
+    }
+
+    impl SpiBus<u8> for Bus {
+        async fn read(&mut self, _words: &mut [u8]) -> Result<(), Infallible> {
+            Ok(())
+        }
+
+        async fn write(&mut self, words: &[u8]) -> Result<(), Infallible> {
+            // Completes on the second poll, like a DMA transfer.
+            self.pending = true;
+            poll_fn(|cx| {
+                if core::mem::take(&mut self.pending) {
+                    cx.waker().wake_by_ref();
+                    Poll::Pending
+                } else {
+                    Poll::Ready(())
+                }
+            })
+            .await;
+            self.sent.copy_from_slice(words);
+            Ok(())
+        }
+
+        async fn transfer(&mut self, _read: &mut [u8], _write: &[u8]) -> Result<(), Infallible> {
+            Ok(())
+        }
+
+        async fn transfer_in_place(&mut self, _words: &mut [u8]) -> Result<(), Infallible> {
+            Ok(())
+        }
+
+        async fn flush(&mut self) -> Result<(), Infallible> {
+            Ok(())
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn test_send_current_while_filling_next() {
+        let mut stream = PingPong::new(DoubleBuffer::new([1; 4], [0; 4]), Bus::default());
+        for value in 2..=3 {
+            block_on(stream.step(|current, next| {
+                *next = [value; 4];
+                assert_eq!(*current, [value - 1; 4]);
+            }))
+            .unwrap();
+        }
+        assert_eq!(stream.bus().sent, [2; 4]);
+        assert_eq!(**stream.buffer(), [3; 4]);
+    }
+}
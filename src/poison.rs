@@ -0,0 +1,94 @@
+use core::panic::{RefUnwindSafe, UnwindSafe};
+
+use crate::{DoubleBuffer, Instrument, Tracked};
+
+impl<T, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Builds the next value with `f`, which gets the current value too.
+    ///
+    /// If `f` panics and the panic is caught, a [`TrackedBuffer`](crate::TrackedBuffer)
+    /// is poisoned: the next value may be half-built, so every swap panics until
+    /// [`DoubleBuffer::clear_poison()`] is called.
+    ///
+    /// A buffer without instrumentation has no poison flag, so after a caught panic
+    /// the next swap publishes the half-built value, unless it's fixed or replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::TrackedBuffer;
+    /// # use std::panic::{catch_unwind, AssertUnwindSafe};
+    /// let mut frame: TrackedBuffer<[u8; 4]> = TrackedBuffer::default();
+    ///
+    /// let result = catch_unwind(AssertUnwindSafe(|| {
+    ///     frame.build_next(|_, next| {
+    ///         next[0] = 1;
+    ///         panic!("the frame couldn't be built");
+    ///     })
+    /// }));
+    /// assert!(result.is_err());
+    /// assert!(frame.is_poisoned());
+    ///
+    /// // Discard the half-built frame before publishing again.
+    /// *frame = [0; 4];
+    /// frame.clear_poison();
+    /// frame.swap();
+    /// ```
+    #[inline]
+    pub fn build_next<R>(&mut self, f: impl FnOnce(&T, &mut T) -> R) -> R {
+        self.set_poisoned(true);
+        let (current, next) = self.split_mut();
+        let result = f(current, next);
+        self.set_poisoned(false);
+        result
+    }
+
+    #[inline]
+    pub(crate) fn set_poisoned(&mut self, poisoned: bool) {
+        if let Some(tracked) = self.instrument.tracked_mut() {
+            tracked.poisoned = poisoned;
+        }
+    }
+
+    #[inline]
+    pub(crate) fn poisoned(&self) -> bool {
+        self.instrument
+            .tracked()
+            .is_some_and(|tracked| tracked.poisoned)
+    }
+}
+
+impl<T, S> DoubleBuffer<T, S, Tracked<T>> {
+    /// Returns `true` if a panic interrupted [`DoubleBuffer::build_next()`],
+    /// so the next value may be half-built.
+    #[inline]
+    pub const fn is_poisoned(&self) -> bool {
+        self.instrument.poisoned
+    }
+
+    /// Allows publishing again the next value of a poisoned buffer, after it was
+    /// fixed or replaced.
+    #[inline]
+    pub fn clear_poison(&mut self) {
+        self.instrument.poisoned = false;
+    }
+}
+
+/// The strategy is only a marker, the values and the instrument decide.
+impl<T: UnwindSafe, S, I: UnwindSafe> UnwindSafe for DoubleBuffer<T, S, I> {}
+
+/// The strategy is only a marker, the values and the instrument decide.
+impl<T: RefUnwindSafe, S, I: RefUnwindSafe> RefUnwindSafe for DoubleBuffer<T, S, I> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unwind_safe_without_hooks() {
+        fn assert_unwind_safe<B: UnwindSafe + RefUnwindSafe>() {}
+
+        assert_unwind_safe::<DoubleBuffer<u8>>();
+        #[cfg(not(feature = "alloc"))]
+        assert_unwind_safe::<crate::TrackedBuffer<u8>>();
+    }
+}
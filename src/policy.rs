@@ -0,0 +1,154 @@
+use core::fmt::{Debug, Formatter};
+use core::ops::{Deref, DerefMut};
+
+use crate::{DoubleBuffer, MoveSwap, SwapStrategy};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Which value [`AccessBuffer`] gives mutable access to through [`DerefMut`].
+///
+/// It's implemented by [`ReadCurrentWriteNext`] and [`ReadWriteCurrent`].
+pub trait AccessPolicy: sealed::Sealed {
+    #[doc(hidden)]
+    fn target_mut<T, S>(buffer: &mut DoubleBuffer<T, S>) -> &mut T;
+}
+
+/// Reads go to the current value and writes to the next value, like [`DoubleBuffer`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ReadCurrentWriteNext;
+
+impl sealed::Sealed for ReadCurrentWriteNext {}
+
+impl AccessPolicy for ReadCurrentWriteNext {
+    #[inline]
+    fn target_mut<T, S>(buffer: &mut DoubleBuffer<T, S>) -> &mut T {
+        buffer.as_mut()
+    }
+}
+
+/// Reads and writes go to the current value, the next value is only written
+/// with [`AccessBuffer::stage_mut()`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ReadWriteCurrent;
+
+impl sealed::Sealed for ReadWriteCurrent {}
+
+impl AccessPolicy for ReadWriteCurrent {
+    #[inline]
+    fn target_mut<T, S>(buffer: &mut DoubleBuffer<T, S>) -> &mut T {
+        let (current, _) = buffer.current_mut_and_next();
+        current
+    }
+}
+
+/// Wraps a [`DoubleBuffer`] choosing with the [`AccessPolicy`] `P` which value
+/// [`DerefMut`] writes, so the convention is part of the type.
+///
+/// It always dereferences to the current value, and [`AccessBuffer::stage_mut()`]
+/// always gives access to the next value.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{AccessBuffer, DoubleBuffer, ReadWriteCurrent};
+/// let mut settings: AccessBuffer<u32, ReadWriteCurrent> = AccessBuffer::new(DoubleBuffer::new(1, 2));
+///
+/// // Writes go to the current value.
+/// *settings += 10;
+/// assert_eq!(*settings, 11);
+///
+/// *settings.stage_mut() = 3;
+/// settings.swap();
+/// assert_eq!(*settings, 3);
+/// ```
+pub struct AccessBuffer<T, P, S = MoveSwap> {
+    buffer: DoubleBuffer<T, S>,
+    policy: P,
+}
+
+impl<T, P: AccessPolicy + Default, S> AccessBuffer<T, P, S> {
+    #[inline]
+    pub fn new(buffer: DoubleBuffer<T, S>) -> Self {
+        Self {
+            buffer,
+            policy: P::default(),
+        }
+    }
+}
+
+impl<T, P, S> AccessBuffer<T, P, S> {
+    #[inline]
+    pub const fn buffer(&self) -> &DoubleBuffer<T, S> {
+        &self.buffer
+    }
+
+    /// Gives mutable access to the next value, whatever the policy is.
+    #[inline]
+    pub fn stage_mut(&mut self) -> &mut T {
+        self.buffer.as_mut()
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> DoubleBuffer<T, S> {
+        self.buffer
+    }
+}
+
+impl<T, P, S: SwapStrategy<T>> AccessBuffer<T, P, S> {
+    /// Swaps the buffer, see [`DoubleBuffer::swap()`].
+    #[inline]
+    pub fn swap(&mut self) {
+        self.buffer.swap();
+    }
+}
+
+impl<T: Debug, P: Debug, S> Debug for AccessBuffer<T, P, S> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AccessBuffer")
+            .field("buffer", &self.buffer)
+            .field("policy", &self.policy)
+            .finish()
+    }
+}
+
+impl<T: Default, P: AccessPolicy + Default, S> Default for AccessBuffer<T, P, S> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(DoubleBuffer::default())
+    }
+}
+
+impl<T, P, S> Deref for AccessBuffer<T, P, S> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+impl<T, P: AccessPolicy, S> DerefMut for AccessBuffer<T, P, S> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        P::target_mut(&mut self.buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_current_write_next() {
+        let mut buffer: AccessBuffer<u32, ReadCurrentWriteNext> = AccessBuffer::default();
+        *buffer = 1;
+        assert_eq!(*buffer, 0);
+        assert_eq!(*buffer.buffer().next(), 1);
+
+        buffer.swap();
+        assert_eq!(*buffer, 1);
+    }
+}
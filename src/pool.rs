@@ -0,0 +1,137 @@
+use alloc::vec::Vec;
+
+use crate::{Clear, DoubleBuffer, Instrument, SwapKind};
+
+/// Simple pool of recycled values for [`DoubleBuffer::swap_with_pool()`].
+///
+/// It can be shared between many buffers of the same type, so values retired
+/// by one buffer can be reused by another one without allocating.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pool<T> {
+    items: Vec<T>,
+}
+
+impl<T> Pool<T> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            items: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Takes a value from the pool, if any.
+    #[inline]
+    pub fn take(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    /// Gives a value to the pool to be reused later.
+    #[inline]
+    pub fn give(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl<T> Default for Pool<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Extend<T> for Pool<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.items.extend(iter);
+    }
+}
+
+impl<T> FromIterator<T> for Pool<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            items: Vec::from_iter(iter),
+        }
+    }
+}
+
+impl<T: Clear, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Swaps buffers like [`DoubleBuffer::swap_with_clear()`], but the next value is
+    /// taken from the pool and the previous current value is given to the pool.
+    ///
+    /// If the pool is empty, the previous current value is cleared and reused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::{DoubleBuffer, Pool};
+    /// let mut pool: Pool<Vec<u8>> = Pool::new();
+    /// pool.give(Vec::with_capacity(1024));
+    ///
+    /// let mut buffer: DoubleBuffer<Vec<u8>> = DoubleBuffer::default();
+    /// buffer.push(1);
+    /// buffer.swap_with_pool(&mut pool);
+    ///
+    /// assert_eq!(buffer, vec![1]);
+    /// assert_eq!(pool.len(), 1);
+    ///
+    /// // The next value was taken from the pool.
+    /// buffer.swap();
+    /// assert!(buffer.capacity() >= 1024);
+    /// ```
+    #[inline]
+    pub fn swap_with_pool(&mut self, pool: &mut Pool<T>) {
+        self.check_invariant();
+        self.flip();
+        if let Some(recycled) = pool.take() {
+            let retired = core::mem::replace(self.next_mut(), recycled);
+            pool.give(retired);
+        }
+        self.next_mut().clear();
+        self.published(SwapKind::Pool);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_swap_with_pool() {
+        let mut pool: Pool<Vec<u8>> = Pool::new();
+        pool.give(vec![9]);
+
+        let mut buffer: DoubleBuffer<Vec<u8>> = DoubleBuffer::new(vec![1], vec![2]);
+        buffer.swap_with_pool(&mut pool);
+        assert_eq!(*buffer.current(), vec![2]);
+        assert!(buffer.next().is_empty());
+        assert_eq!(pool.take(), Some(vec![1]));
+    }
+
+    #[test]
+    fn test_swap_with_empty_pool() {
+        let mut pool: Pool<Vec<u8>> = Pool::new();
+
+        let mut buffer: DoubleBuffer<Vec<u8>> = DoubleBuffer::new(vec![1], vec![2]);
+        buffer.swap_with_pool(&mut pool);
+        assert_eq!(*buffer.current(), vec![2]);
+        assert!(buffer.next().is_empty());
+        assert!(pool.is_empty());
+    }
+}
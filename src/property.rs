@@ -0,0 +1,81 @@
+use core::fmt::Debug;
+
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::strategy::{Map, Strategy};
+
+use crate::{DoubleBuffer, Instrument};
+
+type Parts<T> = (T, T, bool);
+
+impl<T: Debug, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Strategy generating buffers with the current and next values from the given
+    /// strategies, whose current value is in either slot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// use proptest::prelude::*;
+    ///
+    /// proptest!(|(mut buffer in DoubleBuffer::<u8>::strategy_from(0..10u8, 10..20u8))| {
+    ///     buffer.swap();
+    ///     prop_assert!(*buffer >= 10);
+    /// });
+    /// ```
+    #[inline]
+    pub fn strategy_from<C, N>(current: C, next: N) -> impl Strategy<Value = Self>
+    where
+        C: Strategy<Value = T>,
+        N: Strategy<Value = T>,
+    {
+        (current, next, any::<bool>()).prop_map(Self::from_parts)
+    }
+
+    fn from_parts((current, next, swapped): Parts<T>) -> Self {
+        if swapped {
+            let mut buffer = Self::with_strategy(next, current);
+            buffer.flip();
+            buffer
+        } else {
+            Self::with_strategy(current, next)
+        }
+    }
+}
+
+/// Generates both values with the strategy of `T`, and whether the buffer was
+/// swapped an odd number of times, see [`DoubleBuffer::strategy_from()`].
+impl<T: Arbitrary, S, I: Instrument<T>> Arbitrary for DoubleBuffer<T, S, I> {
+    type Parameters = (T::Parameters, T::Parameters);
+    type Strategy = Map<(T::Strategy, T::Strategy, proptest::bool::Any), fn(Parts<T>) -> Self>;
+
+    #[inline]
+    fn arbitrary_with((current, next): Self::Parameters) -> Self::Strategy {
+        (
+            T::arbitrary_with(current),
+            T::arbitrary_with(next),
+            any::<bool>(),
+        )
+            .prop_map(Self::from_parts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TrackedBuffer;
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::TestRunner;
+
+    #[test]
+    fn test_both_orientations() {
+        let mut runner = TestRunner::deterministic();
+        let strategy = any::<TrackedBuffer<u8>>();
+        let mut slots = [false; 2];
+        for _ in 0..64 {
+            let buffer = strategy.new_tree(&mut runner).unwrap().current();
+            slots[buffer.current_slot().index()] = true;
+            assert_eq!(buffer.generation(), 0);
+        }
+        assert_eq!(slots, [true; 2]);
+    }
+}
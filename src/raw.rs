@@ -0,0 +1,69 @@
+use core::slice;
+
+use crate::DoubleBuffer;
+
+impl DoubleBuffer<&mut [u8]> {
+    /// Creates a buffer over two byte regions it doesn't own, like the frames
+    /// given by a C SDK or a vendor driver, with `current` published.
+    ///
+    /// Each region is a pointer and a length in bytes. The buffer only swaps
+    /// which region is current, the regions are never copied or freed, so it
+    /// gives the safe API of [`DoubleBuffer`] over memory owned by foreign code.
+    ///
+    /// # Safety
+    ///
+    /// For as long as the buffer is used, each region must be valid for reads and
+    /// writes of its length, properly initialized, and not accessed other than
+    /// through the buffer, and the two regions must not overlap, as required by
+    /// [`slice::from_raw_parts_mut()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// // Regions allocated by foreign code.
+    /// let (mut front, mut back) = ([1u8; 4], [0u8; 4]);
+    ///
+    /// let mut frames = unsafe {
+    ///     DoubleBuffer::from_raw_parts(
+    ///         (front.as_mut_ptr(), front.len()),
+    ///         (back.as_mut_ptr(), back.len()),
+    ///     )
+    /// };
+    /// frames.copy_from_slice(&[2; 4]);
+    /// frames.swap();
+    /// assert_eq!(**frames, [2; 4]);
+    /// ```
+    #[inline]
+    pub unsafe fn from_raw_parts(current: (*mut u8, usize), next: (*mut u8, usize)) -> Self {
+        // SAFETY: the caller guarantees the regions are valid and exclusive while it's used.
+        let (current, next) = unsafe {
+            (
+                slice::from_raw_parts_mut(current.0, current.1),
+                slice::from_raw_parts_mut(next.0, next.1),
+            )
+        };
+        Self::new(current, next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regions_written_in_place() {
+        let (mut first, mut second) = ([0u8; 2], [0u8; 2]);
+        // SAFETY: both arrays outlive the buffer and are only accessed through it.
+        let mut buffer = unsafe {
+            DoubleBuffer::from_raw_parts(
+                (first.as_mut_ptr(), first.len()),
+                (second.as_mut_ptr(), second.len()),
+            )
+        };
+        buffer[1] = 5;
+        buffer.swap();
+        buffer[0] = 7;
+        assert_eq!((first, second), ([7, 0], [0, 5]));
+    }
+}
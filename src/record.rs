@@ -0,0 +1,226 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::{Clear, Clock, DoubleBuffer, MoveSwap, SwapStrategy, Tracked};
+
+/// Sink of the values published by a [`Recorded`] buffer, for replay capture and
+/// time-travel debugging.
+///
+/// It's given every published value with its generation and the time it was
+/// published, and keeps a clone of it, a serialized form, or anything derived
+/// from it. It's implemented for closures, and with the `alloc` feature, for
+/// vectors of `(generation, time, value)` keeping clones of the values.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::Recorder;
+/// /// Keeps the checksum of every frame, to compare replays.
+/// struct ChecksumLog(Vec<(u64, u32)>);
+///
+/// impl Recorder<[u8; 4], u32> for ChecksumLog {
+///     fn record(&mut self, generation: u64, _time: u32, published: &[u8; 4]) {
+///         let checksum = published.iter().map(|&byte| u32::from(byte)).sum();
+///         self.0.push((generation, checksum));
+///     }
+/// }
+/// ```
+pub trait Recorder<T, I> {
+    /// Records the value published at `generation` at the time `time`.
+    fn record(&mut self, generation: u64, time: I, published: &T);
+}
+
+impl<T, I, F: FnMut(u64, I, &T)> Recorder<T, I> for F {
+    #[inline]
+    fn record(&mut self, generation: u64, time: I, published: &T) {
+        self(generation, time, published);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone, I> Recorder<T, I> for alloc::vec::Vec<(u64, I, T)> {
+    #[inline]
+    fn record(&mut self, generation: u64, time: I, published: &T) {
+        self.push((generation, time, published.clone()));
+    }
+}
+
+/// Wraps a [`TrackedBuffer`](crate::TrackedBuffer) giving every published value to a [`Recorder`], with
+/// its generation and the time measured with a [`Clock`].
+///
+/// Like [`Timestamped`](crate::Timestamped), it dereferences to the current value
+/// and mutably dereferences to the next value, and all swaps must go through the
+/// wrapper, so the inner buffer is only accessible immutably with
+/// [`Recorded::buffer()`].
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{Clock, Recorded, TrackedBuffer};
+/// # struct FixedClock;
+/// # impl Clock for FixedClock {
+/// #     type Instant = u32;
+/// #     type Duration = u32;
+/// #     fn now(&self) -> u32 { 10 }
+/// #     fn elapsed(&self, since: u32) -> u32 { 10 - since }
+/// # }
+/// let mut replay = Vec::new();
+/// let mut position = Recorded::new(
+///     TrackedBuffer::<i32>::default(),
+///     FixedClock,
+///     |generation, time, &position: &i32| replay.push((generation, time, position)),
+/// );
+///
+/// *position = 3;
+/// position.swap();
+/// *position = 5;
+/// position.swap();
+///
+/// assert_eq!(replay, [(1, 10, 3), (2, 10, 5)]);
+/// ```
+pub struct Recorded<T, R, C: Clock, S = MoveSwap> {
+    buffer: DoubleBuffer<T, S, Tracked<T>>,
+    clock: C,
+    recorder: R,
+}
+
+impl<T, R: Recorder<T, C::Instant>, C: Clock, S> Recorded<T, R, C, S> {
+    #[inline]
+    pub const fn new(buffer: DoubleBuffer<T, S, Tracked<T>>, clock: C, recorder: R) -> Self {
+        Self {
+            buffer,
+            clock,
+            recorder,
+        }
+    }
+
+    #[inline]
+    pub const fn buffer(&self) -> &DoubleBuffer<T, S, Tracked<T>> {
+        &self.buffer
+    }
+
+    #[inline]
+    pub const fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    #[inline]
+    pub const fn recorder(&self) -> &R {
+        &self.recorder
+    }
+
+    #[inline]
+    pub const fn recorder_mut(&mut self) -> &mut R {
+        &mut self.recorder
+    }
+
+    /// Swaps like [`DoubleBuffer::try_swap_with()`] and records the published value
+    /// if it was swapped.
+    #[inline]
+    pub fn try_swap_with<E>(&mut self, reset: impl FnOnce() -> Result<T, E>) -> Result<(), E> {
+        self.buffer.try_swap_with(reset)?;
+        self.record_publish();
+        Ok(())
+    }
+
+    /// Returns the buffer and the recorder.
+    #[inline]
+    pub fn into_parts(self) -> (DoubleBuffer<T, S, Tracked<T>>, R) {
+        (self.buffer, self.recorder)
+    }
+
+    #[inline]
+    fn record_publish(&mut self) {
+        let time = self.clock.now();
+        self.recorder
+            .record(self.buffer.generation(), time, self.buffer.current());
+    }
+}
+
+impl<T, R: Recorder<T, C::Instant>, C: Clock, S: SwapStrategy<T>> Recorded<T, R, C, S> {
+    /// Swaps like [`DoubleBuffer::swap()`] and records the published value.
+    #[inline]
+    pub fn swap(&mut self) {
+        self.buffer.swap();
+        self.record_publish();
+    }
+}
+
+impl<T: Clone, R: Recorder<T, C::Instant>, C: Clock, S> Recorded<T, R, C, S> {
+    /// Swaps like [`DoubleBuffer::swap_with_clone()`] and records the published value.
+    #[inline]
+    pub fn swap_with_clone(&mut self) {
+        self.buffer.swap_with_clone();
+        self.record_publish();
+    }
+}
+
+impl<T: Default, R: Recorder<T, C::Instant>, C: Clock, S> Recorded<T, R, C, S> {
+    /// Swaps like [`DoubleBuffer::swap_with_default()`] and records the published value.
+    #[inline]
+    pub fn swap_with_default(&mut self) {
+        self.buffer.swap_with_default();
+        self.record_publish();
+    }
+}
+
+impl<T: Clear, R: Recorder<T, C::Instant>, C: Clock, S> Recorded<T, R, C, S> {
+    /// Swaps like [`DoubleBuffer::swap_with_clear()`] and records the published value.
+    #[inline]
+    pub fn swap_with_clear(&mut self) {
+        self.buffer.swap_with_clear();
+        self.record_publish();
+    }
+}
+
+impl<T, R, C: Clock, S> Deref for Recorded<T, R, C, S> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+impl<T, R, C: Clock, S> DerefMut for Recorded<T, R, C, S> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    struct Ticks;
+
+    impl Clock for Ticks {
+        type Instant = u8;
+        type Duration = u8;
+
+        fn now(&self) -> u8 {
+            7
+        }
+
+        fn elapsed(&self, since: u8) -> u8 {
+            7 - since
+        }
+    }
+
+    #[test]
+    fn test_failed_swap_not_recorded() {
+        let published = Cell::new(0);
+        let mut buffer = Recorded::new(
+            DoubleBuffer::tracked(1, 2),
+            Ticks,
+            |generation, time, value: &u32| {
+                assert_eq!((generation, time), (1, 7));
+                published.set(published.get() + value);
+            },
+        );
+        buffer.try_swap_with(|| Err(())).unwrap_err();
+        buffer.swap_with_default();
+        assert_eq!(published.get(), 2);
+    }
+}
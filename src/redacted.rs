@@ -0,0 +1,80 @@
+use core::fmt::{Debug, Formatter};
+use core::ops::{Deref, DerefMut};
+
+use crate::{DoubleBuffer, MoveSwap, Tracked};
+
+/// Wraps a [`TrackedBuffer`](crate::TrackedBuffer) whose [`Debug`] implementation doesn't print the values,
+/// only the generation, so buffers holding credentials or personal data can't
+/// leak them through logging.
+///
+/// It dereferences to the inner buffer, so it's used like the buffer.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{DoubleBuffer, Redacted};
+/// let mut token: Redacted<[u8; 4]> = Redacted::new(DoubleBuffer::tracked([1; 4], [2; 4]));
+/// token.swap();
+///
+/// assert_eq!(
+///     format!("{token:?}"),
+///     "DoubleBuffer { current: <redacted>, next: <redacted>, generation: 1 }",
+/// );
+/// assert_eq!(**token, [2; 4]);
+/// ```
+pub struct Redacted<T, S = MoveSwap> {
+    buffer: DoubleBuffer<T, S, Tracked<T>>,
+}
+
+impl<T, S> Redacted<T, S> {
+    #[inline]
+    pub const fn new(buffer: DoubleBuffer<T, S, Tracked<T>>) -> Self {
+        Self { buffer }
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> DoubleBuffer<T, S, Tracked<T>> {
+        self.buffer
+    }
+}
+
+impl<T, S> Debug for Redacted<T, S> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DoubleBuffer")
+            .field("current", &format_args!("<redacted>"))
+            .field("next", &format_args!("<redacted>"))
+            .field("generation", &self.buffer.generation())
+            .finish()
+    }
+}
+
+impl<T: Default, S> Default for Redacted<T, S> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(DoubleBuffer::default())
+    }
+}
+
+impl<T, S> From<DoubleBuffer<T, S, Tracked<T>>> for Redacted<T, S> {
+    #[inline]
+    fn from(buffer: DoubleBuffer<T, S, Tracked<T>>) -> Self {
+        Self::new(buffer)
+    }
+}
+
+impl<T, S> Deref for Redacted<T, S> {
+    type Target = DoubleBuffer<T, S, Tracked<T>>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+impl<T, S> DerefMut for Redacted<T, S> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buffer
+    }
+}
@@ -0,0 +1,149 @@
+use alloc::boxed::Box;
+use core::any::{Any, TypeId};
+use core::fmt::{Debug, Formatter};
+
+use hashbrown::HashMap;
+
+use crate::{DoubleBuffer, ReadOnlyView, WriteOnlyView};
+
+/// Buffers of a registry with their type erased, so they're swapped together.
+trait ErasedBuffer: Any {
+    fn swap(&mut self);
+}
+
+impl<T: 'static> ErasedBuffer for DoubleBuffer<T> {
+    #[inline]
+    fn swap(&mut self) {
+        DoubleBuffer::swap(self);
+    }
+}
+
+/// Buffers of different types registered by their type and swapped at once,
+/// for modular engines whose plugins add state that isn't known statically.
+///
+/// There is at most one [`DoubleBuffer<T>`] for each type `T`, looked up with
+/// [`BufferRegistry::view()`] to read the current value and
+/// [`BufferRegistry::stage()`] to write the next value, and
+/// [`BufferRegistry::swap_all()`] publishes all of them at the end of the frame.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{BufferRegistry, DoubleBuffer};
+/// struct Gravity(f32);
+/// struct Score(u32);
+///
+/// let mut registry = BufferRegistry::new();
+/// registry.register(DoubleBuffer::new(Gravity(9.8), Gravity(9.8)));
+/// registry.register(DoubleBuffer::new(Score(0), Score(0)));
+///
+/// registry.stage::<Score>().unwrap().0 += 10;
+/// assert_eq!(registry.view::<Score>().unwrap().0, 0);
+///
+/// registry.swap_all();
+/// assert_eq!(registry.view::<Score>().unwrap().0, 10);
+/// ```
+#[derive(Default)]
+pub struct BufferRegistry {
+    buffers: HashMap<TypeId, Box<dyn ErasedBuffer>>,
+}
+
+impl BufferRegistry {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the buffer of its type, returning the buffer of that type that
+    /// was registered before, if any.
+    pub fn register<T: 'static>(&mut self, buffer: DoubleBuffer<T>) -> Option<DoubleBuffer<T>> {
+        let previous = self.buffers.insert(TypeId::of::<T>(), Box::new(buffer))?;
+        let previous: Box<dyn Any> = previous;
+        previous.downcast().ok().map(|previous| *previous)
+    }
+
+    /// Removes the buffer of the type, returning it.
+    pub fn remove<T: 'static>(&mut self) -> Option<DoubleBuffer<T>> {
+        let buffer: Box<dyn Any> = self.buffers.remove(&TypeId::of::<T>())?;
+        buffer.downcast().ok().map(|buffer| *buffer)
+    }
+
+    /// Returns `true` if a buffer of the type is registered.
+    #[inline]
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.buffers.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Number of registered buffers.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.buffers.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.buffers.is_empty()
+    }
+
+    /// Gives access to the buffer of the type.
+    pub fn get<T: 'static>(&self) -> Option<&DoubleBuffer<T>> {
+        let buffer: &dyn Any = self.buffers.get(&TypeId::of::<T>())?.as_ref();
+        buffer.downcast_ref()
+    }
+
+    /// Gives mutable access to the buffer of the type.
+    pub fn get_mut<T: 'static>(&mut self) -> Option<&mut DoubleBuffer<T>> {
+        let buffer: &mut dyn Any = self.buffers.get_mut(&TypeId::of::<T>())?.as_mut();
+        buffer.downcast_mut()
+    }
+
+    /// Gives a read-only handle to the current value of the buffer of the type,
+    /// see [`DoubleBuffer::view()`].
+    #[inline]
+    pub fn view<T: 'static>(&self) -> Option<ReadOnlyView<'_, T>> {
+        self.get().map(DoubleBuffer::view)
+    }
+
+    /// Gives a handle to the next value of the buffer of the type, see
+    /// [`DoubleBuffer::stage()`].
+    #[inline]
+    pub fn stage<T: 'static>(&mut self) -> Option<WriteOnlyView<'_, T>> {
+        self.get_mut().map(DoubleBuffer::stage)
+    }
+
+    /// Swaps every registered buffer.
+    pub fn swap_all(&mut self) {
+        for buffer in self.buffers.values_mut() {
+            buffer.swap();
+        }
+    }
+}
+
+impl Debug for BufferRegistry {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BufferRegistry")
+            .field("len", &self.buffers.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_replaces_same_type() {
+        let mut registry = BufferRegistry::new();
+        assert!(registry.register(DoubleBuffer::new(1u8, 2)).is_none());
+        registry.register(DoubleBuffer::new(3u16, 4));
+        registry.swap_all();
+
+        let previous = registry.register(DoubleBuffer::new(5u8, 6)).unwrap();
+        assert_eq!(*previous, 2);
+        assert_eq!(registry.len(), 2);
+        assert_eq!(registry.remove::<u16>().as_deref(), Some(&4));
+        assert!(!registry.contains::<u16>());
+        assert!(registry.get::<u32>().is_none());
+    }
+}
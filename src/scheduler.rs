@@ -0,0 +1,145 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::{DoubleBuffer, MoveSwap, SwapStrategy};
+
+/// Owns a [`DoubleBuffer`] and swaps it at a fixed rate.
+///
+/// Each tick calls the step function with the current value and the next value,
+/// then swaps. Deadlines advance by the interval instead of from the time of the
+/// tick, so the rate doesn't drift. If it falls behind by more than one interval,
+/// the missed ticks are skipped instead of run in a burst.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{DoubleBuffer, SwapScheduler};
+/// # use std::time::Duration;
+/// let mut scheduler = SwapScheduler::new(DoubleBuffer::<u32>::default(), Duration::from_millis(1));
+///
+/// scheduler.run(|current, next| {
+///     *next = current + 1;
+///     *next < 10
+/// });
+///
+/// assert_eq!(**scheduler.buffer(), 10);
+/// ```
+pub struct SwapScheduler<T, S = MoveSwap> {
+    buffer: DoubleBuffer<T, S>,
+    interval: Duration,
+    deadline: Instant,
+}
+
+impl<T, S> SwapScheduler<T, S> {
+    /// Creates a scheduler swapping every `interval`, the first tick is due now.
+    #[inline]
+    pub fn new(buffer: DoubleBuffer<T, S>, interval: Duration) -> Self {
+        Self {
+            buffer,
+            interval,
+            deadline: Instant::now(),
+        }
+    }
+
+    /// Creates a scheduler swapping `per_second` times per second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `per_second` is zero.
+    #[inline]
+    pub fn with_rate(buffer: DoubleBuffer<T, S>, per_second: u32) -> Self {
+        Self::new(buffer, Duration::from_secs(1) / per_second)
+    }
+
+    #[inline]
+    pub const fn buffer(&self) -> &DoubleBuffer<T, S> {
+        &self.buffer
+    }
+
+    #[inline]
+    pub const fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Time when the next tick is due.
+    #[inline]
+    pub const fn deadline(&self) -> Instant {
+        self.deadline
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> DoubleBuffer<T, S> {
+        self.buffer
+    }
+
+    #[inline]
+    fn advance_deadline(&mut self, now: Instant) {
+        self.deadline += self.interval;
+        if self.deadline + self.interval < now {
+            self.deadline = now + self.interval;
+        }
+    }
+}
+
+impl<T, S: SwapStrategy<T>> SwapScheduler<T, S> {
+    /// Calls `step` and swaps if the next tick is due, without blocking.
+    ///
+    /// Returns `true` if it was due.
+    pub fn tick(&mut self, step: impl FnOnce(&T, &mut T)) -> bool {
+        let now = Instant::now();
+        if now < self.deadline {
+            return false;
+        }
+        self.step_and_swap(now, step);
+        true
+    }
+
+    /// Calls `step` and swaps at the fixed rate, sleeping between ticks,
+    /// while `step` returns `true`.
+    pub fn run(&mut self, mut step: impl FnMut(&T, &mut T) -> bool) {
+        let mut running = true;
+        while running {
+            let now = Instant::now();
+            if now < self.deadline {
+                thread::sleep(self.deadline - now);
+            }
+            self.step_and_swap(Instant::now(), |current, next| {
+                running = step(current, next)
+            });
+        }
+    }
+
+    #[inline]
+    fn step_and_swap(&mut self, now: Instant, step: impl FnOnce(&T, &mut T)) {
+        let (current, next) = self.buffer.split_mut();
+        step(current, next);
+        self.buffer.swap();
+        self.advance_deadline(now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tick_only_when_due() {
+        let mut scheduler =
+            SwapScheduler::new(DoubleBuffer::<u32>::default(), Duration::from_secs(3600));
+
+        assert!(scheduler.tick(|current, next| *next = current + 1));
+        assert_eq!(*scheduler.buffer(), 1);
+
+        assert!(!scheduler.tick(|current, next| *next = current + 1));
+        assert_eq!(*scheduler.buffer(), 1);
+    }
+
+    #[test]
+    fn test_skip_missed_ticks() {
+        let mut scheduler =
+            SwapScheduler::new(DoubleBuffer::<u32>::default(), Duration::from_millis(1));
+        let now = scheduler.deadline() + Duration::from_millis(100);
+        scheduler.advance_deadline(now);
+        assert_eq!(scheduler.deadline(), now + Duration::from_millis(1));
+    }
+}
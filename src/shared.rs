@@ -0,0 +1,241 @@
+use core::cell::{BorrowMutError, Cell, Ref, RefCell, RefMut};
+use core::fmt::{Debug, Formatter};
+
+use crate::{SlotId, Swap};
+
+/// Double buffer shared through shared references on the same thread, like
+/// [`DoubleBufferCell`](crate::DoubleBufferCell) but for values that aren't
+/// [`Copy`], giving guards to them.
+///
+/// Each value is in its own [`RefCell`], so the current value can be read while
+/// the next one is being written, and only borrowing the same value mutably twice
+/// panics. [`SharedDoubleBuffer::publish()`] swaps them like
+/// [`MoveSwap`](crate::MoveSwap), it can be called while the current value is
+/// being read, the guard keeps the value it borrowed.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::SharedDoubleBuffer;
+/// let log: SharedDoubleBuffer<Vec<&str>> = SharedDoubleBuffer::default();
+///
+/// let on_click = || log.stage_mut().push("click");
+/// let on_frame = || {
+///     log.publish();
+///     log.stage_mut().clear();
+/// };
+///
+/// on_click();
+/// assert!(log.read().is_empty());
+///
+/// on_frame();
+/// assert_eq!(*log.read(), ["click"]);
+/// ```
+pub struct SharedDoubleBuffer<T> {
+    current: Cell<SlotId>,
+    generation: Cell<u64>,
+    buffers: [RefCell<T>; 2],
+}
+
+impl<T> SharedDoubleBuffer<T> {
+    #[inline]
+    pub const fn new(current: T, next: T) -> Self {
+        Self {
+            current: Cell::new(SlotId::FIRST),
+            generation: Cell::new(0),
+            buffers: [RefCell::new(current), RefCell::new(next)],
+        }
+    }
+
+    /// Gives access to the current value.
+    ///
+    /// It never panics, as the value can't be published while it's mutably borrowed.
+    #[inline]
+    pub fn read(&self) -> Ref<'_, T> {
+        self.current_cell().borrow()
+    }
+
+    /// Gives access to the next value.
+    ///
+    /// # Panics
+    ///
+    /// If the next value is mutably borrowed.
+    #[inline]
+    pub fn stage(&self) -> Ref<'_, T> {
+        self.next_cell().borrow()
+    }
+
+    /// Gives mutable access to the next value.
+    ///
+    /// # Panics
+    ///
+    /// If the next value is borrowed, which includes a guard of
+    /// [`SharedDoubleBuffer::read()`] kept over a publish.
+    #[inline]
+    pub fn stage_mut(&self) -> RefMut<'_, T> {
+        self.next_cell().borrow_mut()
+    }
+
+    /// Swaps the current and next values, then writes will be over the previous current value.
+    ///
+    /// # Panics
+    ///
+    /// If the next value is borrowed, because it would be published while it's written.
+    #[inline]
+    pub fn publish(&self) {
+        self.try_publish()
+            .expect("SharedDoubleBuffer published while the next value is borrowed");
+    }
+
+    /// Swaps like [`SharedDoubleBuffer::publish()`], or returns an error without
+    /// swapping if the next value is borrowed.
+    #[inline]
+    pub fn try_publish(&self) -> Result<(), BorrowMutError> {
+        drop(self.next_cell().try_borrow_mut()?);
+        self.current.set(self.current.get().other());
+        self.generation.set(self.generation.get().wrapping_add(1));
+        Ok(())
+    }
+
+    /// Publishes like [`SharedDoubleBuffer::publish()`] only if the generation is
+    /// still `expected`, the one observed when staging started, so a writer doesn't
+    /// publish over a value published by another writer in the meantime.
+    ///
+    /// On conflict, it returns the current value to rebase the staged changes on.
+    ///
+    /// # Panics
+    ///
+    /// If the next value is borrowed, like [`SharedDoubleBuffer::publish()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::SharedDoubleBuffer;
+    /// let document: SharedDoubleBuffer<String> = SharedDoubleBuffer::default();
+    ///
+    /// let observed = document.generation();
+    /// *document.stage_mut() = "draft".to_string();
+    ///
+    /// // Another writer published first.
+    /// document.publish();
+    ///
+    /// let current = document.compare_and_publish(observed).unwrap_err();
+    /// assert_eq!(*current, "draft");
+    /// ```
+    #[inline]
+    pub fn compare_and_publish(&self, expected: u64) -> Result<(), Ref<'_, T>> {
+        if self.generation.get() != expected {
+            return Err(self.read());
+        }
+        self.publish();
+        Ok(())
+    }
+
+    /// Copies the current value into `into` with [`Clone::clone_from()`], reusing
+    /// its allocations, so a consumer can take a snapshot every iteration and
+    /// process it without keeping a guard of [`SharedDoubleBuffer::read()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::SharedDoubleBuffer;
+    /// let samples = SharedDoubleBuffer::new(vec![1, 2], Vec::new());
+    /// let mut snapshot = Vec::with_capacity(16);
+    ///
+    /// samples.clone_latest_into(&mut snapshot);
+    /// samples.stage_mut().push(3);
+    /// samples.publish();
+    ///
+    /// assert_eq!(snapshot, [1, 2]);
+    /// assert_eq!(snapshot.capacity(), 16);
+    /// ```
+    #[inline]
+    pub fn clone_latest_into(&self, into: &mut T)
+    where
+        T: Clone,
+    {
+        into.clone_from(&self.read());
+    }
+
+    /// Number of publishes since it was created, see
+    /// [`DoubleBuffer::generation()`](crate::DoubleBuffer::generation()).
+    #[inline]
+    pub fn generation(&self) -> u64 {
+        self.generation.get()
+    }
+
+    /// Gives access to the current value and mutable access to the next value at once,
+    /// without guards, as it's borrowed exclusively.
+    #[inline]
+    pub fn split_mut(&mut self) -> (&T, &mut T) {
+        let current = self.current.get();
+        let [first, second] = &mut self.buffers;
+        let (current, next) = if current == SlotId::FIRST {
+            (first, second)
+        } else {
+            (second, first)
+        };
+        (current.get_mut(), next.get_mut())
+    }
+
+    #[inline]
+    fn current_cell(&self) -> &RefCell<T> {
+        &self.buffers[self.current.get().index()]
+    }
+
+    #[inline]
+    fn next_cell(&self) -> &RefCell<T> {
+        &self.buffers[self.current.get().other().index()]
+    }
+}
+
+impl<T> Swap for SharedDoubleBuffer<T> {
+    #[inline]
+    fn swap_buffers(&mut self) {
+        self.publish();
+    }
+}
+
+impl<T: Debug> Debug for SharedDoubleBuffer<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SharedDoubleBuffer")
+            .field("current", self.current_cell())
+            .field("next", self.next_cell())
+            .finish()
+    }
+}
+
+impl<T: Default> Default for SharedDoubleBuffer<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default(), T::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_while_staging() {
+        let buffer = SharedDoubleBuffer::new([1], [2]);
+        let current = buffer.read();
+        let mut next = buffer.stage_mut();
+        next[0] = *current.first().unwrap() + 2;
+        assert!(buffer.try_publish().is_err());
+
+        drop(next);
+        buffer.publish();
+        assert_eq!(*current, [1]);
+        assert_eq!(*buffer.read(), [3]);
+        assert!(buffer.try_publish().is_err());
+
+        drop(current);
+        buffer.publish();
+        assert_eq!(buffer.generation(), 2);
+        assert!(buffer.compare_and_publish(1).is_err());
+        assert!(buffer.compare_and_publish(2).is_ok());
+        assert_eq!(*buffer.read(), [3]);
+    }
+}
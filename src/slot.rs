@@ -0,0 +1,90 @@
+use crate::{DoubleBuffer, Instrument};
+
+/// Identity of one of the two physical buffers of a [`DoubleBuffer`].
+///
+/// Unlike the current and next values, it doesn't change with swaps, so it can
+/// be used to look up resources bound to each buffer, like GPU descriptor sets
+/// or DMA descriptors.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::DoubleBuffer;
+/// let descriptor_sets = ["front", "back"];
+/// let mut buffer: DoubleBuffer<u32> = DoubleBuffer::new(1, 2);
+///
+/// let slot = buffer.next_slot();
+/// buffer.swap();
+///
+/// assert_eq!(buffer.current_slot(), slot);
+/// assert_eq!(descriptor_sets[slot.index()], "back");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SlotId(Slot);
+
+/// The enum lets the compiler know the index is `0` or `1`, so indexing the
+/// buffers isn't bounds checked and flipping is a XOR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(u8)]
+enum Slot {
+    First = 0,
+    Second = 1,
+}
+
+impl SlotId {
+    pub const FIRST: Self = Self(Slot::First);
+    pub const SECOND: Self = Self(Slot::Second);
+
+    /// Index of the buffer, `0` or `1`.
+    #[inline]
+    pub const fn index(self) -> usize {
+        self.0 as usize
+    }
+
+    /// The other buffer.
+    #[inline]
+    pub const fn other(self) -> Self {
+        match self.0 {
+            Slot::First => Self::SECOND,
+            Slot::Second => Self::FIRST,
+        }
+    }
+}
+
+impl<T, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Physical buffer holding the current value.
+    #[inline]
+    pub const fn current_slot(&self) -> SlotId {
+        self.current
+    }
+
+    /// Physical buffer holding the next value.
+    #[inline]
+    pub const fn next_slot(&self) -> SlotId {
+        self.current_slot().other()
+    }
+
+    /// Gives access to the value in a physical buffer, it may be the current or the next value.
+    #[inline]
+    pub const fn slot(&self, slot: SlotId) -> &T {
+        // SAFETY: the index is `0` or `1`, so it's in bounds.
+        unsafe { &*self.buffers.as_ptr().add(slot.index()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slot_is_stable_with_clone_swap() {
+        let mut buffer: DoubleBuffer<u32> = DoubleBuffer::new(1, 2);
+        buffer.swap_with_clone();
+        assert_eq!(buffer.current_slot(), SlotId::FIRST);
+        assert_eq!(*buffer.slot(SlotId::FIRST), 2);
+
+        buffer.swap();
+        assert_eq!(buffer.current_slot(), SlotId::SECOND);
+        assert_eq!(buffer.next_slot(), SlotId::FIRST);
+    }
+}
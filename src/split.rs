@@ -0,0 +1,182 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Formatter};
+use core::ops::{Deref, DerefMut};
+
+use crate::{OddLengthError, SlotId, Swap};
+
+/// Double buffer of slices in a single allocation, whose first half is a value
+/// and second half is the other value.
+///
+/// It's created from a vector or boxed slice of even length, like a frame
+/// decoder output with room for two frames, without copying it. Like
+/// [`DoubleBuffer`](crate::DoubleBuffer), it dereferences to the current half and
+/// mutably dereferences to the next half, and swapping only flips which half is current.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::SplitBuffer;
+/// let mut frame = SplitBuffer::try_from(vec![0u8; 8]).unwrap();
+/// assert_eq!(frame.half_len(), 4);
+///
+/// frame[0] = 1;
+/// assert_eq!(*frame, [0; 4]);
+///
+/// frame.swap();
+/// assert_eq!(*frame, [1, 0, 0, 0]);
+/// ```
+pub struct SplitBuffer<T> {
+    current: SlotId,
+    data: Vec<T>,
+}
+
+impl<T> SplitBuffer<T> {
+    /// Length of each half.
+    #[inline]
+    pub fn half_len(&self) -> usize {
+        self.data.len() / 2
+    }
+
+    /// Publishes the next half, then writes will be over the previous current half.
+    #[inline]
+    pub const fn swap(&mut self) {
+        self.current = self.current.other();
+    }
+
+    /// Slot of the half that is current, the first or second half of the allocation.
+    #[inline]
+    pub const fn current_slot(&self) -> SlotId {
+        self.current
+    }
+
+    /// Gives access to the next half.
+    #[inline]
+    pub fn next(&self) -> &[T] {
+        let (first, second) = self.data.split_at(self.half_len());
+        if self.current == SlotId::FIRST {
+            second
+        } else {
+            first
+        }
+    }
+
+    /// Gives access to the current half and mutable access to the next half at once.
+    #[inline]
+    pub fn split_mut(&mut self) -> (&[T], &mut [T]) {
+        let half_len = self.half_len();
+        let (first, second) = self.data.split_at_mut(half_len);
+        if self.current == SlotId::FIRST {
+            (first, second)
+        } else {
+            (second, first)
+        }
+    }
+
+    /// Returns the whole allocation, with the halves in their slots.
+    #[inline]
+    pub fn into_inner(self) -> Vec<T> {
+        self.data
+    }
+}
+
+impl<T> TryFrom<Vec<T>> for SplitBuffer<T> {
+    type Error = OddLengthError<Vec<T>>;
+
+    /// Splits the vector in two halves, the first one is current.
+    ///
+    /// Returns the vector back in the error if its length is odd.
+    #[inline]
+    fn try_from(data: Vec<T>) -> Result<Self, Self::Error> {
+        if !data.len().is_multiple_of(2) {
+            return Err(OddLengthError::new(data.len(), data));
+        }
+        Ok(Self {
+            current: SlotId::FIRST,
+            data,
+        })
+    }
+}
+
+impl<T> TryFrom<Box<[T]>> for SplitBuffer<T> {
+    type Error = OddLengthError<Box<[T]>>;
+
+    /// Splits the slice in two halves, the first one is current.
+    ///
+    /// Returns the slice back in the error if its length is odd.
+    #[inline]
+    fn try_from(data: Box<[T]>) -> Result<Self, Self::Error> {
+        if !data.len().is_multiple_of(2) {
+            return Err(OddLengthError::new(data.len(), data));
+        }
+        Ok(Self {
+            current: SlotId::FIRST,
+            data: data.into_vec(),
+        })
+    }
+}
+
+impl<T> Swap for SplitBuffer<T> {
+    #[inline]
+    fn swap_buffers(&mut self) {
+        self.swap();
+    }
+}
+
+impl<T: Debug> Debug for SplitBuffer<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SplitBuffer")
+            .field("current", &self.deref())
+            .field("next", &self.next())
+            .finish()
+    }
+}
+
+impl<T> Deref for SplitBuffer<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        let (first, second) = self.data.split_at(self.half_len());
+        if self.current == SlotId::FIRST {
+            first
+        } else {
+            second
+        }
+    }
+}
+
+impl<T> DerefMut for SplitBuffer<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        let (_, next) = self.split_mut();
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_no_copy() {
+        let data = vec![1, 2, 3, 4].into_boxed_slice();
+        let address = data.as_ptr();
+        let mut buffer = SplitBuffer::try_from(data).unwrap();
+        assert_eq!((&*buffer, buffer.next()), (&[1, 2][..], &[3, 4][..]));
+
+        buffer.swap();
+        buffer[1] = 5;
+        assert_eq!(buffer.split_mut(), (&[3, 4][..], &mut [1, 5][..]));
+        let data = buffer.into_inner();
+        assert_eq!(data.as_ptr(), address);
+    }
+
+    #[test]
+    fn test_odd_length() {
+        let error = SplitBuffer::try_from(vec![1, 2, 3]).unwrap_err();
+        assert_eq!(error.into_inner(), [1, 2, 3]);
+    }
+}
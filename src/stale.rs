@@ -0,0 +1,129 @@
+#[cfg(debug_assertions)]
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{DoubleBuffer, Tracked};
+
+/// Counts the reads of the current value and the writes of the next value since
+/// the last swap, when debug assertions are enabled.
+#[cfg(debug_assertions)]
+#[derive(Debug)]
+pub(crate) struct StaleReads {
+    limits: Option<(usize, usize)>,
+    // Only loaded and stored, for targets without atomic read-modify-write.
+    reads: AtomicUsize,
+    writes: usize,
+}
+
+#[cfg(debug_assertions)]
+impl StaleReads {
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        Self {
+            limits: None,
+            reads: AtomicUsize::new(0),
+            writes: 0,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn read(&self) {
+        let Some((max_reads, max_writes)) = self.limits else {
+            return;
+        };
+        let reads = self.reads.load(Ordering::Relaxed).saturating_add(1);
+        self.reads.store(reads, Ordering::Relaxed);
+        assert!(
+            reads <= max_reads || self.writes <= max_writes,
+            "DoubleBuffer current value read {reads} times with {} writes staged since the last swap, is a swap missing?",
+            self.writes,
+        );
+    }
+
+    #[inline]
+    pub(crate) fn write(&mut self) {
+        if self.limits.is_some() {
+            self.writes = self.writes.saturating_add(1);
+        }
+    }
+
+    #[inline]
+    pub(crate) fn reset(&mut self) {
+        *self.reads.get_mut() = 0;
+        self.writes = 0;
+    }
+}
+
+impl<T, S> DoubleBuffer<T, S, Tracked<T>> {
+    /// Panics, when debug assertions are enabled, if the current value is read
+    /// more than `reads` times while more than `writes` mutable accesses to the
+    /// next value pile up without a swap.
+    ///
+    /// It catches forgetting to swap, which otherwise keeps showing the stale
+    /// current value. Reads are counted through [`Deref`](core::ops::Deref),
+    /// [`AsRef`] and [`Borrow`](core::borrow::Borrow), and writes like for
+    /// [`DoubleBuffer::has_pending_changes()`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use double_buffer::TrackedBuffer;
+    /// let mut buffer: TrackedBuffer<u32> = TrackedBuffer::default();
+    /// buffer.detect_stale_reads(2, 2);
+    ///
+    /// for frame in 1..10 {
+    ///     *buffer = frame;
+    ///     // Forgot to swap.
+    ///     println!("{}", *buffer);
+    /// }
+    /// ```
+    #[inline]
+    pub fn detect_stale_reads(&mut self, reads: usize, writes: usize) {
+        #[cfg(debug_assertions)]
+        {
+            self.instrument.stale_reads.limits = Some((reads, writes));
+            self.instrument.stale_reads.reset();
+        }
+        #[cfg(not(debug_assertions))]
+        let _ = (reads, writes);
+    }
+
+    /// Stops detecting stale reads.
+    #[inline]
+    pub fn stop_detecting_stale_reads(&mut self) {
+        #[cfg(debug_assertions)]
+        {
+            self.instrument.stale_reads.limits = None;
+        }
+    }
+}
+
+#[cfg(all(test, debug_assertions))]
+mod tests {
+    use crate::TrackedBuffer;
+
+    #[test]
+    fn test_swap_resets_counts() {
+        let mut buffer: TrackedBuffer<u32> = TrackedBuffer::default();
+        buffer.detect_stale_reads(2, 2);
+
+        for frame in 1..10 {
+            *buffer = frame;
+            *buffer *= 2;
+            assert_eq!(*buffer, (frame - 1) * 2);
+            assert_eq!(*buffer.as_ref(), (frame - 1) * 2);
+            buffer.swap();
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "is a swap missing?")]
+    fn test_missing_swap_panics() {
+        let mut buffer: TrackedBuffer<u32> = TrackedBuffer::default();
+        buffer.detect_stale_reads(2, 2);
+
+        for frame in 1..10 {
+            *buffer = frame;
+            assert_eq!(*buffer, 0);
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{DoubleBuffer, Tracked};
+
+/// Number of swaps of a [`DoubleBuffer`] for each way of swapping.
+///
+/// The counters are since the buffer was created or since the last
+/// call to [`DoubleBuffer::reset_stats()`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SwapStats {
+    /// Swaps moving the values, like [`MoveSwap`](crate::MoveSwap).
+    pub swaps: u64,
+    /// Swaps done with [`DoubleBuffer::swap_with_clone()`].
+    pub swaps_with_clone: u64,
+    /// Swaps done with [`DoubleBuffer::swap_with_default()`].
+    pub swaps_with_default: u64,
+    /// Swaps done with [`DoubleBuffer::swap_with_clear()`].
+    pub swaps_with_clear: u64,
+    /// Swaps done with `DoubleBuffer::swap_with_pool()`.
+    pub swaps_with_pool: u64,
+    /// Swaps done with [`DoubleBuffer::try_swap_with()`] and [`DoubleBuffer::replace_current()`].
+    pub swaps_with: u64,
+    /// Swaps done with [`DoubleBuffer::swap_with_merge()`].
+    pub swaps_with_merge: u64,
+}
+
+/// Snapshot of the counters of a [`DoubleBuffer`], see [`DoubleBuffer::buffer_stats()`].
+///
+/// With the `serde` feature, it can be serialized to report it in any telemetry.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BufferStats {
+    /// Number of swaps for each way of swapping.
+    pub swaps: SwapStats,
+    /// See [`DoubleBuffer::generation()`], it isn't reset.
+    pub generation: u64,
+    /// See [`DoubleBuffer::has_pending_changes()`].
+    pub pending_changes: bool,
+    /// See `DoubleBuffer::last_frame_build_time()`, always `None` without the
+    /// `timing` feature.
+    pub last_frame_build_time: Option<core::time::Duration>,
+    /// See `DoubleBuffer::max_frame_build_time()`, always `None` without the
+    /// `timing` feature, so the fields are the same with any features.
+    pub max_frame_build_time: Option<core::time::Duration>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SwapKind {
+    Move,
+    Clone,
+    Default,
+    Clear,
+    #[cfg_attr(not(feature = "alloc"), allow(dead_code))]
+    Pool,
+    With,
+    Merge,
+}
+
+impl SwapKind {
+    /// Returns `true` if the current value moves to the other physical buffer.
+    #[inline]
+    pub(crate) const fn flips(self) -> bool {
+        !matches!(self, Self::Clone | Self::Merge)
+    }
+}
+
+impl SwapStats {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            swaps: 0,
+            swaps_with_clone: 0,
+            swaps_with_default: 0,
+            swaps_with_clear: 0,
+            swaps_with_pool: 0,
+            swaps_with: 0,
+            swaps_with_merge: 0,
+        }
+    }
+
+    /// Total number of swaps of any way.
+    #[inline]
+    pub const fn total(&self) -> u64 {
+        self.swaps
+            .wrapping_add(self.swaps_with_clone)
+            .wrapping_add(self.swaps_with_default)
+            .wrapping_add(self.swaps_with_clear)
+            .wrapping_add(self.swaps_with_pool)
+            .wrapping_add(self.swaps_with)
+            .wrapping_add(self.swaps_with_merge)
+    }
+
+    #[inline]
+    pub(crate) fn count(&mut self, kind: SwapKind) {
+        let counter = match kind {
+            SwapKind::Move => &mut self.swaps,
+            SwapKind::Clone => &mut self.swaps_with_clone,
+            SwapKind::Default => &mut self.swaps_with_default,
+            SwapKind::Clear => &mut self.swaps_with_clear,
+            SwapKind::Pool => &mut self.swaps_with_pool,
+            SwapKind::With => &mut self.swaps_with,
+            SwapKind::Merge => &mut self.swaps_with_merge,
+        };
+        *counter = counter.wrapping_add(1);
+    }
+}
+
+impl<T, S> DoubleBuffer<T, S, Tracked<T>> {
+    /// Number of swaps since the buffer was created or the stats were reset.
+    ///
+    /// Unlike [`DoubleBuffer::generation()`], it's reset by [`DoubleBuffer::reset_stats()`].
+    #[inline]
+    pub const fn swap_count(&self) -> u64 {
+        self.instrument.stats.total()
+    }
+
+    /// Number of swaps for each way of swapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::TrackedBuffer;
+    /// let mut buffer: TrackedBuffer<u32> = TrackedBuffer::default();
+    /// buffer.swap();
+    /// buffer.swap_with_clone();
+    /// buffer.swap_with_clone();
+    ///
+    /// let stats = buffer.stats();
+    /// assert_eq!(stats.swaps, 1);
+    /// assert_eq!(stats.swaps_with_clone, 2);
+    /// assert_eq!(buffer.swap_count(), 3);
+    ///
+    /// buffer.reset_stats();
+    /// assert_eq!(buffer.swap_count(), 0);
+    /// assert_eq!(buffer.generation(), 3);
+    /// ```
+    #[inline]
+    pub const fn stats(&self) -> SwapStats {
+        self.instrument.stats
+    }
+
+    /// All the counters of the buffer at once.
+    ///
+    /// The counters are since the buffer was created or since the last call to
+    /// [`DoubleBuffer::reset_stats()`], except the generation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::TrackedBuffer;
+    /// let mut buffer: TrackedBuffer<[u8; 16]> = TrackedBuffer::default();
+    /// buffer.swap_with_clone();
+    /// buffer[0] = 1;
+    ///
+    /// let stats = buffer.buffer_stats();
+    /// assert_eq!(stats.swaps.swaps_with_clone, 1);
+    /// assert!(stats.pending_changes);
+    /// ```
+    #[inline]
+    pub fn buffer_stats(&self) -> BufferStats {
+        BufferStats {
+            swaps: self.instrument.stats,
+            generation: self.instrument.generation,
+            pending_changes: self.instrument.dirty,
+            #[cfg(feature = "timing")]
+            last_frame_build_time: self.last_frame_build_time(),
+            #[cfg(not(feature = "timing"))]
+            last_frame_build_time: None,
+            #[cfg(feature = "timing")]
+            max_frame_build_time: Some(self.max_frame_build_time()),
+            #[cfg(not(feature = "timing"))]
+            max_frame_build_time: None,
+        }
+    }
+
+    /// Resets the swap counters, it doesn't change the generation.
+    ///
+    /// With the `timing` feature, it also resets the last and maximum frame build times.
+    #[inline]
+    pub fn reset_stats(&mut self) {
+        self.instrument.stats = SwapStats::new();
+        #[cfg(feature = "timing")]
+        self.instrument.timing.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TrackedBuffer;
+
+    #[test]
+    fn test_stats_per_kind() {
+        let mut buffer: TrackedBuffer<[u8; 2]> = TrackedBuffer::default();
+        buffer.swap();
+        buffer.swap_with_clone();
+        buffer.swap_with_default();
+        buffer.swap_with_clear();
+        buffer.try_swap_with(|| Ok::<_, ()>([1, 1])).unwrap();
+        buffer.try_swap_with(|| Err(())).unwrap_err();
+
+        assert_eq!(
+            buffer.stats(),
+            SwapStats {
+                swaps: 1,
+                swaps_with_clone: 1,
+                swaps_with_default: 1,
+                swaps_with_clear: 1,
+                swaps_with_pool: 0,
+                swaps_with: 1,
+                swaps_with_merge: 0,
+            }
+        );
+        assert_eq!(buffer.swap_count(), 5);
+    }
+
+    #[test]
+    fn test_reset_keeps_generation() {
+        let mut buffer: TrackedBuffer<u32> = TrackedBuffer::default();
+        buffer.swap_with_clone();
+        buffer.swap();
+
+        buffer.reset_stats();
+        let stats = buffer.buffer_stats();
+        assert_eq!(stats.swaps.total(), 0);
+        assert_eq!(stats.generation, 2);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize_buffer_stats() {
+        let mut buffer: TrackedBuffer<u32> = TrackedBuffer::default();
+        buffer.swap_with_clone();
+        let stats = buffer.buffer_stats();
+
+        let bytes = postcard::to_allocvec(&stats).unwrap();
+        assert_eq!(postcard::from_bytes::<BufferStats>(&bytes).unwrap(), stats);
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{Clear, DoubleBuffer, Instrument};
+
+/// Defines how [`DoubleBuffer::swap()`] publishes the next value.
+///
+/// The strategy is a type parameter of [`DoubleBuffer`], so the swap policy is
+/// part of the buffer type and call sites only need to call [`DoubleBuffer::swap()`].
+///
+/// # Examples
+///
+/// A custom strategy can delegate to the swapping methods of the buffer:
+///
+/// ```
+/// # use double_buffer::{DoubleBuffer, Instrument, SwapStrategy};
+/// struct CloneSmallSwap;
+///
+/// impl SwapStrategy<Vec<u8>> for CloneSmallSwap {
+///     fn swap<I: Instrument<Vec<u8>>>(buffer: &mut DoubleBuffer<Vec<u8>, Self, I>) {
+///         buffer.swap_with_clone();
+///     }
+/// }
+///
+/// let mut buffer: DoubleBuffer<Vec<u8>, CloneSmallSwap> = DoubleBuffer::default();
+/// buffer.push(1);
+/// buffer.swap();
+/// assert_eq!(buffer, vec![1]);
+/// assert_eq!(*buffer, vec![1]);
+/// ```
+pub trait SwapStrategy<T>: Sized {
+    /// Publishes the next value of the buffer.
+    fn swap<I: Instrument<T>>(buffer: &mut DoubleBuffer<T, Self, I>);
+}
+
+/// Swaps the current and next values, then writes will be over the previous current value.
+///
+/// This is the default strategy, and the fastest one because it only swaps which value
+/// is the current one, but it changes the pointer address of the current value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct MoveSwap;
+
+impl<T> SwapStrategy<T> for MoveSwap {
+    #[inline]
+    fn swap<I: Instrument<T>>(buffer: &mut DoubleBuffer<T, Self, I>) {
+        buffer.move_swap();
+    }
+}
+
+/// Swaps like [`DoubleBuffer::swap_with_clone()`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CloneSwap;
+
+impl<T: Clone> SwapStrategy<T> for CloneSwap {
+    #[inline]
+    fn swap<I: Instrument<T>>(buffer: &mut DoubleBuffer<T, Self, I>) {
+        buffer.swap_with_clone();
+    }
+}
+
+/// Swaps like [`DoubleBuffer::swap_with_default()`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DefaultSwap;
+
+impl<T: Default> SwapStrategy<T> for DefaultSwap {
+    #[inline]
+    fn swap<I: Instrument<T>>(buffer: &mut DoubleBuffer<T, Self, I>) {
+        buffer.swap_with_default();
+    }
+}
+
+/// Swaps like [`DoubleBuffer::swap_with_clear()`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ClearSwap;
+
+impl<T: Clear> SwapStrategy<T> for ClearSwap {
+    #[inline]
+    fn swap<I: Instrument<T>>(buffer: &mut DoubleBuffer<T, Self, I>) {
+        buffer.swap_with_clear();
+    }
+}
@@ -0,0 +1,246 @@
+use crate::{
+    DoubleBuffer, DoubleBufferWithPrevious, ExplicitDoubleBuffer, HistoryBuffer, Instrument,
+    SwapStrategy,
+};
+
+/// Values holding double buffers that can be swapped at once, like every
+/// component storage of a world at the end of the frame.
+///
+/// With the `derive` feature, it can be derived for structs whose fields are all
+/// [`Swap`], swapping them in order.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{DoubleBuffer, Swap};
+/// struct World {
+///     positions: DoubleBuffer<[f32; 4]>,
+///     healths: [DoubleBuffer<u8>; 2],
+/// }
+///
+/// impl Swap for World {
+///     fn swap_buffers(&mut self) {
+///         self.positions.swap_buffers();
+///         self.healths.swap_buffers();
+///     }
+/// }
+///
+/// let mut world = World { positions: DoubleBuffer::default(), healths: Default::default() };
+/// *world.healths[1] = 100;
+/// world.swap_buffers();
+/// assert_eq!(world.healths[1], 100);
+/// ```
+pub trait Swap {
+    fn swap_buffers(&mut self);
+}
+
+impl<T, S: SwapStrategy<T>, I: Instrument<T>> Swap for DoubleBuffer<T, S, I> {
+    #[inline]
+    fn swap_buffers(&mut self) {
+        self.swap();
+    }
+}
+
+impl<T, S: SwapStrategy<T>, I: Instrument<T>> Swap for ExplicitDoubleBuffer<T, S, I> {
+    #[inline]
+    fn swap_buffers(&mut self) {
+        self.swap();
+    }
+}
+
+impl<T, const DEPTH: usize> Swap for HistoryBuffer<T, DEPTH> {
+    #[inline]
+    fn swap_buffers(&mut self) {
+        self.swap();
+    }
+}
+
+impl<T> Swap for DoubleBufferWithPrevious<T> {
+    #[inline]
+    fn swap_buffers(&mut self) {
+        self.swap();
+    }
+}
+
+impl<T: Swap> Swap for [T] {
+    #[inline]
+    fn swap_buffers(&mut self) {
+        self.iter_mut().for_each(Swap::swap_buffers);
+    }
+}
+
+impl<T: Swap, const N: usize> Swap for [T; N] {
+    #[inline]
+    fn swap_buffers(&mut self) {
+        self.as_mut_slice().swap_buffers();
+    }
+}
+
+impl<T: Swap + ?Sized> Swap for &mut T {
+    #[inline]
+    fn swap_buffers(&mut self) {
+        (**self).swap_buffers();
+    }
+}
+
+macro_rules! impl_swap_for_tuples {
+    ($(($($name:ident),+)),+) => {
+        $(
+            impl<$($name: Swap),+> Swap for ($($name,)+) {
+                #[inline]
+                #[allow(non_snake_case)]
+                fn swap_buffers(&mut self) {
+                    let ($($name,)+) = self;
+                    $($name.swap_buffers();)+
+                }
+            }
+        )+
+    };
+}
+
+impl_swap_for_tuples!(
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F),
+    (A, B, C, D, E, F, G),
+    (A, B, C, D, E, F, G, H)
+);
+
+#[cfg(feature = "alloc")]
+mod group {
+    use alloc::boxed::Box;
+    use alloc::rc::Rc;
+    use alloc::vec::Vec;
+    use core::cell::RefCell;
+
+    use super::Swap;
+
+    impl<T: Swap + ?Sized> Swap for Box<T> {
+        #[inline]
+        fn swap_buffers(&mut self) {
+            (**self).swap_buffers();
+        }
+    }
+
+    /// Swaps the value shared with other owners.
+    ///
+    /// It panics if the value is borrowed, like [`RefCell::borrow_mut()`].
+    impl<T: Swap + ?Sized> Swap for Rc<RefCell<T>> {
+        #[inline]
+        fn swap_buffers(&mut self) {
+            self.borrow_mut().swap_buffers();
+        }
+    }
+
+    /// Group of heterogeneous buffers swapped at once with [`SwapGroup::swap_all()`],
+    /// so none of them is forgotten at the end of the frame.
+    ///
+    /// It holds any [`Swap`] value: owned buffers, mutable borrows for the lifetime
+    /// of the group, or buffers shared with `Rc<RefCell<_>>` with the subsystems
+    /// writing them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::{cell::RefCell, rc::Rc};
+    /// # use double_buffer::{DoubleBuffer, SwapGroup};
+    /// let positions: Rc<RefCell<DoubleBuffer<[f32; 2]>>> = Rc::default();
+    /// let score: Rc<RefCell<DoubleBuffer<u32>>> = Rc::default();
+    ///
+    /// let mut frame = SwapGroup::new();
+    /// frame.register(positions.clone());
+    /// frame.register(score.clone());
+    ///
+    /// **score.borrow_mut() = 10;
+    /// frame.swap_all();
+    /// assert_eq!(*score.borrow(), 10);
+    /// ```
+    #[derive(Default)]
+    pub struct SwapGroup<'a> {
+        members: Vec<Box<dyn Swap + 'a>>,
+    }
+
+    impl<'a> SwapGroup<'a> {
+        #[inline]
+        pub const fn new() -> Self {
+            Self {
+                members: Vec::new(),
+            }
+        }
+
+        /// Adds a buffer to the group.
+        #[inline]
+        pub fn register(&mut self, buffer: impl Swap + 'a) {
+            self.members.push(Box::new(buffer));
+        }
+
+        /// Swaps all the buffers, in the order they were registered.
+        #[inline]
+        pub fn swap_all(&mut self) {
+            self.members.swap_buffers();
+        }
+
+        #[inline]
+        pub fn len(&self) -> usize {
+            self.members.len()
+        }
+
+        #[inline]
+        pub fn is_empty(&self) -> bool {
+            self.members.is_empty()
+        }
+    }
+
+    impl Swap for SwapGroup<'_> {
+        #[inline]
+        fn swap_buffers(&mut self) {
+            self.swap_all();
+        }
+    }
+
+    impl core::fmt::Debug for SwapGroup<'_> {
+        #[inline]
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_struct("SwapGroup")
+                .field("len", &self.len())
+                .finish()
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub use group::SwapGroup;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap_tuple() {
+        let mut buffers: (DoubleBuffer<u8>, HistoryBuffer<u8, 2>) = Default::default();
+        *buffers.0 = 1;
+        *buffers.1 = 2;
+        buffers.swap_buffers();
+        assert_eq!((*buffers.0, *buffers.1), (1, 2));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_swap_group_with_borrows() {
+        let mut first: DoubleBuffer<u8> = DoubleBuffer::default();
+        let mut second: DoubleBuffer<u8> = DoubleBuffer::default();
+        *first = 1;
+        *second = 2;
+        {
+            let mut group = SwapGroup::new();
+            group.register(&mut first);
+            group.register(&mut second);
+            group.swap_all();
+            assert_eq!(group.len(), 2);
+        }
+        assert_eq!((*first, *second), (1, 2));
+    }
+}
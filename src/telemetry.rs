@@ -0,0 +1,125 @@
+use metrics::{counter, histogram, Counter, Histogram};
+
+use crate::{DoubleBuffer, Tracked};
+
+/// Handles of the metrics of a buffer, registered in the global recorder.
+#[derive(Debug)]
+pub(crate) struct BufferMetrics {
+    swaps: Counter,
+    staged_writes: Histogram,
+    writes: u64,
+}
+
+impl BufferMetrics {
+    #[inline]
+    pub(crate) fn write(&mut self) {
+        self.writes = self.writes.saturating_add(1);
+    }
+
+    #[inline]
+    pub(crate) fn published(&mut self) {
+        self.swaps.increment(1);
+        self.staged_writes.record(self.writes as f64);
+        self.writes = 0;
+    }
+}
+
+impl<T, S> DoubleBuffer<T, S, Tracked<T>> {
+    /// Reports the health of the buffer to the [`metrics`] recorder, labeled with
+    /// `buffer` as `name`:
+    ///
+    /// - `double_buffer_swaps_total` - counter of swaps, to get the swaps per second.
+    /// - `double_buffer_staged_writes` - histogram of the mutable accesses to the
+    ///   next value before each swap, see [`DoubleBuffer::has_pending_changes()`].
+    ///
+    /// The metrics are registered when it's called, so the recorder must be
+    /// installed before.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::{DoubleBuffer, TrackedBuffer};
+    /// let mut frame: TrackedBuffer<[u8; 1024]> = DoubleBuffer::tracked([0; 1024], [0; 1024]);
+    /// frame.set_metrics("frame");
+    ///
+    /// frame[0] = 1;
+    /// frame.swap_with_clone();
+    /// ```
+    #[inline]
+    pub fn set_metrics(&mut self, name: &'static str) {
+        self.instrument.metrics = Some(BufferMetrics {
+            swaps: counter!("double_buffer_swaps_total", "buffer" => name),
+            staged_writes: histogram!("double_buffer_staged_writes", "buffer" => name),
+            writes: 0,
+        });
+    }
+
+    /// Stops reporting metrics.
+    #[inline]
+    pub fn remove_metrics(&mut self) {
+        self.instrument.metrics = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TrackedBuffer;
+    use metrics::{Gauge, Key, KeyName, Metadata, Recorder, SharedString, Unit};
+    use std::borrow::ToOwned;
+    use std::string::String;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::vec::Vec;
+
+    #[derive(Default)]
+    struct CounterRecorder {
+        counters: Mutex<Vec<(String, Arc<AtomicU64>)>>,
+    }
+
+    impl CounterRecorder {
+        fn get(&self, name: &str) -> u64 {
+            let counters = self.counters.lock().unwrap();
+            let (_, counter) = counters.iter().find(|(key, _)| key == name).unwrap();
+            counter.load(Ordering::Relaxed)
+        }
+    }
+
+    impl Recorder for CounterRecorder {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+            let counter = Arc::new(AtomicU64::new(0));
+            let name = key.name().to_owned();
+            self.counters
+                .lock()
+                .unwrap()
+                .push((name, Arc::clone(&counter)));
+            Counter::from_arc(counter)
+        }
+
+        fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+            Gauge::noop()
+        }
+
+        fn register_histogram(&self, _: &Key, _: &Metadata<'_>) -> Histogram {
+            Histogram::noop()
+        }
+    }
+
+    #[test]
+    fn test_counts_swaps() {
+        let recorder = CounterRecorder::default();
+        let mut buffer: TrackedBuffer<[u8; 16]> = TrackedBuffer::default();
+        metrics::with_local_recorder(&recorder, || buffer.set_metrics("test"));
+
+        buffer.swap();
+        buffer.swap_with_clone();
+
+        assert_eq!(recorder.get("double_buffer_swaps_total"), 2);
+    }
+}
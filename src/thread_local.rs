@@ -0,0 +1,187 @@
+use std::cell::OnceCell;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, Weak};
+use std::thread::LocalKey;
+use std::vec::Vec;
+
+use crate::DoubleBuffer;
+
+/// Declares a `static` [`ThreadLocalBuffer`], giving each thread its own
+/// [`DoubleBuffer`] created from the initializer expression.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::thread_local_double_buffer;
+/// thread_local_double_buffer! {
+///     static REQUESTS: u64 = 0;
+/// }
+///
+/// REQUESTS.with(|requests| **requests += 1);
+/// ```
+#[macro_export]
+macro_rules! thread_local_double_buffer {
+    ($(#[$attr:meta])* $vis:vis static $name:ident: $t:ty = $init:expr;) => {
+        $(#[$attr])*
+        $vis static $name: $crate::ThreadLocalBuffer<$t> = {
+            ::std::thread_local! {
+                static LOCAL: $crate::ThreadSlot<$t> = const { $crate::ThreadSlot::new() };
+            }
+
+            fn init() -> $t {
+                $init
+            }
+
+            $crate::ThreadLocalBuffer::new(&LOCAL, init)
+        };
+    };
+}
+
+/// Double buffer of each thread, declared with [`thread_local_double_buffer!`].
+///
+/// Each thread stages and publishes its own [`DoubleBuffer`] through
+/// [`ThreadLocalBuffer::with()`], created on its first access, and
+/// [`ThreadLocalBuffer::collect()`] visits the current values of every thread,
+/// like per-thread metrics or scratch frames merged once per tick.
+///
+/// The buffer of a thread is dropped when the thread exits, so it isn't visited
+/// anymore.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::thread_local_double_buffer;
+/// # use std::sync::Barrier;
+/// # use std::thread;
+/// thread_local_double_buffer! {
+///     static HITS: u32 = 0;
+/// }
+///
+/// let (staged, merged) = (Barrier::new(4), Barrier::new(4));
+/// thread::scope(|scope| {
+///     for hits in 1..=3 {
+///         let (staged, merged) = (&staged, &merged);
+///         scope.spawn(move || {
+///             HITS.with(|buffer| **buffer = hits);
+///             staged.wait();
+///             // The buffer is visited while the thread is alive.
+///             merged.wait();
+///         });
+///     }
+///
+///     staged.wait();
+///     HITS.swap_all();
+///     let mut total = 0;
+///     HITS.collect(|hits| total += hits);
+///     merged.wait();
+///
+///     assert_eq!(total, 6);
+/// });
+/// ```
+pub struct ThreadLocalBuffer<T: 'static> {
+    local: &'static LocalKey<ThreadSlot<T>>,
+    init: fn() -> T,
+    threads: Mutex<Vec<Weak<Mutex<DoubleBuffer<T>>>>>,
+}
+
+impl<T: Send + 'static> ThreadLocalBuffer<T> {
+    /// Creates the buffers of threads in `local` with `init`, prefer
+    /// [`thread_local_double_buffer!`].
+    #[inline]
+    pub const fn new(local: &'static LocalKey<ThreadSlot<T>>, init: fn() -> T) -> Self {
+        Self {
+            local,
+            init,
+            threads: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Gives mutable access to the buffer of the calling thread, creating it on
+    /// the first call.
+    ///
+    /// # Panics
+    ///
+    /// If it's called inside `f`, or during the destruction of thread locals.
+    pub fn with<R>(&self, f: impl FnOnce(&mut DoubleBuffer<T>) -> R) -> R {
+        self.local.with(|slot| {
+            let buffer = slot.buffer.get_or_init(|| {
+                let buffer = Arc::new(Mutex::new(DoubleBuffer::new((self.init)(), (self.init)())));
+                let mut threads = self.threads();
+                threads.retain(|thread| thread.strong_count() > 0);
+                threads.push(Arc::downgrade(&buffer));
+                buffer
+            });
+            f(&mut buffer.lock().unwrap_or_else(PoisonError::into_inner))
+        })
+    }
+
+    /// Calls `f` with the current value of every thread, waiting for threads
+    /// inside [`ThreadLocalBuffer::with()`].
+    pub fn collect(&self, mut f: impl FnMut(&T)) {
+        for buffer in self.live() {
+            f(&buffer.lock().unwrap_or_else(PoisonError::into_inner));
+        }
+    }
+
+    /// Swaps the buffer of every thread, publishing what they staged, like a tick
+    /// before [`ThreadLocalBuffer::collect()`].
+    pub fn swap_all(&self) {
+        for buffer in self.live() {
+            buffer.lock().unwrap_or_else(PoisonError::into_inner).swap();
+        }
+    }
+
+    #[inline]
+    fn threads(&self) -> MutexGuard<'_, Vec<Weak<Mutex<DoubleBuffer<T>>>>> {
+        self.threads.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Buffers of the threads alive, cloned to not hold the lock while they're visited.
+    fn live(&self) -> Vec<Arc<Mutex<DoubleBuffer<T>>>> {
+        self.threads().iter().filter_map(Weak::upgrade).collect()
+    }
+}
+
+/// Buffer of a thread of a [`ThreadLocalBuffer`], used by [`thread_local_double_buffer!`].
+#[doc(hidden)]
+pub struct ThreadSlot<T> {
+    buffer: OnceCell<Arc<Mutex<DoubleBuffer<T>>>>,
+}
+
+impl<T> ThreadSlot<T> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            buffer: OnceCell::new(),
+        }
+    }
+}
+
+impl<T> Default for ThreadSlot<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::vec::Vec;
+
+    thread_local_double_buffer! {
+        static FRAMES: u32 = 0;
+    }
+
+    #[test]
+    fn test_exited_threads_not_visited() {
+        FRAMES.with(|frames| **frames = 1);
+        thread::spawn(|| FRAMES.with(|frames| **frames = 2))
+            .join()
+            .unwrap();
+
+        FRAMES.swap_all();
+        let mut visited = Vec::new();
+        FRAMES.collect(|frames| visited.push(*frames));
+        assert_eq!(visited, [1]);
+    }
+}
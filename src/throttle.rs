@@ -0,0 +1,178 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::{Clock, DoubleBuffer, MoveSwap, SwapStrategy};
+
+/// Wraps a [`DoubleBuffer`] that is published at most once every minimum interval,
+/// measured with a [`Clock`].
+///
+/// [`Throttled::swap_throttled()`] can be called as often as the producer runs,
+/// it only swaps when the interval has passed since the last swap, so consumers
+/// aren't flooded with values. The writes that weren't published stay in the
+/// next value until a later swap.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{Clock, DoubleBuffer, Throttled};
+/// # use core::cell::Cell;
+/// # struct ManualClock(Cell<u32>);
+/// # impl Clock for ManualClock {
+/// #     type Instant = u32;
+/// #     type Duration = u32;
+/// #     fn now(&self) -> u32 { self.0.get() }
+/// #     fn elapsed(&self, since: u32) -> u32 { self.now() - since }
+/// # }
+/// let clock = ManualClock(Cell::new(0));
+/// let mut buffer = Throttled::new(DoubleBuffer::<u32>::default(), &clock, 10);
+///
+/// *buffer = 1;
+/// assert!(buffer.swap_throttled());
+///
+/// clock.0.set(4);
+/// *buffer = 2;
+/// assert!(!buffer.swap_throttled());
+/// assert_eq!(*buffer, 1);
+///
+/// clock.0.set(10);
+/// assert!(buffer.swap_throttled());
+/// assert_eq!(*buffer, 2);
+/// ```
+pub struct Throttled<T, C: Clock, S = MoveSwap> {
+    buffer: DoubleBuffer<T, S>,
+    clock: C,
+    min_interval: C::Duration,
+    last_publish: Option<C::Instant>,
+}
+
+impl<T, C: Clock, S> Throttled<T, C, S> {
+    #[inline]
+    pub const fn new(buffer: DoubleBuffer<T, S>, clock: C, min_interval: C::Duration) -> Self {
+        Self {
+            buffer,
+            clock,
+            min_interval,
+            last_publish: None,
+        }
+    }
+
+    #[inline]
+    pub const fn buffer(&self) -> &DoubleBuffer<T, S> {
+        &self.buffer
+    }
+
+    #[inline]
+    pub const fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    #[inline]
+    pub const fn min_interval(&self) -> &C::Duration {
+        &self.min_interval
+    }
+
+    #[inline]
+    pub fn set_min_interval(&mut self, min_interval: C::Duration) {
+        self.min_interval = min_interval;
+    }
+
+    /// Time of the last swap, or `None` if it was never swapped.
+    #[inline]
+    pub fn last_publish_time(&self) -> Option<C::Instant> {
+        self.last_publish
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> DoubleBuffer<T, S> {
+        self.buffer
+    }
+}
+
+impl<T, C: Clock, S> Throttled<T, C, S>
+where
+    C::Duration: PartialOrd,
+{
+    /// Whether the minimum interval has passed since the last swap,
+    /// so [`Throttled::swap_throttled()`] would swap.
+    #[inline]
+    pub fn can_swap(&self) -> bool {
+        self.last_publish
+            .is_none_or(|last_publish| self.clock.elapsed(last_publish) >= self.min_interval)
+    }
+}
+
+impl<T, C: Clock, S: SwapStrategy<T>> Throttled<T, C, S>
+where
+    C::Duration: PartialOrd,
+{
+    /// Swaps like [`DoubleBuffer::swap()`] if the minimum interval has passed since
+    /// the last swap, returning whether it was swapped.
+    ///
+    /// The first call always swaps.
+    #[inline]
+    pub fn swap_throttled(&mut self) -> bool {
+        if !self.can_swap() {
+            return false;
+        }
+        self.buffer.swap();
+        self.last_publish = Some(self.clock.now());
+        true
+    }
+}
+
+impl<T, C: Clock, S> Deref for Throttled<T, C, S> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+impl<T, C: Clock, S> DerefMut for Throttled<T, C, S> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CloneSwap;
+    use core::cell::Cell;
+
+    struct ManualClock(Cell<u32>);
+
+    impl Clock for ManualClock {
+        type Instant = u32;
+        type Duration = u32;
+
+        fn now(&self) -> u32 {
+            self.0.get()
+        }
+
+        fn elapsed(&self, since: u32) -> u32 {
+            self.now() - since
+        }
+    }
+
+    #[test]
+    fn test_interval_from_last_swap() {
+        let clock = ManualClock(Cell::new(0));
+        let buffer: DoubleBuffer<u32, CloneSwap> = DoubleBuffer::default();
+        let mut buffer = Throttled::new(buffer, &clock, 5);
+        assert!(buffer.swap_throttled());
+
+        clock.0.set(3);
+        assert!(!buffer.swap_throttled());
+        clock.0.set(7);
+        assert!(buffer.swap_throttled());
+        assert_eq!(buffer.last_publish_time(), Some(7));
+
+        clock.0.set(11);
+        assert!(!buffer.can_swap());
+        buffer.set_min_interval(4);
+        assert!(buffer.swap_throttled());
+        assert_eq!(buffer.last_publish_time(), Some(11));
+    }
+}
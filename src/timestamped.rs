@@ -0,0 +1,177 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::{Clear, Clock, DoubleBuffer, MoveSwap, SwapStrategy};
+
+/// Wraps a [`DoubleBuffer`] recording the time of every swap with a [`Clock`].
+///
+/// Like [`DoubleBuffer`], it dereferences to the current value and mutably
+/// dereferences to the next value. All swaps must go through the wrapper,
+/// so the inner buffer is only accessible immutably with [`Timestamped::buffer()`].
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{Clock, DoubleBuffer, Timestamped};
+/// # struct FixedClock;
+/// # impl Clock for FixedClock {
+/// #     type Instant = u32;
+/// #     type Duration = u32;
+/// #     fn now(&self) -> u32 { 10 }
+/// #     fn elapsed(&self, since: u32) -> u32 { 10 - since }
+/// # }
+/// let mut buffer = Timestamped::new(DoubleBuffer::<u32>::default(), FixedClock);
+/// assert_eq!(buffer.last_publish_time(), None);
+///
+/// *buffer = 1;
+/// buffer.swap();
+/// assert_eq!(*buffer, 1);
+/// assert_eq!(buffer.last_publish_time(), Some(10));
+/// assert_eq!(buffer.time_since_publish(), Some(0));
+/// ```
+pub struct Timestamped<T, C: Clock, S = MoveSwap> {
+    buffer: DoubleBuffer<T, S>,
+    clock: C,
+    last_publish: Option<C::Instant>,
+}
+
+impl<T, C: Clock, S> Timestamped<T, C, S> {
+    #[inline]
+    pub const fn new(buffer: DoubleBuffer<T, S>, clock: C) -> Self {
+        Self {
+            buffer,
+            clock,
+            last_publish: None,
+        }
+    }
+
+    #[inline]
+    pub const fn buffer(&self) -> &DoubleBuffer<T, S> {
+        &self.buffer
+    }
+
+    #[inline]
+    pub const fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    /// Time of the last swap, or `None` if it was never swapped.
+    #[inline]
+    pub fn last_publish_time(&self) -> Option<C::Instant> {
+        self.last_publish
+    }
+
+    /// Time elapsed since the last swap, or `None` if it was never swapped.
+    #[inline]
+    pub fn time_since_publish(&self) -> Option<C::Duration> {
+        self.last_publish
+            .map(|last_publish| self.clock.elapsed(last_publish))
+    }
+
+    /// Swaps like [`DoubleBuffer::try_swap_with()`] and records the time if it was swapped.
+    #[inline]
+    pub fn try_swap_with<E>(&mut self, reset: impl FnOnce() -> Result<T, E>) -> Result<(), E> {
+        self.buffer.try_swap_with(reset)?;
+        self.record_publish();
+        Ok(())
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> DoubleBuffer<T, S> {
+        self.buffer
+    }
+
+    #[inline]
+    fn record_publish(&mut self) {
+        self.last_publish = Some(self.clock.now());
+    }
+}
+
+impl<T, C: Clock, S: SwapStrategy<T>> Timestamped<T, C, S> {
+    /// Swaps like [`DoubleBuffer::swap()`] and records the time.
+    #[inline]
+    pub fn swap(&mut self) {
+        self.buffer.swap();
+        self.record_publish();
+    }
+}
+
+impl<T: Clone, C: Clock, S> Timestamped<T, C, S> {
+    /// Swaps like [`DoubleBuffer::swap_with_clone()`] and records the time.
+    #[inline]
+    pub fn swap_with_clone(&mut self) {
+        self.buffer.swap_with_clone();
+        self.record_publish();
+    }
+}
+
+impl<T: Default, C: Clock, S> Timestamped<T, C, S> {
+    /// Swaps like [`DoubleBuffer::swap_with_default()`] and records the time.
+    #[inline]
+    pub fn swap_with_default(&mut self) {
+        self.buffer.swap_with_default();
+        self.record_publish();
+    }
+}
+
+impl<T: Clear, C: Clock, S> Timestamped<T, C, S> {
+    /// Swaps like [`DoubleBuffer::swap_with_clear()`] and records the time.
+    #[inline]
+    pub fn swap_with_clear(&mut self) {
+        self.buffer.swap_with_clear();
+        self.record_publish();
+    }
+}
+
+impl<T, C: Clock, S> Deref for Timestamped<T, C, S> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+impl<T, C: Clock, S> DerefMut for Timestamped<T, C, S> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    struct ManualClock(Cell<u32>);
+
+    impl Clock for ManualClock {
+        type Instant = u32;
+        type Duration = u32;
+
+        fn now(&self) -> u32 {
+            self.0.get()
+        }
+
+        fn elapsed(&self, since: u32) -> u32 {
+            self.now() - since
+        }
+    }
+
+    #[test]
+    fn test_time_since_publish() {
+        let clock = ManualClock(Cell::new(5));
+        let mut buffer = Timestamped::new(DoubleBuffer::<u32>::default(), &clock);
+
+        buffer.swap_with_clone();
+        clock.0.set(8);
+        assert_eq!(buffer.last_publish_time(), Some(5));
+        assert_eq!(buffer.time_since_publish(), Some(3));
+
+        buffer.try_swap_with(|| Err(())).unwrap_err();
+        assert_eq!(buffer.last_publish_time(), Some(5));
+
+        buffer.swap_with_default();
+        assert_eq!(buffer.time_since_publish(), Some(0));
+    }
+}
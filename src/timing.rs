@@ -0,0 +1,134 @@
+use std::time::{Duration, Instant};
+
+use crate::{DoubleBuffer, Tracked};
+
+/// Number of frames of [`DoubleBuffer::max_frame_build_time()`].
+pub const FRAME_WINDOW: usize = 32;
+
+/// Time between the first write into the next value and its publish.
+pub(crate) struct FrameTiming {
+    first_write: Option<Instant>,
+    last: Option<Duration>,
+    // Build times of the last frames, zero for the frames not built yet.
+    window: [Duration; FRAME_WINDOW],
+    position: usize,
+}
+
+impl FrameTiming {
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        Self {
+            first_write: None,
+            last: None,
+            window: [Duration::ZERO; FRAME_WINDOW],
+            position: 0,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn first_write(&mut self) {
+        self.first_write = Some(Instant::now());
+    }
+
+    #[inline]
+    pub(crate) fn published(&mut self) {
+        if let Some(first_write) = self.first_write.take() {
+            let build_time = first_write.elapsed();
+            self.last = Some(build_time);
+            self.window[self.position] = build_time;
+            self.position = (self.position + 1) % FRAME_WINDOW;
+        }
+    }
+
+    #[inline]
+    pub(crate) fn max(&self) -> Duration {
+        self.window.iter().copied().max().unwrap_or(Duration::ZERO)
+    }
+
+    #[inline]
+    pub(crate) fn reset(&mut self) {
+        // The frame being built, if any, is still measured.
+        *self = Self {
+            first_write: self.first_write,
+            ..Self::new()
+        };
+    }
+}
+
+impl<T, S> DoubleBuffer<T, S, Tracked<T>> {
+    /// Time from the first mutable access to the next value until it was published,
+    /// for the last frame with any write.
+    ///
+    /// Returns `None` if no frame was written and published yet, or since the stats were reset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::TrackedBuffer;
+    /// # use std::time::Duration;
+    /// let mut buffer: TrackedBuffer<u32> = TrackedBuffer::default();
+    /// assert_eq!(buffer.last_frame_build_time(), None);
+    ///
+    /// *buffer = 1;
+    /// std::thread::sleep(Duration::from_millis(1));
+    /// buffer.swap();
+    ///
+    /// assert!(buffer.last_frame_build_time().unwrap() >= Duration::from_millis(1));
+    /// ```
+    #[inline]
+    pub fn last_frame_build_time(&self) -> Option<Duration> {
+        self.instrument.timing.last
+    }
+
+    /// Longest frame build time of the last [`FRAME_WINDOW`] frames with any
+    /// write, or since the stats were reset, so a slow frame doesn't hide the
+    /// later ones once it leaves the window.
+    ///
+    /// See [`DoubleBuffer::last_frame_build_time()`].
+    #[inline]
+    pub fn max_frame_build_time(&self) -> Duration {
+        self.instrument.timing.max()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TrackedBuffer;
+
+    #[test]
+    fn test_frame_without_writes_is_not_measured() {
+        let mut buffer: TrackedBuffer<u32> = TrackedBuffer::default();
+        buffer.swap();
+        assert_eq!(buffer.last_frame_build_time(), None);
+
+        *buffer = 1;
+        std::thread::sleep(Duration::from_millis(2));
+        buffer.swap();
+        let build_time = buffer.last_frame_build_time().unwrap();
+        assert!(build_time >= Duration::from_millis(2));
+
+        buffer.swap();
+        assert_eq!(buffer.last_frame_build_time(), Some(build_time));
+        assert_eq!(buffer.max_frame_build_time(), build_time);
+
+        buffer.reset_stats();
+        assert_eq!(buffer.max_frame_build_time(), Duration::ZERO);
+        assert_eq!(buffer.last_frame_build_time(), None);
+        assert_eq!(buffer.instrument.timing.position, 0);
+    }
+
+    #[test]
+    fn test_max_is_windowed() {
+        let mut timing = FrameTiming::new();
+        timing.first_write = Some(Instant::now() - Duration::from_secs(1));
+        timing.published();
+        assert!(timing.max() >= Duration::from_secs(1));
+
+        for _ in 0..FRAME_WINDOW {
+            timing.first_write();
+            timing.published();
+        }
+        assert!(timing.max() < Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,258 @@
+use crate::hooks::{ChecksumFn, Invariant, OnRetire, OnSwap};
+use crate::{DoubleBuffer, MoveSwap, SlotId, SwapKind, SwapStats};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Instrumentation of a [`DoubleBuffer`], its third type parameter.
+///
+/// It's implemented by `()`, the default, which takes no space in the buffer and
+/// tracks nothing, and by [`Tracked`].
+pub trait Instrument<T>: sealed::Sealed {
+    #[doc(hidden)]
+    const NEW: Self;
+
+    #[doc(hidden)]
+    fn tracked(&self) -> Option<&Tracked<T>>;
+
+    #[doc(hidden)]
+    fn tracked_mut(&mut self) -> Option<&mut Tracked<T>>;
+}
+
+/// Instrumentation of a [`TrackedBuffer`], counting the generations, pending
+/// changes and swaps, and running the hooks, invariant and checksum of the buffer.
+///
+/// The timing, metrics, stale reads and frame lifecycle are tracked here too,
+/// with their features and in debug builds.
+pub struct Tracked<T> {
+    pub(crate) dirty: bool,
+    pub(crate) poisoned: bool,
+    pub(crate) generation: u64,
+    pub(crate) stats: SwapStats,
+    #[cfg(feature = "timing")]
+    pub(crate) timing: crate::timing::FrameTiming,
+    pub(crate) on_swap: Option<OnSwap>,
+    pub(crate) on_retire: Option<OnRetire>,
+    pub(crate) invariant: Option<Invariant<T>>,
+    pub(crate) checksum: Option<ChecksumFn<T>>,
+    pub(crate) last_checksum: Option<(u64, u64)>,
+    #[cfg(debug_assertions)]
+    pub(crate) stale_reads: crate::stale::StaleReads,
+    #[cfg(debug_assertions)]
+    pub(crate) lifecycle: crate::lifecycle::FrameLifecycle,
+    #[cfg(feature = "metrics")]
+    pub(crate) metrics: Option<crate::telemetry::BufferMetrics>,
+}
+
+/// [`DoubleBuffer`] with the [`Tracked`] instrumentation, for the generation,
+/// stats, hooks and checks of the buffer.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{DoubleBuffer, TrackedBuffer};
+/// let mut buffer: TrackedBuffer<u32> = DoubleBuffer::tracked(1, 2);
+/// assert!(!buffer.has_pending_changes());
+///
+/// *buffer = 3;
+/// buffer.swap();
+/// assert_eq!(buffer.generation(), 1);
+/// ```
+pub type TrackedBuffer<T, S = MoveSwap> = DoubleBuffer<T, S, Tracked<T>>;
+
+impl sealed::Sealed for () {}
+
+impl<T> Instrument<T> for () {
+    const NEW: Self = ();
+
+    #[inline]
+    fn tracked(&self) -> Option<&Tracked<T>> {
+        None
+    }
+
+    #[inline]
+    fn tracked_mut(&mut self) -> Option<&mut Tracked<T>> {
+        None
+    }
+}
+
+impl<T> sealed::Sealed for Tracked<T> {}
+
+impl<T> Instrument<T> for Tracked<T> {
+    const NEW: Self = Self {
+        dirty: false,
+        poisoned: false,
+        generation: 0,
+        stats: SwapStats::new(),
+        #[cfg(feature = "timing")]
+        timing: crate::timing::FrameTiming::new(),
+        on_swap: None,
+        on_retire: None,
+        invariant: None,
+        checksum: None,
+        last_checksum: None,
+        #[cfg(debug_assertions)]
+        stale_reads: crate::stale::StaleReads::new(),
+        #[cfg(debug_assertions)]
+        lifecycle: crate::lifecycle::FrameLifecycle::Unused,
+        #[cfg(feature = "metrics")]
+        metrics: None,
+    };
+
+    #[inline]
+    fn tracked(&self) -> Option<&Tracked<T>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn tracked_mut(&mut self) -> Option<&mut Tracked<T>> {
+        Some(self)
+    }
+}
+
+impl<T> Tracked<T> {
+    #[inline]
+    pub(crate) fn read(&self) {
+        #[cfg(debug_assertions)]
+        self.stale_reads.read();
+    }
+
+    #[inline]
+    pub(crate) fn write(&mut self) {
+        #[cfg(feature = "timing")]
+        if !self.dirty {
+            self.timing.first_write();
+        }
+        self.dirty = true;
+        #[cfg(debug_assertions)]
+        self.stale_reads.write();
+        #[cfg(debug_assertions)]
+        self.lifecycle.write();
+        #[cfg(feature = "metrics")]
+        if let Some(metrics) = &mut self.metrics {
+            metrics.write();
+        }
+    }
+
+    #[inline]
+    pub(crate) fn published(&mut self, kind: SwapKind, current: &T, current_slot: SlotId) {
+        #[cfg(feature = "timing")]
+        self.timing.published();
+        #[cfg(feature = "log")]
+        if !self.dirty && kind.flips() {
+            log::warn!(
+                "DoubleBuffer generation {} published without writes to the next value",
+                self.generation.wrapping_add(1),
+            );
+        }
+        self.dirty = false;
+        #[cfg(debug_assertions)]
+        self.stale_reads.reset();
+        #[cfg(debug_assertions)]
+        self.lifecycle.published();
+        self.generation = self.generation.wrapping_add(1);
+        self.stats.count(kind);
+        #[cfg(feature = "metrics")]
+        if let Some(metrics) = &mut self.metrics {
+            metrics.published();
+        }
+        #[cfg(feature = "log")]
+        log::debug!(
+            "DoubleBuffer generation {} published with a {kind:?} swap",
+            self.generation,
+        );
+        if let Some(checksum) = &self.checksum {
+            self.last_checksum = Some((self.generation, checksum.call(current)));
+        }
+        if let Some(on_swap) = self.on_swap.as_mut() {
+            on_swap.call(self.generation, current_slot.index());
+        }
+        let retired = if kind.flips() {
+            current_slot.other()
+        } else {
+            current_slot
+        };
+        if let Some(on_retire) = self.on_retire.as_mut() {
+            on_retire.call(self.generation.wrapping_sub(1), retired);
+        }
+    }
+
+    /// Returns `true` if there is no invariant or the value holds it, in any build.
+    #[inline]
+    pub(crate) fn invariant_holds(&self, value: &T) -> bool {
+        self.invariant
+            .as_ref()
+            .is_none_or(|invariant| invariant.call(value))
+    }
+
+    #[inline]
+    pub(crate) fn check(&self, next: &T) {
+        assert!(
+            !self.poisoned,
+            "DoubleBuffer poisoned, a panic interrupted building the value to publish",
+        );
+        #[cfg(debug_assertions)]
+        if let Some(invariant) = &self.invariant {
+            assert!(
+                invariant.call(next),
+                "DoubleBuffer invariant violated by the value to publish as generation {}",
+                self.generation.wrapping_add(1),
+            );
+        }
+        #[cfg(not(debug_assertions))]
+        let _ = next;
+    }
+}
+
+impl<T> DoubleBuffer<T, MoveSwap, Tracked<T>> {
+    /// Creates a buffer with the [`Tracked`] instrumentation, like [`DoubleBuffer::new()`].
+    #[inline]
+    pub const fn tracked(current: T, next: T) -> Self {
+        Self::with_strategy(current, next)
+    }
+}
+
+impl<T, S> DoubleBuffer<T, S, Tracked<T>> {
+    /// Returns `true` if the next value was mutably accessed since the last swap.
+    ///
+    /// Any mutable access through [`DerefMut`](core::ops::DerefMut),
+    /// [`AsMut`] or [`BorrowMut`](core::borrow::BorrowMut) counts, even if
+    /// the value is not changed.
+    #[inline]
+    pub const fn has_pending_changes(&self) -> bool {
+        self.instrument.dirty
+    }
+
+    /// Number of swaps since the buffer was created.
+    ///
+    /// Every way of swapping publishes a new generation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::TrackedBuffer;
+    /// let mut buffer: TrackedBuffer<u32> = TrackedBuffer::default();
+    /// assert_eq!(buffer.generation(), 0);
+    ///
+    /// buffer.swap();
+    /// buffer.swap_with_clone();
+    /// assert_eq!(buffer.generation(), 2);
+    /// ```
+    #[inline]
+    pub const fn generation(&self) -> u64 {
+        self.instrument.generation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_untracked_buffer_only_holds_values() {
+        // The current slot is padded to the alignment of the values.
+        assert_eq!(size_of::<DoubleBuffer<u64>>(), 2 * 8 + 8);
+        assert_eq!(size_of::<Option<DoubleBuffer<[u8; 3]>>>(), 7);
+    }
+}
@@ -0,0 +1,112 @@
+use core::sync::atomic::AtomicBool;
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::Ordering;
+
+use crate::{DoubleBuffer, Instrument, SwapStrategy};
+
+/// Signal telling when a buffer can be published, like a vertical sync, a DMA
+/// transfer complete flag or a timer compare, used by [`DoubleBuffer::swap_when()`].
+///
+/// It's implemented for closures returning `bool` and for [`AtomicBool`], which
+/// is set from an interrupt handler and cleared when it triggers a swap.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{DoubleBuffer, SwapTrigger};
+/// struct EveryOther(bool);
+///
+/// impl SwapTrigger for EveryOther {
+///     fn poll(&mut self) -> bool {
+///         self.0 = !self.0;
+///         self.0
+///     }
+/// }
+///
+/// let mut buffer: DoubleBuffer<u32> = DoubleBuffer::new(1, 2);
+/// let mut trigger = EveryOther(false);
+/// assert!(buffer.swap_when(&mut trigger));
+/// assert!(!buffer.swap_when(&mut trigger));
+/// assert_eq!(buffer, 2);
+/// ```
+pub trait SwapTrigger {
+    /// Returns `true` if the buffer must be swapped now, consuming the signal.
+    fn poll(&mut self) -> bool;
+}
+
+impl<F: FnMut() -> bool> SwapTrigger for F {
+    #[inline]
+    fn poll(&mut self) -> bool {
+        self()
+    }
+}
+
+impl SwapTrigger for AtomicBool {
+    #[inline]
+    fn poll(&mut self) -> bool {
+        core::mem::take(self.get_mut())
+    }
+}
+
+#[cfg(target_has_atomic = "8")]
+impl SwapTrigger for &AtomicBool {
+    #[inline]
+    fn poll(&mut self) -> bool {
+        self.swap(false, Ordering::Acquire)
+    }
+}
+
+impl<T, S: SwapStrategy<T>, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Swaps like [`DoubleBuffer::swap()`] only if the trigger signals it,
+    /// returning whether it was swapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// use core::sync::atomic::{AtomicBool, Ordering};
+    ///
+    /// static VSYNC: AtomicBool = AtomicBool::new(false);
+    ///
+    /// let mut frame: DoubleBuffer<[u8; 4]> = DoubleBuffer::default();
+    /// frame[0] = 1;
+    /// assert!(!frame.swap_when(&mut &VSYNC));
+    ///
+    /// // Set by the vertical blanking interrupt.
+    /// VSYNC.store(true, Ordering::Release);
+    /// assert!(frame.swap_when(&mut &VSYNC));
+    /// assert_eq!(frame[0], 1);
+    /// ```
+    #[inline]
+    pub fn swap_when(&mut self, trigger: &mut impl SwapTrigger) -> bool {
+        if !trigger.poll() {
+            return false;
+        }
+        self.swap();
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TrackedBuffer;
+
+    #[test]
+    fn test_triggers() {
+        let mut buffer: TrackedBuffer<u8> = DoubleBuffer::tracked(1, 2);
+        let mut ticks = 0;
+        let mut every_third = || {
+            ticks += 1;
+            ticks % 3 == 0
+        };
+        assert!(!buffer.swap_when(&mut every_third));
+        assert!(!buffer.swap_when(&mut every_third));
+        assert!(buffer.swap_when(&mut every_third));
+
+        let mut flag = AtomicBool::new(true);
+        assert!(buffer.swap_when(&mut flag));
+        assert!(!buffer.swap_when(&mut flag));
+        assert_eq!(buffer.generation(), 2);
+    }
+}
@@ -0,0 +1,131 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::{DoubleBuffer, Instrument, MoveSwap, SwapStrategy};
+
+/// Wraps a [`DoubleBuffer`] separating the read and write phases at compile time.
+///
+/// In the reading phase only the current value is accessible. Call
+/// [`Reading::begin_write()`] to get a [`Writing`] handle, which only gives access
+/// to the next value, and [`Writing::finish()`] to swap and go back to reading.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{DoubleBuffer, Reading};
+/// let mut buffer: Reading<u32> = Reading::new(DoubleBuffer::new(1, 2));
+/// assert_eq!(*buffer, 1);
+///
+/// let mut writing = buffer.begin_write();
+/// *writing = 3;
+/// writing.finish();
+///
+/// assert_eq!(*buffer, 3);
+/// ```
+///
+/// The current value can't be read while writing:
+///
+/// ```compile_fail
+/// # use double_buffer::{DoubleBuffer, Reading};
+/// let mut buffer: Reading<u32> = Reading::new(DoubleBuffer::new(1, 2));
+/// let mut writing = buffer.begin_write();
+/// *writing = *buffer;
+/// writing.finish();
+/// ```
+pub struct Reading<T, S = MoveSwap, I = ()> {
+    buffer: DoubleBuffer<T, S, I>,
+}
+
+impl<T, S, I: Instrument<T>> Reading<T, S, I> {
+    #[inline]
+    pub const fn new(buffer: DoubleBuffer<T, S, I>) -> Self {
+        Self { buffer }
+    }
+
+    /// Gives access to the current value.
+    #[inline]
+    pub fn read(&self) -> &T {
+        self.buffer.current()
+    }
+
+    /// Starts the writing phase, giving access only to the next value.
+    #[inline]
+    pub fn begin_write(&mut self) -> Writing<'_, T, S, I> {
+        Writing {
+            buffer: &mut self.buffer,
+        }
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> DoubleBuffer<T, S, I> {
+        self.buffer
+    }
+}
+
+impl<T, S, I: Instrument<T>> Deref for Reading<T, S, I> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.read()
+    }
+}
+
+/// Handle of the writing phase of a [`Reading`] buffer, only the next value is accessible.
+///
+/// Dropping it without calling [`Writing::finish()`] keeps the writes in the
+/// next value, but they aren't published.
+pub struct Writing<'a, T, S = MoveSwap, I = ()> {
+    buffer: &'a mut DoubleBuffer<T, S, I>,
+}
+
+impl<T, S, I: Instrument<T>> Writing<'_, T, S, I> {
+    /// Gives access to the next value.
+    #[inline]
+    pub fn write(&mut self) -> &mut T {
+        self.buffer.as_mut()
+    }
+}
+
+impl<T, S: SwapStrategy<T>, I: Instrument<T>> Writing<'_, T, S, I> {
+    /// Swaps the buffer, publishing the writes, and ends the writing phase.
+    #[inline]
+    pub fn finish(self) {
+        self.buffer.swap();
+    }
+}
+
+impl<T, S, I: Instrument<T>> Deref for Writing<'_, T, S, I> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.buffer.next()
+    }
+}
+
+impl<T, S, I: Instrument<T>> DerefMut for Writing<'_, T, S, I> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.write()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_without_finish_is_not_published() {
+        let mut buffer: Reading<u32> = Reading::new(DoubleBuffer::new(1, 2));
+
+        {
+            let mut writing = buffer.begin_write();
+            assert_eq!(*writing, 2);
+            *writing = 3;
+        }
+        assert_eq!(*buffer, 1);
+
+        buffer.begin_write().finish();
+        assert_eq!(*buffer, 3);
+    }
+}
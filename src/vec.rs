@@ -0,0 +1,218 @@
+use alloc::vec::{self, Vec};
+use core::fmt::{Debug, Formatter};
+use core::ops::Deref;
+
+use crate::{DoubleBuffer, TrackedBuffer};
+
+/// Vector whose items are pushed to a staged side and read from a published side,
+/// for the "collect events during the frame, process them in the next frame" pattern.
+///
+/// It dereferences to the published items, and [`DoubleBufferedVec::publish()`]
+/// publishes the staged items. The vectors are reused, so their capacity is kept.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::DoubleBufferedVec;
+/// let mut events: DoubleBufferedVec<&str> = DoubleBufferedVec::new();
+///
+/// events.push("jump");
+/// events.push("shoot");
+/// assert!(events.is_empty());
+///
+/// events.publish();
+/// assert_eq!(*events, ["jump", "shoot"]);
+///
+/// events.publish();
+/// assert!(events.is_empty());
+/// ```
+pub struct DoubleBufferedVec<T> {
+    buffer: TrackedBuffer<Vec<T>>,
+}
+
+impl<T> DoubleBufferedVec<T> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            buffer: DoubleBuffer::tracked(Vec::new(), Vec::new()),
+        }
+    }
+
+    /// Creates it with the given capacity in both sides.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: DoubleBuffer::tracked(
+                Vec::with_capacity(capacity),
+                Vec::with_capacity(capacity),
+            ),
+        }
+    }
+
+    /// Pushes an item to the staged side.
+    #[inline]
+    pub fn push(&mut self, item: T) {
+        self.buffer.push(item);
+    }
+
+    /// Gives access to the staged items.
+    #[inline]
+    pub fn staged(&self) -> &[T] {
+        self.buffer.next()
+    }
+
+    /// Gives mutable access to the vector of staged items.
+    #[inline]
+    pub fn staged_mut(&mut self) -> &mut Vec<T> {
+        self.buffer.as_mut()
+    }
+
+    /// Publishes the staged items, then items will be pushed to an empty staged side.
+    #[inline]
+    pub fn publish(&mut self) {
+        self.buffer.swap_with_clear();
+    }
+
+    /// Publishes the staged items, keeping them staged too, so items pushed after
+    /// are added to them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBufferedVec;
+    /// let mut log: DoubleBufferedVec<u32> = DoubleBufferedVec::new();
+    /// log.push(1);
+    /// log.publish_carrying();
+    ///
+    /// log.push(2);
+    /// log.publish_carrying();
+    /// assert_eq!(*log, [1, 2]);
+    /// ```
+    #[inline]
+    pub fn publish_carrying(&mut self)
+    where
+        T: Clone,
+    {
+        self.buffer.swap_with_clone();
+    }
+
+    /// Removes the published items and iterates them, so they're consumed by value.
+    ///
+    /// The vector keeps its capacity, reused for the items staged after the next publish.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::EventBuffer;
+    /// let mut clicks: EventBuffer<(u16, u16)> = EventBuffer::new();
+    /// clicks.push((10, 20));
+    /// clicks.publish();
+    ///
+    /// for (x, y) in clicks.drain() {
+    ///     assert_eq!((x, y), (10, 20));
+    /// }
+    /// assert!(clicks.is_empty());
+    /// ```
+    #[inline]
+    pub fn drain(&mut self) -> vec::Drain<'_, T> {
+        self.buffer.drain_current()
+    }
+
+    /// Number of swaps since it was created, see [`DoubleBuffer::generation()`].
+    #[inline]
+    pub const fn generation(&self) -> u64 {
+        self.buffer.generation()
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> TrackedBuffer<Vec<T>> {
+        self.buffer
+    }
+}
+
+/// Queue of events pushed by producers during a frame, published at the end of
+/// it and drained by consumers during the next one, see [`DoubleBufferedVec`].
+pub type EventBuffer<T> = DoubleBufferedVec<T>;
+
+impl<T: Debug> Debug for DoubleBufferedVec<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DoubleBufferedVec")
+            .field("published", &self.buffer.current())
+            .field("staged", self.buffer.next())
+            .finish()
+    }
+}
+
+impl<T> Default for DoubleBufferedVec<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for DoubleBufferedVec<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+impl<T> Extend<T> for DoubleBufferedVec<T> {
+    /// Pushes the items to the staged side.
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.buffer.extend(iter);
+    }
+}
+
+impl<'a, T> IntoIterator for &'a DoubleBufferedVec<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    /// Iterates the published items.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_publish_keeps_capacity() {
+        let mut events: DoubleBufferedVec<u8> = DoubleBufferedVec::with_capacity(16);
+        events.extend([1, 2, 3]);
+        assert_eq!(events.staged(), [1, 2, 3]);
+
+        events.publish();
+        assert_eq!(*events, [1, 2, 3]);
+        assert!(events.staged().is_empty());
+        assert!(events.staged_mut().capacity() >= 16);
+    }
+
+    #[test]
+    fn test_drain_keeps_staged() {
+        let mut events: EventBuffer<u8> = EventBuffer::new();
+        events.push(1);
+        events.publish();
+        events.push(2);
+
+        assert_eq!(events.drain().collect::<Vec<_>>(), [1]);
+        assert!(events.is_empty());
+        assert_eq!(events.staged(), [2]);
+    }
+
+    #[test]
+    fn test_publish_carrying() {
+        let mut events: DoubleBufferedVec<u8> = DoubleBufferedVec::new();
+        events.push(1);
+        events.publish_carrying();
+        assert_eq!(events.staged(), [1]);
+        assert_eq!(events.generation(), 1);
+    }
+}
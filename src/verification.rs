@@ -0,0 +1,63 @@
+//! Proofs of the unsafe code, checked with `cargo kani`.
+
+use crate::DoubleBuffer;
+
+/// Swaps the buffer with any sequence of the swaps that flip and the ones that don't.
+fn any_swaps(buffer: &mut DoubleBuffer<u8>) {
+    let swaps: u8 = kani::any();
+    kani::assume(swaps < 4);
+    for _ in 0..swaps {
+        if kani::any() {
+            buffer.swap();
+        } else {
+            buffer.swap_with_clone();
+        }
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn both_mut_is_in_bounds_and_disjoint() {
+    let mut buffer = DoubleBuffer::new(kani::any(), kani::any());
+    any_swaps(&mut buffer);
+    let (current_value, next_value) = (*buffer.current(), *buffer.next());
+
+    let (current, next) = buffer.both_mut();
+    assert!(!core::ptr::eq(current, next));
+    assert_eq!((*current, *next), (current_value, next_value));
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn split_mut_writes_only_the_next_value() {
+    let mut buffer = DoubleBuffer::new(kani::any(), kani::any());
+    any_swaps(&mut buffer);
+    let current_value = *buffer.current();
+
+    let (_, next) = buffer.split_mut();
+    *next = kani::any();
+    let next_value = *next;
+
+    assert_eq!(*buffer.current(), current_value);
+    assert_eq!(*buffer.next(), next_value);
+}
+
+#[cfg(feature = "embedded-dma")]
+#[kani::proof]
+#[kani::unwind(5)]
+fn split_halves_join_back() {
+    let mut buffer = DoubleBuffer::new(kani::any(), kani::any());
+    any_swaps(&mut buffer);
+    let current_value = *buffer.current();
+
+    let (current, mut next) = buffer.split_halves();
+    *next = kani::any();
+    let next_value = *next;
+    assert_eq!(*current, current_value);
+
+    let buffer = current.join(next).unwrap();
+    assert_eq!(
+        (*buffer.current(), *buffer.next()),
+        (current_value, next_value)
+    );
+}
@@ -0,0 +1,391 @@
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter};
+use core::ops::{Deref, DerefMut};
+
+use crate::{DoubleBuffer, Instrument};
+
+/// Read-only handle to the current value of a [`DoubleBuffer`], created with
+/// [`DoubleBuffer::view()`].
+///
+/// It's a shared reference that can't be turned back into the buffer, so code
+/// given a view can only read the published value, never the staged one.
+pub struct ReadOnlyView<'a, T> {
+    value: &'a T,
+}
+
+/// Handle to the next value of a [`DoubleBuffer`], created with [`DoubleBuffer::stage()`].
+///
+/// It only gives access to the staged value, so code given it can prepare the
+/// next value but can't read the published one or swap the buffer.
+pub struct WriteOnlyView<'a, T> {
+    value: &'a mut T,
+}
+
+/// Next value of a [`DoubleBuffer`] to compare, created with [`DoubleBuffer::staged()`].
+///
+/// Comparing the buffer compares its current value, comparing this view
+/// compares the staged one, without counting as a mutable access.
+pub struct Staged<'a, T> {
+    value: &'a T,
+}
+
+/// Part of both values of a [`DoubleBuffer`], like a field, created with
+/// [`DoubleBuffer::project()`].
+///
+/// Like [`DoubleBuffer`], it dereferences to the part of the current value and
+/// mutably dereferences to the part of the next value, so a subsystem can be
+/// given access to its part of a large state without splitting it into many
+/// buffers. It can't swap, the whole buffer is published by its owner.
+pub struct Projection<'a, U> {
+    current: &'a U,
+    next: &'a mut U,
+}
+
+impl<T, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Projects a part of the values, with `current` giving access to the part of
+    /// the current value and `next` to the same part of the next value.
+    ///
+    /// It counts as a mutable access for [`DoubleBuffer::has_pending_changes()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::{DoubleBuffer, Projection};
+    /// #[derive(Default)]
+    /// struct World {
+    ///     positions: [i32; 2],
+    ///     score: u32,
+    /// }
+    ///
+    /// fn physics(mut positions: Projection<'_, [i32; 2]>) {
+    ///     let current = *positions.current();
+    ///     *positions = current.map(|position| position + 1);
+    /// }
+    ///
+    /// let mut world: DoubleBuffer<World> = DoubleBuffer::default();
+    /// physics(world.project(|world| &world.positions, |world| &mut world.positions));
+    /// world.swap();
+    ///
+    /// assert_eq!(world.positions, [1, 1]);
+    /// ```
+    #[inline]
+    pub fn project<U>(
+        &mut self,
+        current: impl FnOnce(&T) -> &U,
+        next: impl FnOnce(&mut T) -> &mut U,
+    ) -> Projection<'_, U> {
+        let (current_value, next_value) = self.split_mut();
+        Projection {
+            current: current(current_value),
+            next: next(next_value),
+        }
+    }
+
+    /// Gives a read-only handle to the current value, to pass it to code that
+    /// must only see published values, like rendering or serialization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::{DoubleBuffer, ReadOnlyView};
+    /// fn render(frame: ReadOnlyView<'_, u32>) -> u32 {
+    ///     *frame
+    /// }
+    ///
+    /// let mut buffer: DoubleBuffer<u32> = DoubleBuffer::new(1, 2);
+    /// assert_eq!(render(buffer.view()), 1);
+    ///
+    /// buffer.swap();
+    /// assert_eq!(render(buffer.view()), 2);
+    /// ```
+    ///
+    /// The next value can't be reached through the view:
+    ///
+    /// ```compile_fail
+    /// # use double_buffer::{DoubleBuffer, ReadOnlyView};
+    /// fn render(mut frame: ReadOnlyView<'_, u32>) {
+    ///     *frame = 3;
+    /// }
+    /// ```
+    #[inline]
+    pub fn view(&self) -> ReadOnlyView<'_, T> {
+        ReadOnlyView { value: self }
+    }
+
+    /// Gives a view of the next value to compare it, like in assertions about
+    /// the staged value before swapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let mut scores: DoubleBuffer<u32> = DoubleBuffer::new(10, 0);
+    /// *scores = 20;
+    ///
+    /// assert_eq!(scores.staged(), 20);
+    /// assert!(scores.staged() > 10);
+    /// assert_eq!(scores, 10);
+    /// ```
+    #[inline]
+    pub fn staged(&self) -> Staged<'_, T> {
+        Staged { value: self.next() }
+    }
+
+    /// Gives a handle to the next value only, to pass it to code that must only
+    /// prepare the next value, like producer systems.
+    ///
+    /// It counts as a mutable access for [`DoubleBuffer::has_pending_changes()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::{DoubleBuffer, WriteOnlyView};
+    /// fn produce(mut frame: WriteOnlyView<'_, u32>) {
+    ///     *frame += 1;
+    /// }
+    ///
+    /// let mut buffer: DoubleBuffer<u32> = DoubleBuffer::new(1, 2);
+    /// produce(buffer.stage());
+    /// assert_eq!(*buffer, 1);
+    ///
+    /// buffer.swap();
+    /// assert_eq!(*buffer, 3);
+    /// ```
+    #[inline]
+    pub fn stage(&mut self) -> WriteOnlyView<'_, T> {
+        WriteOnlyView {
+            value: self.as_mut(),
+        }
+    }
+}
+
+impl<'a, T> ReadOnlyView<'a, T> {
+    /// Gives access to the current value for the whole lifetime of the view.
+    #[inline]
+    pub const fn get(self) -> &'a T {
+        self.value
+    }
+}
+
+impl<T> Clone for ReadOnlyView<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ReadOnlyView<'_, T> {}
+
+impl<T: Debug> Debug for ReadOnlyView<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T: Display> Display for ReadOnlyView<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T> Deref for ReadOnlyView<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
+}
+
+impl<T> AsRef<T> for ReadOnlyView<'_, T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<T: PartialEq> PartialEq<T> for ReadOnlyView<'_, T> {
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        self.value.eq(other)
+    }
+}
+
+impl<'a, T> Staged<'a, T> {
+    /// Gives access to the next value for the whole lifetime of the view.
+    #[inline]
+    pub const fn get(self) -> &'a T {
+        self.value
+    }
+}
+
+impl<T> Clone for Staged<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Staged<'_, T> {}
+
+impl<T: Debug> Debug for Staged<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T> Deref for Staged<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
+}
+
+impl<T: PartialEq> PartialEq<T> for Staged<'_, T> {
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        self.value.eq(other)
+    }
+}
+
+impl<T: PartialOrd> PartialOrd<T> for Staged<'_, T> {
+    #[inline]
+    fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+        self.value.partial_cmp(other)
+    }
+}
+
+impl<'a, T> WriteOnlyView<'a, T> {
+    /// Gives mutable access to the next value for the whole lifetime of the view.
+    #[inline]
+    pub fn into_mut(self) -> &'a mut T {
+        self.value
+    }
+}
+
+impl<T: Debug> Debug for WriteOnlyView<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T> Deref for WriteOnlyView<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
+}
+
+impl<T> DerefMut for WriteOnlyView<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.value
+    }
+}
+
+impl<T> AsMut<T> for WriteOnlyView<'_, T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut T {
+        self.value
+    }
+}
+
+impl<'a, U> Projection<'a, U> {
+    /// Gives access to the part of the current value.
+    #[inline]
+    pub const fn current(&self) -> &U {
+        self.current
+    }
+
+    /// Gives access to the part of the next value.
+    #[inline]
+    pub const fn next(&self) -> &U {
+        self.next
+    }
+
+    /// Gives access to the part of the current value and mutable access to the
+    /// part of the next value for the whole lifetime of the projection.
+    #[inline]
+    pub fn into_split(self) -> (&'a U, &'a mut U) {
+        (self.current, self.next)
+    }
+}
+
+impl<U: Debug> Debug for Projection<'_, U> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Projection")
+            .field("current", self.current)
+            .field("next", self.next)
+            .finish()
+    }
+}
+
+impl<U> Deref for Projection<'_, U> {
+    type Target = U;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.current
+    }
+}
+
+impl<U> DerefMut for Projection<'_, U> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TrackedBuffer;
+
+    #[test]
+    fn test_view_outlives_handle() {
+        let mut buffer: DoubleBuffer<[u8; 2]> = DoubleBuffer::new([1, 2], [0; 2]);
+        let value = buffer.view().get();
+        assert_eq!(value, &[1, 2]);
+
+        buffer[0] = 3;
+        let view = buffer.view();
+        let copy = view;
+        assert_eq!(view, [1, 2]);
+        assert_eq!(copy.as_ref(), &[1, 2]);
+    }
+
+    #[test]
+    fn test_stage_marks_dirty() {
+        let mut buffer: TrackedBuffer<u8> = DoubleBuffer::tracked(1, 2);
+        let staged = buffer.stage().into_mut();
+        *staged = 3;
+        assert!(buffer.has_pending_changes());
+        assert_eq!(*buffer, 1);
+
+        buffer.swap();
+        assert_eq!(*buffer, 3);
+    }
+
+    #[test]
+    fn test_project_field() {
+        let mut buffer: TrackedBuffer<(u8, [u8; 2])> =
+            DoubleBuffer::tracked((1, [2, 3]), (0, [0; 2]));
+        let mut second = buffer.project(|value| &value.1, |value| &mut value.1);
+        second[1] = second[0] + 1;
+        assert_eq!(*second, [2, 3]);
+        assert_eq!(second.next(), &[0, 3]);
+
+        assert!(buffer.has_pending_changes());
+        buffer.swap();
+        assert_eq!(*buffer, (0, [0, 3]));
+    }
+}
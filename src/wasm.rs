@@ -0,0 +1,120 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{DoubleBuffer, TrackedBuffer};
+
+/// Double buffer of bytes exported to JavaScript, for pixel data handed to
+/// `putImageData` or WebGL by the render loop of a Wasm game.
+///
+/// The frame is written from Rust with [`JsDoubleBuffer::next_mut()`] and
+/// published with `swap()`, and JavaScript reads the current frame with
+/// `currentView()`, a `Uint8Array` over the Wasm memory without copying it.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::JsDoubleBuffer;
+/// let mut frame = JsDoubleBuffer::new(4 * 320 * 240);
+/// frame.next_mut().fill(255);
+/// frame.swap();
+/// assert_eq!(frame.current()[0], 255);
+/// ```
+///
+/// In JavaScript:
+///
+/// ```js
+/// const pixels = new Uint8ClampedArray(frame.currentView());
+/// context.putImageData(new ImageData(pixels, 320, 240), 0, 0);
+/// ```
+#[wasm_bindgen]
+pub struct JsDoubleBuffer {
+    buffer: TrackedBuffer<Vec<u8>>,
+}
+
+#[wasm_bindgen]
+impl JsDoubleBuffer {
+    /// Creates a buffer of two frames of `len` zeroed bytes.
+    #[wasm_bindgen(constructor)]
+    pub fn new(len: usize) -> Self {
+        Self {
+            buffer: DoubleBuffer::tracked(vec![0; len], vec![0; len]),
+        }
+    }
+
+    /// Publishes the next frame, then writes will be over the previous current frame.
+    #[inline]
+    pub fn swap(&mut self) {
+        self.buffer.swap();
+    }
+
+    /// Length of each frame in bytes.
+    #[wasm_bindgen(getter)]
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    #[wasm_bindgen(js_name = isEmpty)]
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Number of swaps since it was created.
+    #[wasm_bindgen(getter)]
+    pub fn generation(&self) -> u64 {
+        self.buffer.generation()
+    }
+
+    /// View of the current frame over the Wasm memory, without copying it.
+    ///
+    /// The view is only valid until the next call into Rust that swaps or
+    /// allocates, as the memory may grow and detach it, so create it every frame.
+    #[wasm_bindgen(js_name = currentView)]
+    pub fn current_view(&self) -> Uint8Array {
+        // SAFETY: the view is only read by JavaScript before calling into Rust
+        // again, as documented, so the frame isn't modified or moved meanwhile.
+        unsafe { Uint8Array::view(self.buffer.current()) }
+    }
+
+    /// Copies `bytes` from JavaScript to the start of the next frame.
+    #[wasm_bindgen(js_name = stage)]
+    pub fn stage(&mut self, bytes: &[u8]) {
+        let len = bytes.len().min(self.buffer.len());
+        self.buffer[..len].copy_from_slice(&bytes[..len]);
+    }
+}
+
+impl JsDoubleBuffer {
+    /// Gives access to the current frame.
+    #[inline]
+    pub fn current(&self) -> &[u8] {
+        self.buffer.current()
+    }
+
+    /// Gives mutable access to the next frame, to write it from Rust.
+    #[inline]
+    pub fn next_mut(&mut self) -> &mut [u8] {
+        &mut self.buffer
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> TrackedBuffer<Vec<u8>> {
+        self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stage_clamped_to_frame() {
+        let mut frame = JsDoubleBuffer::new(2);
+        frame.stage(&[1, 2, 3]);
+        frame.swap();
+        assert_eq!(frame.current(), [1, 2]);
+        assert_eq!(frame.generation(), 1);
+    }
+}
@@ -0,0 +1,231 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::{Clear, Clock, DoubleBuffer, MoveSwap, SwapStrategy};
+
+/// Wraps a [`DoubleBuffer`] that must be published at least once every deadline,
+/// measured with a [`Clock`], so supervisory code can detect a stalled producer.
+///
+/// The deadline is counted from the last swap, or from when it was created if it
+/// was never swapped. [`Watchdog::is_stalled()`] is a flag to poll, and
+/// [`Watchdog::check_stall()`] calls a callback once for each stall.
+///
+/// Like [`Timestamped`](crate::Timestamped), it dereferences to the current value
+/// and mutably dereferences to the next value, and all swaps must go through the
+/// wrapper, so the inner buffer is only accessible immutably with
+/// [`Watchdog::buffer()`].
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{Clock, DoubleBuffer, Watchdog};
+/// # use core::cell::Cell;
+/// # struct ManualClock(Cell<u32>);
+/// # impl Clock for ManualClock {
+/// #     type Instant = u32;
+/// #     type Duration = u32;
+/// #     fn now(&self) -> u32 { self.0.get() }
+/// #     fn elapsed(&self, since: u32) -> u32 { self.now() - since }
+/// # }
+/// let clock = ManualClock(Cell::new(0));
+/// let mut setpoint = Watchdog::new(DoubleBuffer::<f32>::default(), &clock, 100);
+///
+/// *setpoint = 1.5;
+/// setpoint.swap();
+///
+/// clock.0.set(150);
+/// let mut alarms = 0;
+/// assert!(setpoint.check_stall(|_| alarms += 1));
+/// assert!(setpoint.check_stall(|_| alarms += 1));
+/// assert_eq!(alarms, 1);
+///
+/// setpoint.swap();
+/// assert!(!setpoint.is_stalled());
+/// ```
+pub struct Watchdog<T, C: Clock, S = MoveSwap> {
+    buffer: DoubleBuffer<T, S>,
+    clock: C,
+    deadline: C::Duration,
+    last_publish: C::Instant,
+    reported: bool,
+}
+
+impl<T, C: Clock, S> Watchdog<T, C, S> {
+    /// Creates a watchdog whose first deadline is counted from now.
+    #[inline]
+    pub fn new(buffer: DoubleBuffer<T, S>, clock: C, deadline: C::Duration) -> Self {
+        let last_publish = clock.now();
+        Self {
+            buffer,
+            clock,
+            deadline,
+            last_publish,
+            reported: false,
+        }
+    }
+
+    #[inline]
+    pub const fn buffer(&self) -> &DoubleBuffer<T, S> {
+        &self.buffer
+    }
+
+    #[inline]
+    pub const fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    #[inline]
+    pub const fn deadline(&self) -> &C::Duration {
+        &self.deadline
+    }
+
+    #[inline]
+    pub fn set_deadline(&mut self, deadline: C::Duration) {
+        self.deadline = deadline;
+    }
+
+    /// Time of the last swap, or of the creation if it was never swapped.
+    #[inline]
+    pub fn last_publish_time(&self) -> C::Instant {
+        self.last_publish
+    }
+
+    /// Time elapsed since the last swap, or since the creation if it was never swapped.
+    #[inline]
+    pub fn time_since_publish(&self) -> C::Duration {
+        self.clock.elapsed(self.last_publish)
+    }
+
+    /// Swaps like [`DoubleBuffer::try_swap_with()`] and feeds the watchdog if it was swapped.
+    #[inline]
+    pub fn try_swap_with<E>(&mut self, reset: impl FnOnce() -> Result<T, E>) -> Result<(), E> {
+        self.buffer.try_swap_with(reset)?;
+        self.feed();
+        Ok(())
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> DoubleBuffer<T, S> {
+        self.buffer
+    }
+
+    #[inline]
+    fn feed(&mut self) {
+        self.last_publish = self.clock.now();
+        self.reported = false;
+    }
+}
+
+impl<T, C: Clock, S> Watchdog<T, C, S>
+where
+    C::Duration: PartialOrd,
+{
+    /// Whether the deadline has passed without a swap.
+    #[inline]
+    pub fn is_stalled(&self) -> bool {
+        self.time_since_publish() > self.deadline
+    }
+
+    /// Whether the deadline has passed without a swap, calling `on_stall` with the
+    /// time since the last swap the first time it's detected, until the next swap.
+    #[inline]
+    pub fn check_stall(&mut self, on_stall: impl FnOnce(C::Duration)) -> bool {
+        let elapsed = self.time_since_publish();
+        if elapsed <= self.deadline {
+            return false;
+        }
+        if !self.reported {
+            self.reported = true;
+            on_stall(elapsed);
+        }
+        true
+    }
+}
+
+impl<T, C: Clock, S: SwapStrategy<T>> Watchdog<T, C, S> {
+    /// Swaps like [`DoubleBuffer::swap()`] and feeds the watchdog.
+    #[inline]
+    pub fn swap(&mut self) {
+        self.buffer.swap();
+        self.feed();
+    }
+}
+
+impl<T: Clone, C: Clock, S> Watchdog<T, C, S> {
+    /// Swaps like [`DoubleBuffer::swap_with_clone()`] and feeds the watchdog.
+    #[inline]
+    pub fn swap_with_clone(&mut self) {
+        self.buffer.swap_with_clone();
+        self.feed();
+    }
+}
+
+impl<T: Default, C: Clock, S> Watchdog<T, C, S> {
+    /// Swaps like [`DoubleBuffer::swap_with_default()`] and feeds the watchdog.
+    #[inline]
+    pub fn swap_with_default(&mut self) {
+        self.buffer.swap_with_default();
+        self.feed();
+    }
+}
+
+impl<T: Clear, C: Clock, S> Watchdog<T, C, S> {
+    /// Swaps like [`DoubleBuffer::swap_with_clear()`] and feeds the watchdog.
+    #[inline]
+    pub fn swap_with_clear(&mut self) {
+        self.buffer.swap_with_clear();
+        self.feed();
+    }
+}
+
+impl<T, C: Clock, S> Deref for Watchdog<T, C, S> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+impl<T, C: Clock, S> DerefMut for Watchdog<T, C, S> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    struct ManualClock(Cell<u32>);
+
+    impl Clock for ManualClock {
+        type Instant = u32;
+        type Duration = u32;
+
+        fn now(&self) -> u32 {
+            self.0.get()
+        }
+
+        fn elapsed(&self, since: u32) -> u32 {
+            self.now() - since
+        }
+    }
+
+    #[test]
+    fn test_deadline_from_creation() {
+        let clock = ManualClock(Cell::new(10));
+        let mut buffer = Watchdog::new(DoubleBuffer::<u32>::default(), &clock, 5);
+
+        clock.0.set(15);
+        assert!(!buffer.is_stalled());
+        clock.0.set(16);
+        assert!(buffer.check_stall(|elapsed| assert_eq!(elapsed, 6)));
+
+        buffer.try_swap_with(|| Err(())).unwrap_err();
+        assert!(buffer.is_stalled());
+        buffer.swap_with_default();
+        assert_eq!(buffer.time_since_publish(), 0);
+    }
+}
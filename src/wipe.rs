@@ -0,0 +1,141 @@
+use core::fmt::{Debug, Formatter};
+use core::ops::{Deref, DerefMut};
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::stats::SwapKind;
+use crate::{DoubleBuffer, Instrument, MoveSwap};
+
+/// Zeroizes both values, without counting as a write.
+impl<T: Zeroize, S, I: Instrument<T>> Zeroize for DoubleBuffer<T, S, I> {
+    #[inline]
+    fn zeroize(&mut self) {
+        let (current, next) = self.both_mut();
+        current.zeroize();
+        next.zeroize();
+    }
+}
+
+impl<T: Zeroize, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Swaps buffers like [`MoveSwap`] and zeroizes the next value in place,
+    /// so the retired value doesn't stay in memory, then writes will be over
+    /// the zeroized value.
+    ///
+    /// Unlike [`DoubleBuffer::swap_with_clear()`] or [`DoubleBuffer::swap_with_default()`],
+    /// the writes wiping the value aren't optimized away.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let mut key: DoubleBuffer<[u8; 4]> = DoubleBuffer::new([1; 4], [2; 4]);
+    /// key.swap_with_zeroize();
+    /// assert_eq!(key, [2; 4]);
+    /// assert_eq!(*key.next(), [0; 4]);
+    /// ```
+    #[inline]
+    pub fn swap_with_zeroize(&mut self) {
+        self.check_invariant();
+        self.flip();
+        self.next_mut().zeroize();
+        self.published(SwapKind::Clear);
+    }
+}
+
+/// Wraps a [`DoubleBuffer`] holding secrets, zeroizing every value when it's
+/// retired by a swap and both values when it's dropped.
+///
+/// Like [`DoubleBuffer`], it dereferences to the current value and mutably
+/// dereferences to the next value. All swaps go through
+/// [`DoubleBuffer::swap_with_zeroize()`], so the inner buffer is only accessible
+/// immutably with [`ZeroizeOnSwap::buffer()`].
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::{DoubleBuffer, ZeroizeOnSwap};
+/// let mut session_key: ZeroizeOnSwap<[u8; 16]> = ZeroizeOnSwap::new(DoubleBuffer::default());
+///
+/// *session_key = [7; 16];
+/// session_key.swap();
+/// assert_eq!(*session_key, [7; 16]);
+/// assert_eq!(*session_key.buffer().next(), [0; 16]);
+/// ```
+pub struct ZeroizeOnSwap<T: Zeroize, S = MoveSwap> {
+    buffer: DoubleBuffer<T, S>,
+}
+
+impl<T: Zeroize, S> ZeroizeOnSwap<T, S> {
+    #[inline]
+    pub const fn new(buffer: DoubleBuffer<T, S>) -> Self {
+        Self { buffer }
+    }
+
+    #[inline]
+    pub const fn buffer(&self) -> &DoubleBuffer<T, S> {
+        &self.buffer
+    }
+
+    /// Swaps like [`DoubleBuffer::swap_with_zeroize()`].
+    #[inline]
+    pub fn swap(&mut self) {
+        self.buffer.swap_with_zeroize();
+    }
+}
+
+impl<T: Zeroize, S> Drop for ZeroizeOnSwap<T, S> {
+    #[inline]
+    fn drop(&mut self) {
+        self.buffer.zeroize();
+    }
+}
+
+impl<T: Zeroize, S> ZeroizeOnDrop for ZeroizeOnSwap<T, S> {}
+
+impl<T: Zeroize + Debug, S> Debug for ZeroizeOnSwap<T, S> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ZeroizeOnSwap").field(&self.buffer).finish()
+    }
+}
+
+impl<T: Zeroize + Default, S> Default for ZeroizeOnSwap<T, S> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(DoubleBuffer::default())
+    }
+}
+
+impl<T: Zeroize, S> Deref for ZeroizeOnSwap<T, S> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+impl<T: Zeroize, S> DerefMut for ZeroizeOnSwap<T, S> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TrackedBuffer;
+
+    #[test]
+    fn test_zeroize_both() {
+        let mut buffer: TrackedBuffer<[u8; 2]> = DoubleBuffer::tracked([1; 2], [2; 2]);
+        buffer.zeroize();
+        assert_eq!((*buffer, *buffer.next()), ([0; 2], [0; 2]));
+        assert!(!buffer.has_pending_changes());
+
+        let mut wrapped = ZeroizeOnSwap::new(DoubleBuffer::new([3u8; 2], [4; 2]));
+        wrapped.swap();
+        assert_eq!((*wrapped, *wrapped.buffer().next()), ([4; 2], [0; 2]));
+    }
+}
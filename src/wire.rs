@@ -0,0 +1,58 @@
+use bincode::error::{DecodeError, EncodeError};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::{DoubleBuffer, Instrument};
+
+impl<T: Serialize, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Serializes the current value with `bincode`, in its standard configuration,
+    /// into `buf`, returning the length of the bytes, to ship it to another machine.
+    ///
+    /// It's like `DoubleBuffer::to_bytes()` of the `postcard` feature, for peers
+    /// already speaking `bincode`. The buffer is
+    /// rebuilt with [`DoubleBuffer::from_bincode()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBuffer;
+    /// let mut scene: DoubleBuffer<(u8, [i16; 2])> = DoubleBuffer::default();
+    /// *scene = (3, [-10, 20]);
+    /// scene.swap();
+    ///
+    /// let mut packet = [0; 32];
+    /// let len = scene.to_bincode(&mut packet).unwrap();
+    ///
+    /// let received: DoubleBuffer<(u8, [i16; 2])> = DoubleBuffer::from_bincode(&packet[..len]).unwrap();
+    /// assert_eq!(received, (3, [-10, 20]));
+    /// ```
+    pub fn to_bincode(&self, buf: &mut [u8]) -> Result<usize, EncodeError> {
+        bincode::serde::encode_into_slice(self.current(), buf, bincode::config::standard())
+    }
+}
+
+impl<T: DeserializeOwned + Clone, S, I: Instrument<T>> DoubleBuffer<T, S, I> {
+    /// Creates a buffer with the value serialized by [`DoubleBuffer::to_bincode()`]
+    /// as the current value and the next value.
+    pub fn from_bincode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let (value, _): (T, usize) =
+            bincode::serde::decode_from_slice(bytes, bincode::config::standard())?;
+        Ok(Self::with_strategy(value.clone(), value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buffer_too_small() {
+        let buffer: DoubleBuffer<u64> = DoubleBuffer::new(u64::MAX, 0);
+        let mut bytes = [0; 4];
+        assert!(matches!(
+            buffer.to_bincode(&mut bytes),
+            Err(EncodeError::UnexpectedEnd)
+        ));
+        assert!(DoubleBuffer::<u64>::from_bincode(&[]).is_err());
+    }
+}
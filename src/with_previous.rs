@@ -0,0 +1,172 @@
+use core::fmt::{Debug, Formatter};
+use core::ops::{Deref, DerefMut};
+
+use crate::Lerp;
+
+/// Like a [`DoubleBuffer`](crate::DoubleBuffer) that also retains the previously
+/// published value, accessible with [`DoubleBufferWithPrevious::previous()`], so
+/// renderers can interpolate between the last two frames.
+///
+/// Swapping rotates three values without cloning: the next value becomes the
+/// current one, the current one becomes the previous one, and the previous one
+/// is reused as the next value.
+///
+/// # Examples
+///
+/// ```
+/// # use double_buffer::DoubleBufferWithPrevious;
+/// let mut position: DoubleBufferWithPrevious<f32> = DoubleBufferWithPrevious::default();
+///
+/// *position = 10.0;
+/// position.swap();
+///
+/// assert_eq!(position.interpolated(0.25), 2.5);
+/// ```
+pub struct DoubleBufferWithPrevious<T> {
+    current: usize,
+    buffers: [T; 3],
+}
+
+impl<T> DoubleBufferWithPrevious<T> {
+    #[inline]
+    pub const fn new(previous: T, current: T, next: T) -> Self {
+        Self {
+            current: 1,
+            buffers: [previous, current, next],
+        }
+    }
+
+    /// Publishes the next value, the current value becomes the previous one,
+    /// and writes will be over the value that was the previous one.
+    #[inline]
+    pub const fn swap(&mut self) {
+        self.current = Self::next_offset(self.current);
+    }
+
+    /// Interpolates from the previous value to the current value.
+    #[inline]
+    pub fn interpolated<A>(&self, alpha: A) -> T
+    where
+        T: Lerp<A>,
+    {
+        self.previous().lerp(self.current(), alpha)
+    }
+
+    #[inline]
+    const fn next_offset(offset: usize) -> usize {
+        (offset + 1) % 3
+    }
+
+    #[inline]
+    const fn previous_offset(offset: usize) -> usize {
+        (offset + 2) % 3
+    }
+
+    /// Gives access to the value published before the current one.
+    ///
+    /// Unlike with [`DoubleBuffer`](crate::DoubleBuffer), where the previous value
+    /// becomes the next value and it can be written, it's kept read-only until the
+    /// following swap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_buffer::DoubleBufferWithPrevious;
+    /// let mut buffer = DoubleBufferWithPrevious::new(0, 1, 2);
+    /// buffer.swap();
+    ///
+    /// *buffer = 3;
+    /// assert_eq!(*buffer.previous(), 1);
+    /// assert_eq!(*buffer, 2);
+    /// ```
+    #[inline]
+    pub const fn previous(&self) -> &T {
+        &self.buffers[Self::previous_offset(self.current)]
+    }
+
+    #[inline]
+    const fn current(&self) -> &T {
+        &self.buffers[self.current]
+    }
+
+    #[inline]
+    const fn next(&self) -> &T {
+        &self.buffers[Self::next_offset(self.current)]
+    }
+
+    #[inline]
+    const fn next_mut(&mut self) -> &mut T {
+        &mut self.buffers[Self::next_offset(self.current)]
+    }
+}
+
+impl<T: Debug> Debug for DoubleBufferWithPrevious<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DoubleBufferWithPrevious")
+            .field("previous", self.previous())
+            .field("current", self.current())
+            .field("next", self.next())
+            .finish()
+    }
+}
+
+impl<T: Default> Default for DoubleBufferWithPrevious<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default(), T::default(), T::default())
+    }
+}
+
+impl<T> Deref for DoubleBufferWithPrevious<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.current()
+    }
+}
+
+impl<T> DerefMut for DoubleBufferWithPrevious<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.next_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap_rotates() {
+        let mut buffer = DoubleBufferWithPrevious::new(1, 2, 3);
+        buffer.swap();
+        assert_eq!(*buffer.previous(), 2);
+        assert_eq!(*buffer.current(), 3);
+        assert_eq!(*buffer.next(), 1);
+
+        buffer.swap();
+        assert_eq!(*buffer.previous(), 3);
+        assert_eq!(*buffer.current(), 1);
+        assert_eq!(*buffer.next(), 2);
+    }
+
+    #[test]
+    fn test_previous_survives_writes() {
+        let mut buffer = DoubleBufferWithPrevious::new(1, 2, 3);
+        buffer.swap();
+        *buffer = 4;
+        assert_eq!(*buffer.previous(), 2);
+        assert_eq!(*buffer.next(), 4);
+    }
+
+    #[test]
+    fn test_interpolated() {
+        let mut buffer = DoubleBufferWithPrevious::new(0.0f64, 0.0, 0.0);
+        *buffer = 8.0;
+        buffer.swap();
+        assert_eq!(buffer.interpolated(0.5f64), 4.0);
+        assert_eq!(buffer.interpolated(1.0f32), 8.0);
+    }
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "derive")]
+
+use double_buffer::{DoubleBuffered, Swap};
+
+#[derive(Debug, Default, Clone, PartialEq, DoubleBuffered)]
+struct Body {
+    position: [f32; 2],
+    velocity: [f32; 2],
+}
+
+#[derive(Debug, Default, Clone, PartialEq, DoubleBuffered)]
+#[double_buffered(single)]
+struct Camera {
+    zoom: f32,
+}
+
+#[derive(DoubleBuffered)]
+struct Tagged<T: Copy> {
+    tag: T,
+}
+
+#[test]
+fn test_buffer_per_field() {
+    let mut body = BodyBuffers::new(Body::default(), Body::default());
+
+    let stage = body.stage();
+    *stage.position = [1.0, 0.0];
+    *stage.velocity = [0.5, 0.0];
+    assert_eq!(*body.read().position, [0.0, 0.0]);
+
+    body.swap_all();
+    assert_eq!(*body.read().position, [1.0, 0.0]);
+    assert_eq!(*body.read().velocity, [0.5, 0.0]);
+    assert_eq!(*body.velocity, [0.5, 0.0]);
+}
+
+#[test]
+fn test_single_buffer() {
+    let mut camera = CameraBuffers::new(Camera { zoom: 1.0 }, Camera { zoom: 1.0 });
+    camera.stage().zoom = 2.0;
+    assert_eq!(camera.read().zoom, 1.0);
+
+    camera.swap_all();
+    assert_eq!(camera.read().zoom, 2.0);
+}
+
+#[test]
+fn test_generic_struct() {
+    let mut tagged = TaggedBuffers::new(Tagged { tag: 'a' }, Tagged { tag: 'b' });
+    tagged.swap_all();
+    assert_eq!(*tagged.read().tag, 'b');
+}
+
+#[derive(Swap)]
+struct World {
+    bodies: BodyBuffers,
+    cameras: [CameraBuffers; 2],
+}
+
+#[derive(Swap)]
+struct Scores(
+    double_buffer::DoubleBuffer<u32>,
+    double_buffer::DoubleBuffer<u32>,
+);
+
+#[test]
+fn test_derive_swap() {
+    let mut world = World {
+        bodies: BodyBuffers::new(Body::default(), Body::default()),
+        cameras: [
+            CameraBuffers::new(Camera::default(), Camera::default()),
+            CameraBuffers::new(Camera::default(), Camera { zoom: 2.0 }),
+        ],
+    };
+    *world.bodies.stage().position = [1.0, 1.0];
+
+    world.swap_buffers();
+    assert_eq!(*world.bodies.read().position, [1.0, 1.0]);
+    assert_eq!(world.cameras[1].read().zoom, 2.0);
+}
+
+#[test]
+fn test_derive_swap_tuple_struct() {
+    let mut scores = Scores(Default::default(), Default::default());
+    *scores.1 = 1;
+    scores.swap_buffers();
+    assert_eq!(scores.1, 1);
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "alloc")]
+
+use double_buffer::{CloneSwap, TrackedBuffer};
+use proptest::prelude::*;
+
+#[derive(Debug, Clone)]
+enum Op {
+    Push(u8),
+    Clear,
+    Swap,
+    SwapWithClone,
+    SwapWithDefault,
+    SwapWithClear,
+    SwapWithTake,
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        any::<u8>().prop_map(Op::Push),
+        Just(Op::Clear),
+        Just(Op::Swap),
+        Just(Op::SwapWithClone),
+        Just(Op::SwapWithDefault),
+        Just(Op::SwapWithClear),
+        Just(Op::SwapWithTake),
+    ]
+}
+
+/// Reference model, two plain values with the documented semantics of each swap.
+#[derive(Debug, Default)]
+struct Model {
+    current: Vec<u8>,
+    next: Vec<u8>,
+    generation: u64,
+}
+
+impl Model {
+    fn apply(&mut self, op: &Op, clone_swap: bool) {
+        match op {
+            Op::Push(value) => self.next.push(*value),
+            Op::Clear => self.next.clear(),
+            Op::Swap if clone_swap => self.current = self.next.clone(),
+            Op::Swap => core::mem::swap(&mut self.current, &mut self.next),
+            Op::SwapWithClone => self.current = self.next.clone(),
+            Op::SwapWithDefault | Op::SwapWithClear | Op::SwapWithTake => {
+                self.current = core::mem::take(&mut self.next);
+            }
+        }
+        if !matches!(op, Op::Push(_) | Op::Clear) {
+            self.generation += 1;
+        }
+    }
+}
+
+fn apply<S: double_buffer::SwapStrategy<Vec<u8>>>(buffer: &mut TrackedBuffer<Vec<u8>, S>, op: &Op) {
+    match op {
+        Op::Push(value) => buffer.push(*value),
+        Op::Clear => buffer.clear(),
+        Op::Swap => buffer.swap(),
+        Op::SwapWithClone => buffer.swap_with_clone(),
+        Op::SwapWithDefault => buffer.swap_with_default(),
+        Op::SwapWithClear => buffer.swap_with_clear(),
+        Op::SwapWithTake => drop(buffer.swap_with_take()),
+    }
+}
+
+fn check<S: double_buffer::SwapStrategy<Vec<u8>>>(
+    buffer: &TrackedBuffer<Vec<u8>, S>,
+    model: &Model,
+) -> Result<(), TestCaseError> {
+    prop_assert_eq!(buffer.current(), &model.current);
+    prop_assert_eq!(buffer.next(), &model.next);
+    prop_assert_eq!(buffer.generation(), model.generation);
+    prop_assert_eq!(buffer.swap_count(), model.generation);
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn test_move_swap_matches_model(ops in prop::collection::vec(op(), 0..64)) {
+        let mut buffer: TrackedBuffer<Vec<u8>> = TrackedBuffer::default();
+        let mut model = Model::default();
+        for op in &ops {
+            apply(&mut buffer, op);
+            model.apply(op, false);
+            check(&buffer, &model)?;
+        }
+    }
+
+    #[test]
+    fn test_clone_swap_matches_model(ops in prop::collection::vec(op(), 0..64)) {
+        let mut buffer: TrackedBuffer<Vec<u8>, CloneSwap> = TrackedBuffer::default();
+        let mut model = Model::default();
+        for op in &ops {
+            apply(&mut buffer, op);
+            model.apply(op, true);
+            check(&buffer, &model)?;
+        }
+    }
+}
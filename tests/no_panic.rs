@@ -0,0 +1,65 @@
+//! Checks that the accessors have no panic paths, run with `cargo test --release`.
+//!
+//! Each function drops a guard calling an undefined function if it unwinds, so
+//! the test only links if the optimizer removed every panic path.
+// The frame timing reads the clock on the first write, which may panic.
+#![cfg(all(not(debug_assertions), not(feature = "timing")))]
+
+use double_buffer::DoubleBuffer;
+
+struct Unreachable;
+
+impl Drop for Unreachable {
+    #[inline(always)]
+    fn drop(&mut self) {
+        extern "C" {
+            fn double_buffer_accessor_may_panic() -> !;
+        }
+        // SAFETY: it's never called, otherwise the test doesn't link.
+        unsafe { double_buffer_accessor_may_panic() }
+    }
+}
+
+macro_rules! no_panic {
+    ($(fn $name:ident($($arg:ident: $ty:ty),*) -> $ret:ty $body:block)*) => {
+        $(
+            #[inline(never)]
+            fn $name($($arg: $ty),*) -> $ret {
+                let guard = Unreachable;
+                let result = $body;
+                core::mem::forget(guard);
+                result
+            }
+        )*
+    };
+}
+
+no_panic! {
+    fn read(buffer: &DoubleBuffer<[u8; 4]>) -> u8 {
+        buffer[3]
+    }
+
+    fn write(buffer: &mut DoubleBuffer<[u8; 4]>) -> () {
+        buffer[3] = 1;
+    }
+
+    fn split(buffer: &mut DoubleBuffer<u64>) -> () {
+        let (current, next) = buffer.split_mut();
+        *next = *current;
+    }
+
+    fn slots(buffer: &DoubleBuffer<u64>) -> u64 {
+        *buffer.slot(buffer.next_slot()) + *buffer.current() + *buffer.next()
+    }
+}
+
+#[test]
+fn test_accessors_do_not_panic() {
+    let mut buffer = DoubleBuffer::new([0; 4], [0; 4]);
+    write(&mut buffer);
+    assert_eq!(read(&buffer), 0);
+
+    let mut buffer = DoubleBuffer::new(1, 2);
+    split(&mut buffer);
+    assert_eq!(slots(&buffer), 3);
+}
@@ -11,3 +11,34 @@ fn test_pointer_format() {
     let buffer: DoubleBuffer<u32> = DoubleBuffer::default();
     assert!(format!("{:p}", buffer).starts_with("0x"));
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn test_timestamped_with_std_clock() {
+    use double_buffer::{StdClock, Timestamped};
+
+    let mut buffer = Timestamped::new(DoubleBuffer::<u32>::default(), StdClock);
+    assert!(buffer.time_since_publish().is_none());
+
+    buffer.swap();
+    assert!(buffer.last_publish_time().unwrap() <= std::time::Instant::now());
+}
+
+#[test]
+fn test_poisoned_buffer_does_not_publish() {
+    use double_buffer::TrackedBuffer;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let mut buffer: TrackedBuffer<Vec<u8>> = DoubleBuffer::tracked(vec![1], vec![]);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        buffer.build_next(|current, next| {
+            next.extend_from_slice(current);
+            panic!("interrupted");
+        })
+    }));
+    assert!(result.is_err());
+
+    assert!(catch_unwind(AssertUnwindSafe(|| buffer.swap_with_clone())).is_err());
+    assert_eq!(*buffer, [1]);
+    assert_eq!(buffer.generation(), 0);
+}